@@ -0,0 +1,106 @@
+use aead::{Aead as _, KeyInit, Payload};
+use alloc::vec::Vec;
+
+use crate::Error;
+
+/// An authenticated encryption with associated data (AEAD) algorithm, as in Section 5.2 of RFC
+/// 9180
+pub trait Aead {
+    /// The `aead_id` of this AEAD
+    const ID: u16;
+
+    /// The size of a key, in bytes
+    const N_K: usize;
+
+    /// The size of a nonce, in bytes
+    const N_N: usize;
+
+    /// Encrypt and authenticate `plaintext` and `aad`, as in `Seal(key, nonce, aad, pt)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ExportOnly`] for [`ExportOnly`], or [`Error::Aead`] if encryption fails.
+    fn seal(key: &[u8], nonce: &[u8], aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>, Error>;
+
+    /// Decrypt and verify `ciphertext` and `aad`, as in `Open(key, nonce, aad, ct)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ExportOnly`] for [`ExportOnly`], or [`Error::Aead`] if the ciphertext is
+    /// not authentic.
+    fn open(key: &[u8], nonce: &[u8], aad: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>, Error>;
+}
+
+macro_rules! define_aead {
+    ($name:ident, $cipher:ty, $id:literal, $n_k:literal, $doc:literal) => {
+        #[doc = $doc]
+        #[derive(Clone, Copy, Debug)]
+        pub struct $name;
+
+        impl Aead for $name {
+            const ID: u16 = $id;
+            const N_K: usize = $n_k;
+            const N_N: usize = 12;
+
+            fn seal(
+                key: &[u8],
+                nonce: &[u8],
+                aad: &[u8],
+                plaintext: &[u8],
+            ) -> Result<Vec<u8>, Error> {
+                let cipher = <$cipher>::new_from_slice(key).map_err(|_| Error::Aead)?;
+                let payload = Payload {
+                    msg: plaintext,
+                    aad,
+                };
+                cipher
+                    .encrypt(nonce.into(), payload)
+                    .map_err(|_| Error::Aead)
+            }
+
+            fn open(
+                key: &[u8],
+                nonce: &[u8],
+                aad: &[u8],
+                ciphertext: &[u8],
+            ) -> Result<Vec<u8>, Error> {
+                let cipher = <$cipher>::new_from_slice(key).map_err(|_| Error::Aead)?;
+                let payload = Payload {
+                    msg: ciphertext,
+                    aad,
+                };
+                cipher
+                    .decrypt(nonce.into(), payload)
+                    .map_err(|_| Error::Aead)
+            }
+        }
+    };
+}
+
+define_aead!(Aes128Gcm, aes_gcm::Aes128Gcm, 0x0001, 16, "AES-128-GCM");
+define_aead!(Aes256Gcm, aes_gcm::Aes256Gcm, 0x0002, 32, "AES-256-GCM");
+define_aead!(
+    ChaCha20Poly1305,
+    chacha20poly1305::ChaCha20Poly1305,
+    0x0003,
+    32,
+    "`ChaCha20Poly1305`"
+);
+
+/// The export-only "AEAD", for contexts that are only used to export secrets
+#[derive(Clone, Copy, Debug)]
+pub struct ExportOnly;
+
+impl Aead for ExportOnly {
+    const ID: u16 = 0xFFFF;
+    const N_K: usize = 0;
+    const N_N: usize = 0;
+
+    fn seal(_: &[u8], _: &[u8], _: &[u8], _: &[u8]) -> Result<Vec<u8>, Error> {
+        Err(Error::ExportOnly)
+    }
+
+    fn open(_: &[u8], _: &[u8], _: &[u8], _: &[u8]) -> Result<Vec<u8>, Error> {
+        Err(Error::ExportOnly)
+    }
+}
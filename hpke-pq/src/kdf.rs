@@ -0,0 +1,108 @@
+use alloc::vec::Vec;
+use hkdf::{Hkdf, HkdfExtract};
+use sha2::{Sha256, Sha384, Sha512};
+
+use crate::Error;
+
+/// The version label prepended to every labeled KDF input
+const HPKE_VERSION: &[u8] = b"HPKE-v1";
+
+/// A key derivation function, as in Section 4 of RFC 9180
+pub trait Kdf {
+    /// The `kdf_id` of this KDF
+    const ID: u16;
+
+    /// The output size of `Extract`, in bytes
+    const N_H: usize;
+
+    /// `LabeledExtract(salt, label, ikm)`
+    fn labeled_extract(suite_id: &[u8], salt: &[u8], label: &[u8], ikm: &[u8]) -> Vec<u8>;
+
+    /// `LabeledExpand(prk, label, info, L)`, where `L` is the length of `okm`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidLength`] if `okm` is longer than `255 * N_H` bytes or `prk` is
+    /// shorter than `N_H` bytes.
+    fn labeled_expand(
+        prk: &[u8],
+        suite_id: &[u8],
+        label: &[u8],
+        info: &[u8],
+        okm: &mut [u8],
+    ) -> Result<(), Error>;
+}
+
+macro_rules! define_hkdf {
+    ($name:ident, $hash:ty, $id:literal, $n_h:literal, $doc:literal) => {
+        #[doc = $doc]
+        #[derive(Clone, Copy, Debug)]
+        pub struct $name;
+
+        impl Kdf for $name {
+            const ID: u16 = $id;
+            const N_H: usize = $n_h;
+
+            fn labeled_extract(suite_id: &[u8], salt: &[u8], label: &[u8], ikm: &[u8]) -> Vec<u8> {
+                let mut extract = HkdfExtract::<$hash>::new(Some(salt));
+                for part in [HPKE_VERSION, suite_id, label, ikm] {
+                    extract.input_ikm(part);
+                }
+                extract.finalize().0.to_vec()
+            }
+
+            fn labeled_expand(
+                prk: &[u8],
+                suite_id: &[u8],
+                label: &[u8],
+                info: &[u8],
+                okm: &mut [u8],
+            ) -> Result<(), Error> {
+                let invalid_length = Error::InvalidLength {
+                    expected: 255 * Self::N_H,
+                    actual: okm.len(),
+                };
+                let length = u16::try_from(okm.len())
+                    .map_err(|_| invalid_length)?
+                    .to_be_bytes();
+                let hkdf = Hkdf::<$hash>::from_prk(prk).map_err(|_| Error::InvalidLength {
+                    expected: Self::N_H,
+                    actual: prk.len(),
+                })?;
+                hkdf.expand_multi_info(&[&length, HPKE_VERSION, suite_id, label, info], okm)
+                    .map_err(|_| invalid_length)
+            }
+        }
+    };
+}
+
+define_hkdf!(HkdfSha256, Sha256, 0x0001, 32, "HKDF-SHA256");
+define_hkdf!(HkdfSha384, Sha384, 0x0002, 48, "HKDF-SHA384");
+define_hkdf!(HkdfSha512, Sha512, 0x0003, 64, "HKDF-SHA512");
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn labeled_expand() {
+        let prk = HkdfSha256::labeled_extract(b"suite", b"", b"label", b"ikm");
+        assert_eq!(prk.len(), HkdfSha256::N_H);
+
+        // The length is part of the input, so a shorter output is not a prefix of a longer one
+        let mut short = [0u8; 16];
+        let mut long = [0u8; 32];
+        HkdfSha256::labeled_expand(&prk, b"suite", b"label", b"info", &mut short).unwrap();
+        HkdfSha256::labeled_expand(&prk, b"suite", b"label", b"info", &mut long).unwrap();
+        assert_ne!(short, long[..16]);
+
+        let mut too_long = [0u8; 255 * 32 + 1];
+        assert_eq!(
+            HkdfSha256::labeled_expand(&prk, b"suite", b"label", b"info", &mut too_long),
+            Err(Error::InvalidLength {
+                expected: 255 * 32,
+                actual: 255 * 32 + 1
+            })
+        );
+    }
+}
@@ -0,0 +1,369 @@
+use ::kem::{Decapsulate, Encapsulate};
+use alloc::vec::Vec;
+use ml_kem::{EncodedSizeUser, KemCore, Seed};
+use rand_core::CryptoRngCore;
+
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+use sha3::Shake256;
+
+use crate::Error;
+
+/// A key encapsulation mechanism, as in Section 4 of RFC 9180
+pub trait Kem {
+    /// The `kem_id` of this KEM
+    const ID: u16;
+
+    /// The size of a shared secret, in bytes
+    const N_SECRET: usize;
+
+    /// The size of an encapsulated key, in bytes
+    const N_ENC: usize;
+
+    /// The size of a serialized public key, in bytes
+    const N_PK: usize;
+
+    /// The size of a serialized private key, in bytes
+    const N_SK: usize;
+
+    /// A public key, to which shared secrets are encapsulated
+    type PublicKey: Clone;
+
+    /// A private key, with which shared secrets are decapsulated
+    type PrivateKey;
+
+    /// `GenerateKeyPair()`.  Panics if the RNG fails.
+    fn generate_key_pair(rng: &mut impl CryptoRngCore) -> (Self::PrivateKey, Self::PublicKey);
+
+    /// `DeriveKeyPair(ikm)`, which deterministically derives a key pair from the input keying
+    /// material `ikm`
+    fn derive_key_pair(ikm: &[u8]) -> (Self::PrivateKey, Self::PublicKey);
+
+    /// `SerializePublicKey(pkX)`
+    fn serialize_public_key(pk: &Self::PublicKey) -> Vec<u8>;
+
+    /// `DeserializePublicKey(pkXm)`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Kem`] if `bytes` is not a valid public key.
+    fn deserialize_public_key(bytes: &[u8]) -> Result<Self::PublicKey, Error>;
+
+    /// `SerializePrivateKey(skX)`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ExpandedPrivateKey`] if `sk` cannot be serialized in the form that
+    /// `DeserializePrivateKey` expects, because it was not expanded from a seed.
+    fn serialize_private_key(sk: &Self::PrivateKey) -> Result<Vec<u8>, Error>;
+
+    /// `DeserializePrivateKey(skXm)`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Kem`] if `bytes` is not a valid private key.
+    fn deserialize_private_key(bytes: &[u8]) -> Result<Self::PrivateKey, Error>;
+
+    /// `Encap(pkR)`, which returns a shared secret and its encapsulation `enc`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Kem`] if the RNG fails.
+    fn encap(
+        pk: &Self::PublicKey,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<(Vec<u8>, Vec<u8>), Error>;
+
+    /// `Decap(enc, skR)`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Kem`] if `enc` has the wrong length.
+    fn decap(enc: &[u8], sk: &Self::PrivateKey) -> Result<Vec<u8>, Error>;
+}
+
+/// Derive the seed of a key pair from `ikm`, as in `DeriveKeyPair` for ML-KEM in
+/// draft-ietf-hpke-pq and for X-Wing in draft-connolly-cfrg-xwing-kem:
+///
+/// ```text
+/// seed = SHAKE256(ikm, Nsk)
+/// ```
+fn derive_seed(ikm: &[u8], seed: &mut [u8]) {
+    Shake256::default().chain(ikm).finalize_xof().read(seed);
+}
+
+macro_rules! define_ml_kem {
+    ($name:ident, $kem:ty, $params:ty, $id:literal, $n_enc:literal, $n_pk:literal, $doc:literal) => {
+        #[doc = $doc]
+        #[derive(Clone, Copy, Debug)]
+        pub struct $name;
+
+        impl Kem for $name {
+            const ID: u16 = $id;
+            const N_SECRET: usize = 32;
+            const N_ENC: usize = $n_enc;
+            const N_PK: usize = $n_pk;
+            const N_SK: usize = 64;
+
+            type PublicKey = ml_kem::kem::EncapsulationKey<$params>;
+
+            /// Private keys are expanded from, and serialized as, the 64-byte seed `(d || z)`.  A
+            /// key parsed from its expanded encoding has no seed, so it cannot be serialized.
+            type PrivateKey = ml_kem::kem::DecapsulationKey<$params>;
+
+            fn generate_key_pair(
+                rng: &mut impl CryptoRngCore,
+            ) -> (Self::PrivateKey, Self::PublicKey) {
+                <$kem>::generate(rng)
+            }
+
+            fn derive_key_pair(ikm: &[u8]) -> (Self::PrivateKey, Self::PublicKey) {
+                let mut seed = Seed::default();
+                derive_seed(ikm, &mut seed);
+                let key_pair = <$kem>::from_seed(&seed);
+                wipe!(seed);
+                key_pair
+            }
+
+            fn serialize_public_key(pk: &Self::PublicKey) -> Vec<u8> {
+                pk.as_bytes().to_vec()
+            }
+
+            fn deserialize_public_key(bytes: &[u8]) -> Result<Self::PublicKey, Error> {
+                Ok(Self::PublicKey::try_from(bytes)?)
+            }
+
+            fn serialize_private_key(sk: &Self::PrivateKey) -> Result<Vec<u8>, Error> {
+                let mut seed = sk.to_seed().ok_or(Error::ExpandedPrivateKey)?;
+                let bytes = seed.to_vec();
+                wipe!(seed);
+                Ok(bytes)
+            }
+
+            fn deserialize_private_key(bytes: &[u8]) -> Result<Self::PrivateKey, Error> {
+                let mut seed = Seed::try_from(bytes).map_err(|_| ml_kem::Error::InvalidLength {
+                    expected: Self::N_SK,
+                    actual: bytes.len(),
+                })?;
+                let sk = Self::PrivateKey::from_seed(&seed);
+                wipe!(seed);
+                Ok(sk)
+            }
+
+            fn encap(
+                pk: &Self::PublicKey,
+                rng: &mut impl CryptoRngCore,
+            ) -> Result<(Vec<u8>, Vec<u8>), Error> {
+                let (ct, mut ss) = pk.encapsulate(rng)?;
+                let shared_secret = ss.to_vec();
+                wipe!(ss);
+                Ok((shared_secret, ct.as_ref().to_vec()))
+            }
+
+            fn decap(enc: &[u8], sk: &Self::PrivateKey) -> Result<Vec<u8>, Error> {
+                let ct = ml_kem::Ciphertext::<$kem>::try_from(enc)?;
+                let mut ss = sk.decapsulate(&ct)?;
+                let shared_secret = ss.to_vec();
+                wipe!(ss);
+                Ok(shared_secret)
+            }
+        }
+    };
+}
+
+define_ml_kem!(
+    MlKem512,
+    ml_kem::MlKem512,
+    ml_kem::MlKem512Params,
+    0x0040,
+    768,
+    800,
+    "ML-KEM-512"
+);
+define_ml_kem!(
+    MlKem768,
+    ml_kem::MlKem768,
+    ml_kem::MlKem768Params,
+    0x0041,
+    1088,
+    1184,
+    "ML-KEM-768"
+);
+define_ml_kem!(
+    MlKem1024,
+    ml_kem::MlKem1024,
+    ml_kem::MlKem1024Params,
+    0x0042,
+    1568,
+    1568,
+    "ML-KEM-1024"
+);
+
+/// X-Wing, the hybrid of ML-KEM-768 and X25519
+#[derive(Clone, Copy, Debug)]
+pub struct XWing;
+
+impl Kem for XWing {
+    const ID: u16 = 0x647a;
+    const N_SECRET: usize = x_wing::SHARED_KEY_SIZE;
+    const N_ENC: usize = x_wing::CIPHERTEXT_SIZE;
+    const N_PK: usize = x_wing::ENCAPSULATION_KEY_SIZE;
+    const N_SK: usize = x_wing::DECAPSULATION_KEY_SIZE;
+
+    type PublicKey = x_wing::EncapsulationKey;
+
+    /// Private keys are the 32-byte X-Wing seeds
+    type PrivateKey = x_wing::DecapsulationKey;
+
+    fn generate_key_pair(rng: &mut impl CryptoRngCore) -> (Self::PrivateKey, Self::PublicKey) {
+        x_wing::generate_key_pair(rng)
+    }
+
+    fn derive_key_pair(ikm: &[u8]) -> (Self::PrivateKey, Self::PublicKey) {
+        let mut seed = [0u8; x_wing::DECAPSULATION_KEY_SIZE];
+        derive_seed(ikm, &mut seed);
+        let sk = x_wing::DecapsulationKey::from(seed);
+        wipe!(seed);
+        let pk = sk.encapsulation_key();
+        (sk, pk)
+    }
+
+    fn serialize_public_key(pk: &Self::PublicKey) -> Vec<u8> {
+        pk.as_bytes().to_vec()
+    }
+
+    fn deserialize_public_key(bytes: &[u8]) -> Result<Self::PublicKey, Error> {
+        Ok(Self::PublicKey::try_from(bytes)?)
+    }
+
+    fn serialize_private_key(sk: &Self::PrivateKey) -> Result<Vec<u8>, Error> {
+        Ok(sk.as_bytes().to_vec())
+    }
+
+    fn deserialize_private_key(bytes: &[u8]) -> Result<Self::PrivateKey, Error> {
+        Ok(Self::PrivateKey::try_from(bytes)?)
+    }
+
+    fn encap(
+        pk: &Self::PublicKey,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<(Vec<u8>, Vec<u8>), Error> {
+        let (ct, mut ss) = pk.encapsulate(rng)?;
+        let shared_secret = ss.to_vec();
+        wipe!(ss);
+        Ok((shared_secret, ct.as_bytes().to_vec()))
+    }
+
+    fn decap(enc: &[u8], sk: &Self::PrivateKey) -> Result<Vec<u8>, Error> {
+        let ct = x_wing::Ciphertext::try_from(enc)?;
+        let mut ss = sk.decapsulate(&ct)?;
+        let shared_secret = ss.to_vec();
+        wipe!(ss);
+        Ok(shared_secret)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use hex_literal::hex;
+    use sha2::{Digest, Sha256};
+
+    fn kem_test<K: Kem>()
+    where
+        K::PublicKey: PartialEq + core::fmt::Debug,
+    {
+        let mut rng = rand::thread_rng();
+        let (sk, pk) = K::generate_key_pair(&mut rng);
+
+        let pk_bytes = K::serialize_public_key(&pk);
+        assert_eq!(pk_bytes.len(), K::N_PK);
+        assert_eq!(K::deserialize_public_key(&pk_bytes).unwrap(), pk);
+
+        let sk_bytes = K::serialize_private_key(&sk).unwrap();
+        assert_eq!(sk_bytes.len(), K::N_SK);
+        let sk = K::deserialize_private_key(&sk_bytes).unwrap();
+
+        let (ss, enc) = K::encap(&pk, &mut rng).unwrap();
+        assert_eq!(ss.len(), K::N_SECRET);
+        assert_eq!(enc.len(), K::N_ENC);
+        assert_eq!(K::decap(&enc, &sk).unwrap(), ss);
+        assert!(K::decap(&enc[1..], &sk).is_err());
+
+        // `DeriveKeyPair` is deterministic, and depends on all of `ikm`
+        let (sk1, pk1) = K::derive_key_pair(b"input keying material");
+        let (sk2, pk2) = K::derive_key_pair(b"input keying material");
+        let (_, pk3) = K::derive_key_pair(b"input keying materiaL");
+        assert_eq!(
+            K::serialize_private_key(&sk1).unwrap(),
+            K::serialize_private_key(&sk2).unwrap()
+        );
+        assert_eq!(pk1, pk2);
+        assert_ne!(pk1, pk3);
+    }
+
+    #[test]
+    fn ml_kem() {
+        kem_test::<MlKem512>();
+        kem_test::<MlKem768>();
+        kem_test::<MlKem1024>();
+    }
+
+    #[test]
+    fn x_wing() {
+        kem_test::<XWing>();
+    }
+
+    fn derive_key_pair_test<K: Kem>(ikm: &[u8], seed: &[u8], pk_digest: [u8; 32]) {
+        let (sk, pk) = K::derive_key_pair(ikm);
+        assert_eq!(K::serialize_private_key(&sk).unwrap(), &seed[..K::N_SK]);
+        assert_eq!(
+            Sha256::digest(K::serialize_public_key(&pk)).as_slice(),
+            pk_digest
+        );
+    }
+
+    #[test]
+    fn derive_key_pair() {
+        // The seed was computed independently with Python's `hashlib`, and the digests of the
+        // public keys with OpenSSL 3.5 for ML-KEM and with the `cryptography` package for X-Wing.
+        // The seeds are all prefixes of the same SHAKE256 output.
+        let ikm = [0x5a; 32];
+        let seed = hex!(
+            "bb7fbe60ca23013e1ba589647d74cb72d9998a7ee681196d077a83bbf21ac956"
+            "b372ed936a4f72c80fae40a1db9d551e5e10fa61315c6aeadc5fa4a79773d9b0"
+        );
+
+        derive_key_pair_test::<MlKem512>(
+            &ikm,
+            &seed,
+            hex!("fc97e6a4d34ab1ff30167426d1a22fd9f5f8290e37d236e954d854c72df0c13d"),
+        );
+        derive_key_pair_test::<MlKem768>(
+            &ikm,
+            &seed,
+            hex!("81ece404cf4e2e23d2cd88cf3680722cd1e1e1cf96dd9929477cc4eabb579314"),
+        );
+        derive_key_pair_test::<MlKem1024>(
+            &ikm,
+            &seed,
+            hex!("d50d6b922435f30cce6335805755bb935ddc16aa3068a5d9bb9600b99e42c1df"),
+        );
+        derive_key_pair_test::<XWing>(
+            &ikm,
+            &seed,
+            hex!("bbe2d4e418a875b2369bf736e399bbafe7f042b5311f7435ee4d633c881c3e2b"),
+        );
+    }
+
+    #[test]
+    fn serialize_expanded_private_key() {
+        // A key parsed from its expanded encoding cannot be serialized as a seed
+        let (sk, _) = MlKem768::derive_key_pair(b"input keying material");
+        let expanded =
+            ml_kem::kem::DecapsulationKey::<ml_kem::MlKem768Params>::from_bytes(&sk.as_bytes());
+        assert_eq!(
+            MlKem768::serialize_private_key(&expanded),
+            Err(Error::ExpandedPrivateKey)
+        );
+    }
+}
@@ -0,0 +1,576 @@
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![doc = include_str!("../README.md")]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![warn(clippy::pedantic)] // Be pedantic by default
+#![deny(missing_docs)] // Require all public interfaces to be documented
+
+//! # Usage
+//!
+//! A cipher suite is chosen with the type parameters of the setup functions: a [`kem::Kem`], a
+//! [`kdf::Kdf`], and an [`aead::Aead`].
+//!
+//! ```
+//! use hpke_pq::{aead::Aes128Gcm, kdf::HkdfSha256, kem::{Kem, MlKem768}};
+//! let mut rng = rand::thread_rng();
+//!
+//! // The recipient derives a key pair and publishes the public key
+//! let (sk, pk) = MlKem768::derive_key_pair(b"input keying material of the recipient");
+//! let pk_bytes = MlKem768::serialize_public_key(&pk);
+//!
+//! // The sender sets up a context to the public key, and sends `enc` along with its messages
+//! let pk = MlKem768::deserialize_public_key(&pk_bytes)?;
+//! let (enc, mut sender) =
+//!     hpke_pq::setup_base_s::<MlKem768, HkdfSha256, Aes128Gcm>(&pk, b"info", &mut rng)?;
+//! let ct = sender.seal(b"aad", b"hello")?;
+//!
+//! // The recipient sets up the matching context from `enc`
+//! let mut receiver = hpke_pq::setup_base_r::<MlKem768, HkdfSha256, Aes128Gcm>(&enc, &sk, b"info")?;
+//! assert_eq!(receiver.open(b"aad", &ct)?, b"hello");
+//!
+//! // Both sides can export the same secrets
+//! let mut exported_s = [0u8; 32];
+//! let mut exported_r = [0u8; 32];
+//! sender.export(b"context", &mut exported_s)?;
+//! receiver.export(b"context", &mut exported_r)?;
+//! assert_eq!(exported_s, exported_r);
+//! # Ok::<(), hpke_pq::Error>(())
+//! ```
+
+extern crate alloc;
+
+/// Wipe secret intermediate values from memory when the `zeroize` feature is enabled.  Without
+/// the feature, this is a no-op that still borrows its arguments mutably, so that call sites
+/// compile identically in both configurations.
+macro_rules! wipe {
+    ($($x:expr),+ $(,)?) => {
+        $(
+            #[cfg(feature = "zeroize")]
+            zeroize::Zeroize::zeroize(&mut $x);
+            #[cfg(not(feature = "zeroize"))]
+            let _ = &mut $x;
+        )+
+    };
+}
+
+/// Authenticated encryption with associated data (AEAD) algorithms
+pub mod aead;
+
+/// Key derivation functions
+pub mod kdf;
+
+/// Key encapsulation mechanisms
+pub mod kem;
+
+use alloc::vec::Vec;
+use core::{fmt, marker::PhantomData};
+use rand_core::CryptoRngCore;
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+use crate::aead::Aead;
+use crate::kdf::Kdf;
+use crate::kem::Kem;
+
+/// Errors that can result from operations in this crate
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// A KEM operation failed, e.g., because a public key or an encapsulated key was invalid.
+    Kem(ml_kem::Error),
+
+    /// An input or output had the wrong length.
+    InvalidLength {
+        /// The length required, or the largest length allowed
+        expected: usize,
+
+        /// The length that was provided
+        actual: usize,
+    },
+
+    /// The PSK inputs were inconsistent: `psk` and `psk_id` must both be empty in base mode, and
+    /// both be nonempty in PSK mode.
+    InconsistentPsk,
+
+    /// Opening a ciphertext failed, because the ciphertext or the associated data is not
+    /// authentic.
+    Aead,
+
+    /// The context uses the export-only AEAD, so it cannot seal or open messages.
+    ExportOnly,
+
+    /// The context has reached its message limit, because its sequence number would overflow.
+    MessageLimitReached,
+
+    /// A private key was parsed from its expanded encoding, so it has no seed to be serialized as.
+    ExpandedPrivateKey,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Kem(e) => write!(f, "KEM error: {e}"),
+            Self::InvalidLength { expected, actual } => {
+                write!(f, "invalid length: expected {expected} bytes, got {actual}")
+            }
+            Self::InconsistentPsk => f.write_str("inconsistent PSK inputs"),
+            Self::Aead => f.write_str("AEAD error"),
+            Self::ExportOnly => f.write_str("export-only context cannot seal or open"),
+            Self::MessageLimitReached => f.write_str("message limit reached"),
+            Self::ExpandedPrivateKey => f.write_str("expanded private key has no seed"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+impl From<ml_kem::Error> for Error {
+    fn from(e: ml_kem::Error) -> Self {
+        Self::Kem(e)
+    }
+}
+
+/// The HPKE modes of Section 5 of RFC 9180 that work with KEMs without authentication
+#[derive(Clone, Copy)]
+#[repr(u8)]
+enum Mode {
+    Base = 0x00,
+    Psk = 0x01,
+}
+
+/// The `suite_id` of a cipher suite, `"HPKE" || I2OSP(kem_id, 2) || I2OSP(kdf_id, 2) ||
+/// I2OSP(aead_id, 2)`
+fn hpke_suite_id<K: Kem, F: Kdf, A: Aead>() -> [u8; 10] {
+    let mut suite_id = [0u8; 10];
+    suite_id[..4].copy_from_slice(b"HPKE");
+    suite_id[4..6].copy_from_slice(&K::ID.to_be_bytes());
+    suite_id[6..8].copy_from_slice(&F::ID.to_be_bytes());
+    suite_id[8..].copy_from_slice(&A::ID.to_be_bytes());
+    suite_id
+}
+
+/// The encryption context derived by `KeySchedule` in Section 5.1 of RFC 9180
+struct Context<F: Kdf, A: Aead> {
+    suite_id: [u8; 10],
+    key: Vec<u8>,
+    base_nonce: Vec<u8>,
+    exporter_secret: Vec<u8>,
+    seq: u64,
+    suite: PhantomData<(F, A)>,
+}
+
+impl<F: Kdf, A: Aead> Context<F, A> {
+    fn key_schedule<K: Kem>(
+        mode: Mode,
+        shared_secret: &[u8],
+        info: &[u8],
+        psk: &[u8],
+        psk_id: &[u8],
+    ) -> Result<Self, Error> {
+        // VerifyPSKInputs
+        let got_psk = !psk.is_empty();
+        if got_psk == psk_id.is_empty() || got_psk != matches!(mode, Mode::Psk) {
+            return Err(Error::InconsistentPsk);
+        }
+
+        let suite_id = hpke_suite_id::<K, F, A>();
+        let psk_id_hash = F::labeled_extract(&suite_id, b"", b"psk_id_hash", psk_id);
+        let info_hash = F::labeled_extract(&suite_id, b"", b"info_hash", info);
+        let mut key_schedule_context = Vec::with_capacity(1 + 2 * F::N_H);
+        key_schedule_context.push(mode as u8);
+        key_schedule_context.extend_from_slice(&psk_id_hash);
+        key_schedule_context.extend_from_slice(&info_hash);
+
+        let mut secret = F::labeled_extract(&suite_id, shared_secret, b"secret", psk);
+        let mut ctx = Self {
+            suite_id,
+            key: alloc::vec![0u8; A::N_K],
+            base_nonce: alloc::vec![0u8; A::N_N],
+            exporter_secret: alloc::vec![0u8; F::N_H],
+            seq: 0,
+            suite: PhantomData,
+        };
+        // `secret` is wiped whether or not the expansion succeeds, and `ctx` wipes itself on drop
+        let expanded = F::labeled_expand(
+            &secret,
+            &suite_id,
+            b"key",
+            &key_schedule_context,
+            &mut ctx.key,
+        )
+        .and_then(|()| {
+            F::labeled_expand(
+                &secret,
+                &suite_id,
+                b"base_nonce",
+                &key_schedule_context,
+                &mut ctx.base_nonce,
+            )
+        })
+        .and_then(|()| {
+            F::labeled_expand(
+                &secret,
+                &suite_id,
+                b"exp",
+                &key_schedule_context,
+                &mut ctx.exporter_secret,
+            )
+        });
+        wipe!(secret);
+        expanded?;
+        Ok(ctx)
+    }
+
+    /// `ComputeNonce(seq)`, the XOR of the base nonce and the big-endian sequence number
+    fn compute_nonce(&self) -> Vec<u8> {
+        let mut nonce = self.base_nonce.clone();
+        let seq = self.seq.to_be_bytes();
+        for (n, s) in nonce.iter_mut().rev().zip(seq.iter().rev()) {
+            *n ^= s;
+        }
+        nonce
+    }
+
+    /// Check that the sequence number can be incremented after the next message, as in
+    /// `IncrementSeq()`.  The limit of `2^(8 * N_N) - 1` messages is larger than `u64::MAX` for
+    /// every AEAD that has a nonce.
+    fn check_seq(&self) -> Result<(), Error> {
+        if self.seq == u64::MAX {
+            Err(Error::MessageLimitReached)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn export(&self, exporter_context: &[u8], out: &mut [u8]) -> Result<(), Error> {
+        F::labeled_expand(
+            &self.exporter_secret,
+            &self.suite_id,
+            b"sec",
+            exporter_context,
+            out,
+        )
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<F: Kdf, A: Aead> Zeroize for Context<F, A> {
+    fn zeroize(&mut self) {
+        self.key.zeroize();
+        self.exporter_secret.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<F: Kdf, A: Aead> Drop for Context<F, A> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+/// The sender's encryption context, `ContextS`
+pub struct SenderContext<F: Kdf, A: Aead>(Context<F, A>);
+
+impl<F: Kdf, A: Aead> SenderContext<F, A> {
+    /// Encrypt `plaintext` with the associated data `aad`, as in `ContextS.Seal(aad, pt)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ExportOnly`] if the AEAD is [`aead::ExportOnly`], or
+    /// [`Error::MessageLimitReached`] if the context has sealed too many messages.
+    pub fn seal(&mut self, aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>, Error> {
+        self.0.check_seq()?;
+        let ct = A::seal(&self.0.key, &self.0.compute_nonce(), aad, plaintext)?;
+        self.0.seq += 1;
+        Ok(ct)
+    }
+
+    /// Export a secret of the length of `out`, as in `Context.Export(exporter_context, L)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidLength`] if `out` is longer than `255 * N_H` bytes.
+    pub fn export(&self, exporter_context: &[u8], out: &mut [u8]) -> Result<(), Error> {
+        self.0.export(exporter_context, out)
+    }
+}
+
+/// The recipient's encryption context, `ContextR`
+pub struct ReceiverContext<F: Kdf, A: Aead>(Context<F, A>);
+
+impl<F: Kdf, A: Aead> ReceiverContext<F, A> {
+    /// Decrypt `ciphertext` with the associated data `aad`, as in `ContextR.Open(aad, ct)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Aead`] if the ciphertext is not authentic, [`Error::ExportOnly`] if the
+    /// AEAD is [`aead::ExportOnly`], or [`Error::MessageLimitReached`] if the context has opened
+    /// too many messages.
+    pub fn open(&mut self, aad: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>, Error> {
+        self.0.check_seq()?;
+        let pt = A::open(&self.0.key, &self.0.compute_nonce(), aad, ciphertext)?;
+        self.0.seq += 1;
+        Ok(pt)
+    }
+
+    /// Export a secret of the length of `out`, as in `Context.Export(exporter_context, L)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidLength`] if `out` is longer than `255 * N_H` bytes.
+    pub fn export(&self, exporter_context: &[u8], out: &mut [u8]) -> Result<(), Error> {
+        self.0.export(exporter_context, out)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<F: Kdf, A: Aead> zeroize::ZeroizeOnDrop for SenderContext<F, A> {}
+
+#[cfg(feature = "zeroize")]
+impl<F: Kdf, A: Aead> zeroize::ZeroizeOnDrop for ReceiverContext<F, A> {}
+
+/// Set up a sender context in base mode, `SetupBaseS(pkR, info)`, returning the encapsulated
+/// key `enc` along with the context.
+///
+/// # Errors
+///
+/// Returns [`Error::Kem`] if the RNG fails.
+pub fn setup_base_s<K: Kem, F: Kdf, A: Aead>(
+    pk: &K::PublicKey,
+    info: &[u8],
+    rng: &mut impl CryptoRngCore,
+) -> Result<(Vec<u8>, SenderContext<F, A>), Error> {
+    let (mut shared_secret, enc) = K::encap(pk, rng)?;
+    let ctx = Context::key_schedule::<K>(Mode::Base, &shared_secret, info, b"", b"");
+    wipe!(shared_secret);
+    Ok((enc, SenderContext(ctx?)))
+}
+
+/// Set up a recipient context in base mode, `SetupBaseR(enc, skR, info)`.
+///
+/// # Errors
+///
+/// Returns [`Error::Kem`] if `enc` has the wrong length.
+pub fn setup_base_r<K: Kem, F: Kdf, A: Aead>(
+    enc: &[u8],
+    sk: &K::PrivateKey,
+    info: &[u8],
+) -> Result<ReceiverContext<F, A>, Error> {
+    let mut shared_secret = K::decap(enc, sk)?;
+    let ctx = Context::key_schedule::<K>(Mode::Base, &shared_secret, info, b"", b"");
+    wipe!(shared_secret);
+    Ok(ReceiverContext(ctx?))
+}
+
+/// Set up a sender context in PSK mode, `SetupPSKS(pkR, info, psk, psk_id)`, returning the
+/// encapsulated key `enc` along with the context.
+///
+/// # Errors
+///
+/// Returns [`Error::InconsistentPsk`] if `psk` or `psk_id` is empty, or [`Error::Kem`] if the
+/// RNG fails.
+pub fn setup_psk_s<K: Kem, F: Kdf, A: Aead>(
+    pk: &K::PublicKey,
+    info: &[u8],
+    psk: &[u8],
+    psk_id: &[u8],
+    rng: &mut impl CryptoRngCore,
+) -> Result<(Vec<u8>, SenderContext<F, A>), Error> {
+    let (mut shared_secret, enc) = K::encap(pk, rng)?;
+    let ctx = Context::key_schedule::<K>(Mode::Psk, &shared_secret, info, psk, psk_id);
+    wipe!(shared_secret);
+    Ok((enc, SenderContext(ctx?)))
+}
+
+/// Set up a recipient context in PSK mode, `SetupPSKR(enc, skR, info, psk, psk_id)`.
+///
+/// # Errors
+///
+/// Returns [`Error::InconsistentPsk`] if `psk` or `psk_id` is empty, or [`Error::Kem`] if `enc`
+/// has the wrong length.
+pub fn setup_psk_r<K: Kem, F: Kdf, A: Aead>(
+    enc: &[u8],
+    sk: &K::PrivateKey,
+    info: &[u8],
+    psk: &[u8],
+    psk_id: &[u8],
+) -> Result<ReceiverContext<F, A>, Error> {
+    let mut shared_secret = K::decap(enc, sk)?;
+    let ctx = Context::key_schedule::<K>(Mode::Psk, &shared_secret, info, psk, psk_id);
+    wipe!(shared_secret);
+    Ok(ReceiverContext(ctx?))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::aead::{Aes128Gcm, Aes256Gcm, ChaCha20Poly1305, ExportOnly};
+    use crate::kdf::{HkdfSha256, HkdfSha384, HkdfSha512};
+    use crate::kem::{MlKem1024, MlKem512, MlKem768, XWing};
+
+    const PSK: &[u8] = b"a pre-shared key of at least 32 bytes";
+    const PSK_ID: &[u8] = b"psk id";
+
+    fn contexts<K: Kem, F: Kdf, A: Aead>(
+        psk: bool,
+    ) -> (SenderContext<F, A>, ReceiverContext<F, A>) {
+        let mut rng = rand::thread_rng();
+        let (sk, pk) = K::generate_key_pair(&mut rng);
+        if psk {
+            let (enc, sender) =
+                setup_psk_s::<K, F, A>(&pk, b"info", PSK, PSK_ID, &mut rng).unwrap();
+            let receiver = setup_psk_r::<K, F, A>(&enc, &sk, b"info", PSK, PSK_ID).unwrap();
+            (sender, receiver)
+        } else {
+            let (enc, sender) = setup_base_s::<K, F, A>(&pk, b"info", &mut rng).unwrap();
+            let receiver = setup_base_r::<K, F, A>(&enc, &sk, b"info").unwrap();
+            (sender, receiver)
+        }
+    }
+
+    fn suite_test<K: Kem, F: Kdf, A: Aead>() {
+        for psk in [false, true] {
+            let (mut sender, mut receiver) = contexts::<K, F, A>(psk);
+            for i in 0..3u8 {
+                let pt = [i; 40];
+                let ct = sender.seal(&[i], &pt).unwrap();
+                assert_eq!(ct.len(), pt.len() + 16);
+                assert_eq!(receiver.open(&[i], &ct).unwrap(), pt);
+            }
+
+            // A message is bound to its sequence number and associated data
+            let ct = sender.seal(b"aad", b"first").unwrap();
+            sender.seal(b"aad", b"second").unwrap();
+            let ct2 = sender.seal(b"aad", b"third").unwrap();
+            assert_eq!(receiver.open(b"aad", &ct2), Err(Error::Aead));
+            assert_eq!(receiver.open(b"bad", &ct), Err(Error::Aead));
+
+            let mut exported_s = [0u8; 64];
+            let mut exported_r = [0u8; 64];
+            sender.export(b"context", &mut exported_s).unwrap();
+            receiver.export(b"context", &mut exported_r).unwrap();
+            assert_eq!(exported_s, exported_r);
+            receiver.export(b"other", &mut exported_r).unwrap();
+            assert_ne!(exported_s, exported_r);
+        }
+    }
+
+    #[test]
+    fn suites() {
+        suite_test::<MlKem512, HkdfSha256, Aes128Gcm>();
+        suite_test::<MlKem768, HkdfSha256, Aes128Gcm>();
+        suite_test::<MlKem768, HkdfSha384, Aes256Gcm>();
+        suite_test::<MlKem1024, HkdfSha512, ChaCha20Poly1305>();
+        suite_test::<XWing, HkdfSha256, ChaCha20Poly1305>();
+    }
+
+    #[test]
+    fn modes_differ() {
+        // Base and PSK mode derive different secrets from the same shared secret
+        let mut rng = rand::thread_rng();
+        let (sk, pk) = XWing::generate_key_pair(&mut rng);
+        let (enc, sender) =
+            setup_psk_s::<XWing, HkdfSha256, ExportOnly>(&pk, b"", PSK, PSK_ID, &mut rng).unwrap();
+        let base = setup_base_r::<XWing, HkdfSha256, ExportOnly>(&enc, &sk, b"").unwrap();
+        let wrong_psk =
+            setup_psk_r::<XWing, HkdfSha256, ExportOnly>(&enc, &sk, b"", b"another psk", PSK_ID)
+                .unwrap();
+
+        let mut exported_s = [0u8; 32];
+        let mut exported_r = [0u8; 32];
+        sender.export(b"", &mut exported_s).unwrap();
+        base.export(b"", &mut exported_r).unwrap();
+        assert_ne!(exported_s, exported_r);
+        wrong_psk.export(b"", &mut exported_r).unwrap();
+        assert_ne!(exported_s, exported_r);
+    }
+
+    #[test]
+    fn export_only() {
+        let (mut sender, mut receiver) = contexts::<MlKem768, HkdfSha256, ExportOnly>(false);
+        assert_eq!(sender.seal(b"", b"message").err(), Some(Error::ExportOnly));
+        assert_eq!(
+            receiver.open(b"", b"message").err(),
+            Some(Error::ExportOnly)
+        );
+
+        let mut exported_s = [0u8; 255 * 32];
+        let mut exported_r = [0u8; 255 * 32];
+        sender.export(b"context", &mut exported_s).unwrap();
+        receiver.export(b"context", &mut exported_r).unwrap();
+        assert_eq!(exported_s, exported_r);
+
+        let mut too_long = [0u8; 255 * 32 + 1];
+        assert_eq!(
+            sender.export(b"context", &mut too_long),
+            Err(Error::InvalidLength {
+                expected: 255 * 32,
+                actual: 255 * 32 + 1
+            })
+        );
+    }
+
+    #[test]
+    fn inconsistent_psk() {
+        let mut rng = rand::thread_rng();
+        let (sk, pk) = MlKem768::generate_key_pair(&mut rng);
+        for (psk, psk_id) in [(PSK, &b""[..]), (&b""[..], PSK_ID), (&b""[..], &b""[..])] {
+            assert_eq!(
+                setup_psk_s::<MlKem768, HkdfSha256, Aes128Gcm>(&pk, b"", psk, psk_id, &mut rng)
+                    .err(),
+                Some(Error::InconsistentPsk)
+            );
+        }
+
+        let (enc, _) = setup_base_s::<MlKem768, HkdfSha256, Aes128Gcm>(&pk, b"", &mut rng).unwrap();
+        assert!(setup_psk_r::<MlKem768, HkdfSha256, Aes128Gcm>(&enc, &sk, b"", PSK, b"").is_err());
+    }
+
+    #[test]
+    fn nonce() {
+        let (mut sender, _) = contexts::<MlKem768, HkdfSha256, Aes128Gcm>(false);
+        assert_eq!(sender.0.compute_nonce(), sender.0.base_nonce);
+
+        sender.0.seq = 0x0102;
+        let nonce = sender.0.compute_nonce();
+        assert_eq!(nonce[..10], sender.0.base_nonce[..10]);
+        assert_eq!(nonce[10], sender.0.base_nonce[10] ^ 0x01);
+        assert_eq!(nonce[11], sender.0.base_nonce[11] ^ 0x02);
+
+        sender.0.seq = u64::MAX;
+        assert_eq!(
+            sender.seal(b"", b"").err(),
+            Some(Error::MessageLimitReached)
+        );
+    }
+
+    #[test]
+    fn suite_id() {
+        assert_eq!(
+            hpke_suite_id::<XWing, HkdfSha256, ChaCha20Poly1305>(),
+            *b"HPKE\x64\x7a\x00\x01\x00\x03"
+        );
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn zeroize() {
+        let mut ctx = Context::<HkdfSha256, Aes128Gcm>::key_schedule::<MlKem768>(
+            Mode::Base,
+            &[0x5a; 32],
+            b"info",
+            b"",
+            b"",
+        )
+        .unwrap();
+        assert!(ctx.key.iter().chain(&ctx.exporter_secret).any(|&b| b != 0));
+        ctx.zeroize();
+
+        assert!(ctx.key.is_empty());
+        assert!(ctx.exporter_secret.is_empty());
+    }
+}
@@ -0,0 +1,189 @@
+//! Messages to ML-KEM and X-Wing keys sealed by another HPKE implementation, which this crate
+//! must open.
+
+use hex_literal::hex;
+use hpke_pq::aead::{Aead, Aes128Gcm, Aes256Gcm, ChaCha20Poly1305};
+use hpke_pq::kdf::{HkdfSha256, HkdfSha384, Kdf};
+use hpke_pq::kem::{Kem, MlKem1024, MlKem768, XWing};
+use hpke_pq::setup_base_r;
+
+// The messages were sealed in base mode with the HPKE implementation of Python's `cryptography`
+// package (version 48, on OpenSSL), whose `MLKEM768_X25519` KEM is X-Wing.  Its one-shot API
+// seals a single message, so only the first sequence number is covered.  Each private key is a
+// fixed seed, `skRm`; for X-Wing, the `cryptography` key was expanded from it as X-Wing does.
+// Messages sealed by this crate to the same keys were also checked to open with `cryptography`.
+//
+// These are not the test vectors of draft-ietf-hpke-pq, which were not available.  `cryptography`
+// does not expose `DeriveKeyPair`, so that is not covered here.
+
+struct Vector {
+    sk_rm: &'static [u8],
+    enc: &'static [u8],
+    ct: &'static [u8],
+}
+
+const INFO: &[u8] = &hex!("4f6465206f6e2061204772656369616e2055726e");
+const AAD: &[u8] = &hex!("436f756e742d30");
+const PT: &[u8] = &hex!("4265617574792069732074727574682c20747275746820626561757479");
+
+fn open<K: Kem, F: Kdf, A: Aead>(v: &Vector) {
+    let sk_r = K::deserialize_private_key(v.sk_rm).unwrap();
+    let mut receiver = setup_base_r::<K, F, A>(v.enc, &sk_r, INFO).unwrap();
+    assert_eq!(receiver.open(AAD, v.ct).unwrap(), PT);
+}
+
+#[test]
+fn ml_kem_768() {
+    open::<MlKem768, HkdfSha256, Aes128Gcm>(&ML_KEM_768);
+}
+
+#[test]
+fn ml_kem_1024() {
+    open::<MlKem1024, HkdfSha384, Aes256Gcm>(&ML_KEM_1024);
+}
+
+#[test]
+fn x_wing() {
+    open::<XWing, HkdfSha256, ChaCha20Poly1305>(&X_WING);
+}
+
+/// ML-KEM-768, HKDF-SHA256, AES-128-GCM
+const ML_KEM_768: Vector = Vector {
+    sk_rm: &hex!("9c7714f7819e956b8fead2134cd324a66377cb8814f5fe5f96dfaa04906af9bc"
+        "6e4dcae6f0cd89d473646a176b2759478704faf4aa6fb22f42a0d0da55023894"),
+    enc: &hex!("6f6008ef716f071f462cf36651d06645df889ff8e959f5d3de8169de0ddcaf00"
+        "60891c571b3ed221ec359e945898c99a95f360d29d4c9e593006eb61b5f04a60"
+        "3d6d58d2179591f9827717c4202a9fdbd52f445c933b3f54a2cedb7ade563ed5"
+        "c148abf9723291db89e7ee3d83bd660ce0969372a9aca06597d6f7f19ed42f70"
+        "b1aabf91b38ec0d8e54d30a1a1483553e48cd13f2dd244c117956274d9d3593e"
+        "d6f3d0e246a56bb8d1a5fd74d24c7f6a3ee6858a2f2166827627b09d65f87b2b"
+        "1e5725892c7173bd3061802660df11264652a65fa0484007385ab13d777862fe"
+        "a2274354d397363ee7423b9795150f12553f11e5eca5955e8f267da2b9772b47"
+        "090b8aea76d7f80338cd9cd2857cd8bf0c363d945588d0b18512753825814a54"
+        "913d1d3dea5b887d67f1afb4cafd6f8dedc79759c039da070a1f06aa53b65119"
+        "f1e4d01b6f3dad715dd4d6470d6866c3fa462d354c7a8c4b9c42f01f8fa6bc74"
+        "6e7d42c27ce45a9a81733088eb2c3e2cf11ef4e5ff1df95bd7bbb6da6f87e84e"
+        "c0a2104508fcbfae2fd556a73875f699a8cd9e32647398bdb9c5077db733a063"
+        "024b7fb3cf2ce84bf8507c4c5298ca0e7773542caf183c381aa51b565b550823"
+        "5da217284c7616b3d615a03d9392a545a488d5b835bd6bb146419bcfa3c110ab"
+        "721b8847c5937b287a4bbeffcbabdd0f6431eb0aaa202810cba1fe79fc22a713"
+        "c3bd3f48b2d1aa384ba7eea873542f02a15a87fdbeae51e859286317212e819c"
+        "7de36f1617135de95f5f6b2444f5f4d18c1d130a873eb0807897161248bc9987"
+        "12df19d7b836c2c575aec566ea803494f4a50858d4500d6affee1bd4868d71b1"
+        "a84404881426ec0acbba64435a2dc54aa649b731016c942ddb28b7b54d332544"
+        "ea483effb02439f17230c873db2eb2477b13835d6f67626acb22e78018cc2d17"
+        "92c3f9beedd5ebc708a48e2bd6fb6dcd3493d9638b188c7a4894b05932c52117"
+        "7474cb14ee89cd73984ecd04ada065d8e43a21bb5c4048098fc1399df3fbcb7e"
+        "77a021815b27ce26f73668705badee05f97bad22997c5ef339faf4d0ea31f5fa"
+        "f4d541da0e6d5731a976a76704de9f5700d5b0705e52d532e2186862ec1a2fe0"
+        "0d8de5a306d9eaf7f74fccc1f4e6558ac1e039005dc7b2ec76f0665bc564cf21"
+        "a71452874e012699dc41530764cc88267ceeb7e71ca7b89821b76b9e0f640c04"
+        "f0ae7cdd0f9186de9c84a548bfb0d6833ef5d8e0f298704905bd923ccca554e7"
+        "505b5cd9ad44559f4a07afdbc44048d1400efcaf88d73f667f13e763551a76e7"
+        "a7db147dfac0c35d5dbbc0fcec6941eb62bd0b9faeffde80346a546b7fcf7080"
+        "599c4c3e000bccb8bb0cc0ebf70212ef270141f88e33836b93b520abbd5d9f8a"
+        "06801407e79d8e5ae246f3384d774871deb509c4fe0c09fdfc0b5af4d9213d6a"
+        "73f7d4300e5ab4263acb4173a57696973c1a74a62927b26a3304143018edeeaf"
+        "7716127c82a59a7fbfef92cd9825cecfffa2c01073cfac8fe3a340bdd8335aa4"),
+    ct: &hex!("cca0f69faebcae0b5182ae891ab000833701202b2914f61dbbf6bcec515ec286"
+        "d6605be4de50545efe9f18cef0"),
+};
+
+/// ML-KEM-1024, HKDF-SHA384, AES-256-GCM
+const ML_KEM_1024: Vector = Vector {
+    sk_rm: &hex!("5090569506125621c41e99c1d62d348050f536853d1ca67f4eb3fb163529926e"
+        "79e25284a51a22d76a70796f9bce33ee414ac5d5040efdad4f6c39536e6493d0"),
+    enc: &hex!("cd21c2f4654cf96377f587dd4195a178eb3daf1be573e6d413d86a183ff9c165"
+        "620e14f2d69e7971af3cbb3b785809fc8812980b5d2277dfb4639c728fd29b41"
+        "990e56c07e560fcee377faeee4efa1b6899741061ae51a400753e1f030553ba5"
+        "32f0f2d8f32a8438a50aa01ef02dda2cf1f291b962010bd8eb9ae859bd474843"
+        "38afb59b6e111bc8d2e0ed718a1f8803e3834146ee6aa29f3eaebc76c5489d8e"
+        "e9acee186a56908994fa915f74da9d58cd61e231c03dfdf1451a62fd7b45b269"
+        "fe7308ddc8e6ffd998f64d15dfb7fc10f5049e7ea2d1284c46cc7989907b2868"
+        "a6e9d93fe728eae7757af7be5c4041b0655548a283af77516cda7a7639f6f475"
+        "7dcb490294a31c88530df08be1383572c0c4826d791d300381c40040b812e844"
+        "2e070ecb3d53c827865deb0753661663145b2601118eaf5c7621783fc3d7f137"
+        "5430e32c1360a97b3ebc2d9a5fb1ff2f8543e512cc63294db42912fdb42f1194"
+        "8a1895dc683c7801d49539d3a704da55147cdb5687db79979387ab8997519225"
+        "920d76a50e5f12ee1bb50f992c9825c344b091844c388103cc529862e1661cce"
+        "a779c14d69b1ba5839b350c73e22c682100d520428f60438c71d2131dc3bc8b0"
+        "2f4db6350bb76df195833d6bc8ae911d28f89bb7a3a0e407a0e00a662b09a192"
+        "9f76e880052cc0acb70655cde1e2bca2d5c7d1f073be62910d33522a0b1b96ca"
+        "76f826eb8ab1df66097833fe6e5dc7e78864331c3e8caaf82396e813cae751fa"
+        "c47a054157bc74ed90f4588b751b41e8474651b546bbc8e03e8ce2df47909d0c"
+        "b2cc7f39e9a64a446a60bc0ffe7b3d091131e67029e3d0aab4013b4ee560a3d0"
+        "e18fcd98692da411a779f20d10e3671b785cbec67390c2f061ac87159c63be80"
+        "6c51c4d40e04efd6ec8e2b13dbc50ae2b2d40ab0a3d6764c5c47fc80cb49eee9"
+        "cedc4f94e46640156f6e91ddb3ade13443fc214471353ac0752a2f53f3921221"
+        "b5b5e57807fbabd3d745f6a7366c497cf87a81a95680822cfaaac679053ce239"
+        "50094631f8be3ea8bfacac23a1f66f63c08c36ab96f58bb3767ac6424c46190e"
+        "ef586ceca59d11364f52cdf53df793bf6dd715b5c2c49e891e38a31d8de3962b"
+        "59bd31505693ef68fab1469196d26492099d188b1340db3aba187f7328c5120f"
+        "ca8690d0db7b7d317e9dce80589ffb0bf571c047299e14819381545a7137f29e"
+        "68367c4035faaada5b4cffdb8381a8a849653ebe5b700a349707b902789047f2"
+        "b86543af5c4ddbf71764995d90a6f8be2b334e3066be3677497d0c8c33154750"
+        "9bc3a32c8f26d6104e2d886f415ccf2a7f6d5fc6dced2f7b8afa29f16b9657f9"
+        "7f06066648182e577404a3315bc8e529a5606057aa4f557d290a0fab69c17c7a"
+        "c8a334d7e28450f6e6c702e50213134ed3525b742d543c725e539f5cbc506e6c"
+        "6e52cf302c290313dab51188c094b8b1f0ab93f8aeafa443fabf45f1d4bff63a"
+        "edeac20a01282d81dc0763484e95c3494d95837721264b11d9d1d0c8194446b8"
+        "5fbcc524d0ae933d778b2bf5be21439f9c676d2e4fd39b4b06ee4d213b9803d9"
+        "47bbfc0726aaa99d7ee7cfd1e76de012f51d17bf759190b4acd080ccd7fe34f1"
+        "a03db4c5ccfc0f413c542989861f1d1b3234e429277d39749b7c6f582b68ab68"
+        "aec8e33f925414200517d18569e86171c61f19dbcf03a7b201a9b7ea1ac2f23c"
+        "dd34b8f07d2410c52c02a2f5402675d6db1b33716890d049ad91b28ae675428f"
+        "70f43ff5a4623b575d4064f05ba88513bd426821d58f37f10f615921802cdfb5"
+        "eec1019519ad5ff3d4ff57824d0f2337a37076a09abe5c8bf6559e9d1b00dca0"
+        "890be89f8ef02d0edeb6a34e99801d46f9474fc916b5e6c88ed51cdbcc20a42a"
+        "6a77df659967704b4e7f11470a98514da7f9b3754c6eedca1b225d6244617301"
+        "452df745e23937155d2011ba4ca36dcc92f04f6d33f562862f483eb863766199"
+        "9a665e990794b7157d9f1e2f4027695a78660a09f0354802a4c8e43c72fbee8e"
+        "e607ee985833549067eaef67209af9bd1e72b9da66112d88cf6c3404eb0cdc21"
+        "65a899efac25f5f1d5396d71e26a24bd9c7c12b26730ec2817460b1f755893b5"
+        "2056545b36f52c19081c5f58c780e4c40fcdf1673bd18c90f75b2b834aada7e6"
+        "f556a0ac49de3ed753d49e5a7dfd8236599d6693eb3aa1711dc5dc799a3b0092"),
+    ct: &hex!("b8d1ebb1fafa31f1037c7e279306e6f02bc19b4db087dd2a4d104b54d0d1a4fc"
+        "320ab5e0a864720e53705e67fb"),
+};
+
+/// X-Wing, HKDF-SHA256, ChaCha20Poly1305
+const X_WING: Vector = Vector {
+    sk_rm: &hex!("d7c4dc2b1b426d0f98c9ef195f84b68b30a672f544b3f24487a635d3e09bbe32"),
+    enc: &hex!("05f545370a0501cc5b3212de3fb353b79a393cd706f9f24be7ea83931485e1d1"
+        "c146b70613e85b9bb9d8317472bcecad5122e71dbf9e8a9993921924345696bb"
+        "9daf2d0f7d5f0355b7f4d8870cf0b3c55d32d60b416d14564ea0a3d5a1c777c7"
+        "86da0cfe9ca2389a752756fb6533e42f5b05db2ad727df174b86c59c9229662c"
+        "4cc06dcaf5d9a6e24ecf0cc435098e53befbb1bcb38278ee045d432142d0b900"
+        "904e3a9579d368d6a4940f03dd3a896798cdc483e656683d1fe25e2d126f2ad9"
+        "ebb2a7a9606e5cccd333862f5187ca36dec29f0ba0e78fefa22dc78427bf1a63"
+        "eb9f0c383412607ed9e89779c58ce37e7601947f47f4fc8391442b6bb4a7c48a"
+        "e5c61d478d0afae710b5ce18af18eac097e41f41f278aeefcca3c1c14ffaf358"
+        "a76a3756f60a3a3f53048bec0f682fa6cc6765b5767af1ccb109753efe25a9e4"
+        "cf006fc2eb826ff87a760392b9b289f72be39e7469810c210647aab18fc081a7"
+        "573cc8e6a93f70fce44c2cc9d891786fc8e46e68108a7839d65cde094f31012f"
+        "5689d940c180afc11aeef28b3764f6bc66051911b5cecbf0e080c822f24881fc"
+        "097b348def8ae1280f979f28097c2307a7a11095628f2dfaa2848f6039bdff71"
+        "befc34b61bef9a257ba0f07dd3f618ba3102580fb18803bd4959743ad4cdbc63"
+        "e4f4577a4f1e36dcff8380c9ab08fcdba6b1d76876c3695e57b61c7daf9d410b"
+        "dc8b6e99673c8813ed71f000222951ac81e7449d30e3f3db63e7d0d7861e4e1a"
+        "a31b3e1c7dd88ccc6b5706b2ea90e1787a83860e479eec6847616c10c76b7896"
+        "f0362392dc4112a73b6b57749ee7ead4431ace405dfa10240eecca41330431ba"
+        "66b32fb351dc9e5fa8e04688081c8d64103b2741448e8415f735ead7070a7c6f"
+        "ab9042d6a49169605126f84e75ec6fd49a17c1e2774cc2066df623f90d2dad6e"
+        "8e5af0b564a9ae6d37a1f9b6cc69ecd80ddce32cdc71a2b6ceabf456fb8b71a2"
+        "ebb136d30934f0306f0cda4dab0c8a8e0800b790488cca6c82b965027be8e034"
+        "46cf2e651e669c68b9c58ced6c7cb298abaaae1c933bf050f360bd36bf88194c"
+        "08309ac3463f6b5ea6f8bd3e1e08f6b1208db8e2d9ddce9ad7dc0c59f54b97c2"
+        "5727f537f8ffbe336f37b6a63dc4c8c154d76115674b584d0c5b623257d4ed9e"
+        "110b1414344efb6bf2e1b38858d10cfd5f7625356fc8dea0f643ae449f9140a7"
+        "fdeba99af9f77ed0c8e3c5df2c8ca6a41d9ad353798e278e483b7624668105af"
+        "6a2073aff4af526d6b3f8dece778be887f8355c8c47f2c6e3c3ba3b811012110"
+        "62a9722767be31a7258386b4fe937a48ecbaba9fa1f620f9e03060d3f922fdb6"
+        "118f01133585bfd81bad8d955081e5b6afcc3958b32ec5ace44ab7c9bf57d883"
+        "582e7ece4cc460667aad6dfd440f2ca01834205cd56fe033572823c643057194"
+        "2e446f254d0f42f287ae9a03ee71fbd370f94c7223d718c190ef23946954bea5"
+        "45bcd6b17db147e546488723236d84e08a25ffcfc828d8dba5d330f81d378f3b"
+        "562f681d0889024a6b18a8404a1bbf330596612db64d11685b1de82860808941"),
+    ct: &hex!("91ced8c877757eed686ccd3b35becfb65e61c7d3bb8558fa75a0b03673c97a9c"
+        "ec1a5240dcdd052f2f7819bd75"),
+};
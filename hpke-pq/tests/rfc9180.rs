@@ -0,0 +1,333 @@
+//! The DHKEM(X25519, HKDF-SHA256), HKDF-SHA256, AES-128-GCM vectors in base and PSK mode from
+//! Appendices A.1.1 and A.1.2 of RFC 9180, which check the key schedule, the AEAD nonces and
+//! secret export.
+//!
+//! None of the KEMs of this crate are in RFC 9180, so the vectors are run with a minimal DHKEM
+//! defined here on top of the public [`Kem`] trait.  The inputs, the key pairs and the first
+//! encryption of each vector are those of the RFC; the other encryptions and the exports of the
+//! PSK vector were reproduced with the HPKE implementation of OpenSSL 3.5, which also matches
+//! every value of the base vector.
+
+use hex_literal::hex;
+use hpke_pq::aead::Aes128Gcm;
+use hpke_pq::kdf::{HkdfSha256, Kdf};
+use hpke_pq::kem::Kem;
+use hpke_pq::{setup_base_r, setup_base_s, setup_psk_r, setup_psk_s, Error};
+use rand::{CryptoRng, RngCore};
+use rand_core::CryptoRngCore;
+use x25519_dalek::{PublicKey, StaticSecret};
+
+/// DHKEM(X25519, HKDF-SHA256), as in Sections 4.1 and 7.1.3 of RFC 9180
+struct DhkemX25519;
+
+impl DhkemX25519 {
+    const SUITE_ID: &'static [u8] = b"KEM\x00\x20";
+
+    /// `ExtractAndExpand(dh, kem_context)`
+    fn extract_and_expand(dh: &[u8], kem_context: &[u8]) -> Vec<u8> {
+        let eae_prk = HkdfSha256::labeled_extract(Self::SUITE_ID, b"", b"eae_prk", dh);
+        let mut shared_secret = vec![0u8; Self::N_SECRET];
+        HkdfSha256::labeled_expand(
+            &eae_prk,
+            Self::SUITE_ID,
+            b"shared_secret",
+            kem_context,
+            &mut shared_secret,
+        )
+        .unwrap();
+        shared_secret
+    }
+}
+
+impl Kem for DhkemX25519 {
+    const ID: u16 = 0x0020;
+    const N_SECRET: usize = 32;
+    const N_ENC: usize = 32;
+    const N_PK: usize = 32;
+    const N_SK: usize = 32;
+
+    type PublicKey = PublicKey;
+    type PrivateKey = StaticSecret;
+
+    fn generate_key_pair(rng: &mut impl CryptoRngCore) -> (Self::PrivateKey, Self::PublicKey) {
+        let sk = StaticSecret::random_from_rng(rng);
+        let pk = PublicKey::from(&sk);
+        (sk, pk)
+    }
+
+    fn derive_key_pair(ikm: &[u8]) -> (Self::PrivateKey, Self::PublicKey) {
+        let dkp_prk = HkdfSha256::labeled_extract(Self::SUITE_ID, b"", b"dkp_prk", ikm);
+        let mut sk = [0u8; 32];
+        HkdfSha256::labeled_expand(&dkp_prk, Self::SUITE_ID, b"sk", b"", &mut sk).unwrap();
+        let sk = StaticSecret::from(sk);
+        let pk = PublicKey::from(&sk);
+        (sk, pk)
+    }
+
+    fn serialize_public_key(pk: &Self::PublicKey) -> Vec<u8> {
+        pk.as_bytes().to_vec()
+    }
+
+    fn deserialize_public_key(bytes: &[u8]) -> Result<Self::PublicKey, Error> {
+        let bytes = <[u8; 32]>::try_from(bytes).map_err(|_| Error::InvalidLength {
+            expected: Self::N_PK,
+            actual: bytes.len(),
+        })?;
+        Ok(PublicKey::from(bytes))
+    }
+
+    fn serialize_private_key(sk: &Self::PrivateKey) -> Result<Vec<u8>, Error> {
+        Ok(sk.to_bytes().to_vec())
+    }
+
+    fn deserialize_private_key(bytes: &[u8]) -> Result<Self::PrivateKey, Error> {
+        let bytes = <[u8; 32]>::try_from(bytes).map_err(|_| Error::InvalidLength {
+            expected: Self::N_SK,
+            actual: bytes.len(),
+        })?;
+        Ok(StaticSecret::from(bytes))
+    }
+
+    fn encap(
+        pk: &Self::PublicKey,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<(Vec<u8>, Vec<u8>), Error> {
+        let mut sk_e = [0u8; 32];
+        rng.try_fill_bytes(&mut sk_e)
+            .map_err(|_| Error::Kem(ml_kem::Error::Rng))?;
+        let sk_e = StaticSecret::from(sk_e);
+        let enc = PublicKey::from(&sk_e).to_bytes();
+        let dh = sk_e.diffie_hellman(pk);
+        let kem_context = [enc, pk.to_bytes()].concat();
+        Ok((
+            Self::extract_and_expand(dh.as_bytes(), &kem_context),
+            enc.to_vec(),
+        ))
+    }
+
+    fn decap(enc: &[u8], sk: &Self::PrivateKey) -> Result<Vec<u8>, Error> {
+        let pk_e = Self::deserialize_public_key(enc)?;
+        let dh = sk.diffie_hellman(&pk_e);
+        let kem_context = [enc, PublicKey::from(sk).as_bytes()].concat();
+        Ok(Self::extract_and_expand(dh.as_bytes(), &kem_context))
+    }
+}
+
+/// An RNG that returns fixed bytes, the ephemeral private key `skEm`
+struct FixedRng([u8; 32]);
+
+impl RngCore for FixedRng {
+    fn next_u32(&mut self) -> u32 {
+        unreachable!("only try_fill_bytes is used")
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        unreachable!("only try_fill_bytes is used")
+    }
+
+    fn fill_bytes(&mut self, _dest: &mut [u8]) {
+        unreachable!("only try_fill_bytes is used")
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        dest.copy_from_slice(&self.0);
+        Ok(())
+    }
+}
+
+impl CryptoRng for FixedRng {}
+
+/// A vector of Appendix A.1 of RFC 9180
+struct Vector {
+    info: &'static [u8],
+    ikm_e: [u8; 32],
+    pk_em: [u8; 32],
+    sk_em: [u8; 32],
+    ikm_r: [u8; 32],
+    pk_rm: [u8; 32],
+    sk_rm: [u8; 32],
+    /// `(psk, psk_id)`, in PSK mode only
+    psk: Option<(&'static [u8], &'static [u8])>,
+    pt: &'static [u8],
+    /// The first encryptions, `(aad, ct)`, in sequence number order
+    encryptions: [(&'static [u8], &'static [u8]); 3],
+    /// The exports, `(exporter_context, exported_value)`, with `L = 32`
+    exports: [(&'static [u8], [u8; 32]); 3],
+}
+
+const INFO: &[u8] = &hex!("4f6465206f6e2061204772656369616e2055726e");
+const PT: &[u8] = &hex!("4265617574792069732074727574682c20747275746820626561757479");
+
+/// A.1.1, in base mode
+const BASE: Vector = Vector {
+    info: INFO,
+    ikm_e: hex!("7268600d403fce431561aef583ee1613527cff655c1343f29812e66706df3234"),
+    pk_em: hex!("37fda3567bdbd628e88668c3c8d7e97d1d1253b6d4ea6d44c150f741f1bf4431"),
+    sk_em: hex!("52c4a758a802cd8b936eceea314432798d5baf2d7e9235dc084ab1b9cfa2f736"),
+    ikm_r: hex!("6db9df30aa07dd42ee5e8181afdb977e538f5e1fec8a06223f33f7013e525037"),
+    pk_rm: hex!("3948cfe0ad1ddb695d780e59077195da6c56506b027329794ab02bca80815c4d"),
+    sk_rm: hex!("4612c550263fc8ad58375df3f557aac531d26850903e55a9f23f21d8534e8ac8"),
+    psk: None,
+    pt: PT,
+    encryptions: [
+        (
+            &hex!("436f756e742d30"),
+            &hex!("f938558b5d72f1a23810b4be2ab4f84331acc02fc97babc53a52ae8218a355a96d8770ac83d07bea87e13c512a"),
+        ),
+        (
+            &hex!("436f756e742d31"),
+            &hex!("af2d7e9ac9ae7e270f46ba1f975be53c09f8d875bdc8535458c2494e8a6eab251c03d0c22a56b8ca42c2063b84"),
+        ),
+        (
+            &hex!("436f756e742d32"),
+            &hex!("498dfcabd92e8acedc281e85af1cb4e3e31c7dc394a1ca20e173cb72516491588d96a19ad4a683518973dcc180"),
+        ),
+    ],
+    exports: [
+        (
+            b"",
+            hex!("3853fe2b4035195a573ffc53856e77058e15d9ea064de3e59f4961d0095250ee"),
+        ),
+        (
+            &hex!("00"),
+            hex!("2e8f0b54673c7029649d4eb9d5e33bf1872cf76d623ff164ac185da9e88c21a5"),
+        ),
+        (
+            &hex!("54657374436f6e74657874"),
+            hex!("e9e43065102c3836401bed8c3c3c75ae46be1639869391d62c61f1ec7af54931"),
+        ),
+    ],
+};
+
+/// A.1.2, in PSK mode
+const PSK: Vector = Vector {
+    info: INFO,
+    ikm_e: hex!("78628c354e46f3e169bd231be7b2ff1c77aa302460a26dbfa15515684c00130b"),
+    pk_em: hex!("0ad0950d9fb9588e59690b74f1237ecdf1d775cd60be2eca57af5a4b0471c91b"),
+    sk_em: hex!("463426a9ffb42bb17dbe6044b9abd1d4e4d95f9041cef0e99d7824eef2b6f588"),
+    ikm_r: hex!("d4a09d09f575fef425905d2ab396c1449141463f698f8efdb7accfaff8995098"),
+    pk_rm: hex!("9fed7e8c17387560e92cc6462a68049657246a09bfa8ade7aefe589672016366"),
+    sk_rm: hex!("c5eb01eb457fe6c6f57577c5413b931550a162c71a03ac8d196babbd4e5ce0fd"),
+    psk: Some((
+        &hex!("0247fd33b913760fa1fa51e1892d9f307fbe65eb171e8132c2af18555a738b82"),
+        &hex!("456e6e796e20447572696e206172616e204d6f726961"),
+    )),
+    pt: PT,
+    encryptions: [
+        (
+            &hex!("436f756e742d30"),
+            &hex!("e52c6fed7f758d0cf7145689f21bc1be6ec9ea097fef4e959440012f4feb73fb611b946199e681f4cfc34db8ea"),
+        ),
+        (
+            &hex!("436f756e742d31"),
+            &hex!("49f3b19b28a9ea9f43e8c71204c00d4a490ee7f61387b6719db765e948123b45b61633ef059ba22cd62437c8ba"),
+        ),
+        (
+            &hex!("436f756e742d32"),
+            &hex!("257ca6a08473dc851fde45afd598cc83e326ddd0abe1ef23baa3baa4dd8cde99fce2c1e8ce687b0b47ead1adc9"),
+        ),
+    ],
+    exports: [
+        (
+            b"",
+            hex!("dff17af354c8b41673567db6259fd6029967b4e1aad13023c2ae5df8f4f43bf6"),
+        ),
+        (
+            &hex!("00"),
+            hex!("6a847261d8207fe596befb52928463881ab493da345b10e1dcc645e3b94e2d95"),
+        ),
+        (
+            &hex!("54657374436f6e74657874"),
+            hex!("8aff52b45a1be3a734bc7a41e20b4e055ad4c4d22104b0c20285a7c4302401cd"),
+        ),
+    ],
+};
+
+impl Vector {
+    fn derive_key_pair(&self) {
+        for (ikm, sk, pk) in [
+            (self.ikm_e, self.sk_em, self.pk_em),
+            (self.ikm_r, self.sk_rm, self.pk_rm),
+        ] {
+            let (derived_sk, derived_pk) = DhkemX25519::derive_key_pair(&ikm);
+            assert_eq!(derived_sk.to_bytes(), sk);
+            assert_eq!(derived_pk.to_bytes(), pk);
+        }
+    }
+
+    fn sender(&self) {
+        let pk_r = PublicKey::from(self.pk_rm);
+        let mut rng = FixedRng(self.sk_em);
+        let (enc, mut sender) = match self.psk {
+            None => setup_base_s::<DhkemX25519, HkdfSha256, Aes128Gcm>(&pk_r, self.info, &mut rng),
+            Some((psk, psk_id)) => setup_psk_s::<DhkemX25519, HkdfSha256, Aes128Gcm>(
+                &pk_r, self.info, psk, psk_id, &mut rng,
+            ),
+        }
+        .unwrap();
+        assert_eq!(enc, self.pk_em);
+
+        for (aad, ct) in self.encryptions {
+            assert_eq!(sender.seal(aad, self.pt).unwrap(), ct);
+        }
+
+        for (exporter_context, exported_value) in self.exports {
+            let mut out = [0u8; 32];
+            sender.export(exporter_context, &mut out).unwrap();
+            assert_eq!(out, exported_value);
+        }
+    }
+
+    fn receiver(&self) {
+        let sk_r = StaticSecret::from(self.sk_rm);
+        let mut receiver = match self.psk {
+            None => {
+                setup_base_r::<DhkemX25519, HkdfSha256, Aes128Gcm>(&self.pk_em, &sk_r, self.info)
+            }
+            Some((psk, psk_id)) => setup_psk_r::<DhkemX25519, HkdfSha256, Aes128Gcm>(
+                &self.pk_em,
+                &sk_r,
+                self.info,
+                psk,
+                psk_id,
+            ),
+        }
+        .unwrap();
+
+        for (aad, ct) in self.encryptions {
+            assert_eq!(receiver.open(aad, ct).unwrap(), self.pt);
+        }
+
+        for (exporter_context, exported_value) in self.exports {
+            let mut out = [0u8; 32];
+            receiver.export(exporter_context, &mut out).unwrap();
+            assert_eq!(out, exported_value);
+        }
+    }
+}
+
+#[test]
+fn derive_key_pair() {
+    BASE.derive_key_pair();
+    PSK.derive_key_pair();
+}
+
+#[test]
+fn base_sender() {
+    BASE.sender();
+}
+
+#[test]
+fn base_receiver() {
+    BASE.receiver();
+}
+
+#[test]
+fn psk_sender() {
+    PSK.sender();
+}
+
+#[test]
+fn psk_receiver() {
+    PSK.receiver();
+}
@@ -1,7 +1,8 @@
+use ::kem::{Decapsulate, Encapsulate};
 use criterion::{criterion_group, criterion_main, Criterion};
-use crypto_common::rand_core::CryptoRngCore;
 use hybrid_array::{Array, ArraySize};
 use ml_kem::*;
+use rand_core::CryptoRngCore;
 
 pub fn rand<L: ArraySize>(rng: &mut impl CryptoRngCore) -> Array<u8, L> {
     let mut val = Array::<u8, L>::default();
@@ -9,50 +10,87 @@ pub fn rand<L: ArraySize>(rng: &mut impl CryptoRngCore) -> Array<u8, L> {
     val
 }
 
+// The precomputed keys are not part of the generic `KemCore` interface, so the benchmarks are
+// instantiated for each parameter set with a macro.
+macro_rules! bench_kem {
+    ($c:expr, $name:literal, $kem:ty) => {{
+        let c: &mut Criterion = $c;
+        let mut group = c.benchmark_group($name);
+
+        let mut rng = rand::thread_rng();
+        let d: B32 = rand(&mut rng);
+        let z: B32 = rand(&mut rng);
+        let m: B32 = rand(&mut rng);
+
+        let (dk, ek) = <$kem>::generate_deterministic(&d, &z);
+        let dk_bytes = dk.as_bytes();
+        let ek_bytes = ek.as_bytes();
+        let (ct, _sk) = ek.encapsulate(&mut rng).unwrap();
+
+        // Key generation
+        group.bench_function("keygen", |b| {
+            b.iter(|| {
+                let (dk, ek) = <$kem as KemCore>::generate_deterministic(&d, &z);
+                let _dk_bytes = dk.as_bytes();
+                let _ek_bytes = ek.as_bytes();
+            })
+        });
+
+        // Encapsulation
+        group.bench_function("encapsulate", |b| {
+            b.iter(|| {
+                let ek = <$kem as KemCore>::EncapsulationKey::from_bytes(&ek_bytes);
+                ek.encapsulate_deterministic(&m).unwrap();
+            })
+        });
+
+        // Encapsulation to a key with a cached matrix
+        let ek_pre = ek.precompute();
+        group.bench_function("encapsulate_precomputed", |b| {
+            b.iter(|| {
+                ek_pre.encapsulate_deterministic(&m).unwrap();
+            })
+        });
+
+        // Decapsulation
+        group.bench_function("decapsulate", |b| {
+            b.iter(|| {
+                let dk = <$kem as KemCore>::DecapsulationKey::from_bytes(&dk_bytes);
+                dk.decapsulate(&ct).unwrap();
+            })
+        });
+
+        // Decapsulation with a static key, with and without a cached matrix
+        group.bench_function("decapsulate_static", |b| {
+            b.iter(|| {
+                dk.decapsulate(&ct).unwrap();
+            })
+        });
+
+        let dk_pre = dk.precompute();
+        group.bench_function("decapsulate_precomputed", |b| {
+            b.iter(|| {
+                dk_pre.decapsulate(&ct).unwrap();
+            })
+        });
+
+        // Round trip
+        group.bench_function("round_trip", |b| {
+            b.iter(|| {
+                let (dk, ek) = <$kem as KemCore>::generate_deterministic(&d, &z);
+                let (ct, _sk) = ek.encapsulate(&mut rng).unwrap();
+                dk.decapsulate(&ct).unwrap();
+            })
+        });
+
+        group.finish();
+    }};
+}
+
 fn criterion_benchmark(c: &mut Criterion) {
-    let mut rng = rand::thread_rng();
-    let d: B32 = rand(&mut rng);
-    let z: B32 = rand(&mut rng);
-    let m: B32 = rand(&mut rng);
-
-    let (dk, ek) = MlKem768::generate_deterministic(&d, &z);
-    let dk_bytes = dk.as_bytes();
-    let ek_bytes = ek.as_bytes();
-    let (ct, _sk) = ek.encapsulate(&mut rng).unwrap();
-
-    // Key generation
-    c.bench_function("keygen", |b| {
-        b.iter(|| {
-            let (dk, ek) = <MlKem768 as KemCore>::generate_deterministic(&d, &z);
-            let _dk_bytes = dk.as_bytes();
-            let _ek_bytes = ek.as_bytes();
-        })
-    });
-
-    // Encapsulation
-    c.bench_function("encapsulate", |b| {
-        b.iter(|| {
-            let ek = <MlKem768 as KemCore>::EncapsulationKey::from_bytes(&ek_bytes);
-            ek.encapsulate_deterministic(&m).unwrap();
-        })
-    });
-
-    // Decapsulation
-    c.bench_function("decapsulate", |b| {
-        b.iter(|| {
-            let dk = <MlKem768 as KemCore>::DecapsulationKey::from_bytes(&dk_bytes);
-            dk.decapsulate(&ct).unwrap();
-        })
-    });
-
-    // Round trip
-    c.bench_function("round_trip", |b| {
-        b.iter(|| {
-            let (dk, ek) = <MlKem768 as KemCore>::generate_deterministic(&d, &z);
-            let (ct, _sk) = ek.encapsulate(&mut rng).unwrap();
-            dk.decapsulate(&ct).unwrap();
-        })
-    });
+    bench_kem!(c, "ML-KEM-512", MlKem512);
+    bench_kem!(c, "ML-KEM-768", MlKem768);
+    bench_kem!(c, "ML-KEM-1024", MlKem1024);
 }
 
 criterion_group!(benches, criterion_benchmark);
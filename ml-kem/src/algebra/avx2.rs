@@ -0,0 +1,409 @@
+//! An AVX2 backend for the NTT, the inverse NTT, and `MultiplyNTTs`, after the reference Kyber
+//! AVX2 implementation.  Sixteen coefficients are processed at a time, using signed Montgomery
+//! multiplication with precomputed twiddle factors.
+//!
+//! Unlike the reference code, coefficients stay in their natural order, and every reduction is
+//! the same as in the scalar implementation, so that the results are bit-for-bit identical.
+
+#[allow(clippy::wildcard_imports)]
+use core::arch::x86_64::*;
+use hybrid_array::{typenum::U256, Array};
+
+use super::{FieldElement, Integer, GAMMA_MONT, NTT_INVERSE_SCALE, ZETAS_MONT};
+
+cpufeatures::new!(avx2_cpuid, "avx2");
+
+type Coefficients = Array<FieldElement, U256>;
+
+/// A multiplier in the Montgomery domain, together with its product with `q^{-1} mod 2^16`
+#[derive(Clone, Copy)]
+struct Twiddle {
+    mont: Integer,
+    qinv: Integer,
+}
+
+impl Twiddle {
+    const fn new(mont: Integer) -> Self {
+        Self {
+            mont,
+            qinv: mont.wrapping_mul(FieldElement::QINV),
+        }
+    }
+}
+
+/// Twiddle factors for sixteen lanes
+#[derive(Clone, Copy)]
+struct Twiddles {
+    mont: [Integer; 16],
+    qinv: [Integer; 16],
+}
+
+const TWIDDLES_ZERO: Twiddles = Twiddles {
+    mont: [0; 16],
+    qinv: [0; 16],
+};
+
+/// The zeta index used by block `b` of the layer with half-width `len`
+const fn zeta_index(forward: bool, len: usize, b: usize) -> usize {
+    if forward {
+        128 / len + b
+    } else {
+        256 / len - 1 - b
+    }
+}
+
+/// For the layers with `len < 16`, each 32-coefficient chunk is split into two registers holding
+/// the left and right sides of its butterflies (see [`split`]).  This gives, for each lane of
+/// those registers, the block of the chunk that it belongs to.
+const fn lane_block(len: usize, lane: usize) -> usize {
+    match len {
+        8 => lane / 8,
+        4 => [0, 2, 1, 3][lane / 4],
+        _ => lane / 4 + 4 * ((lane % 4) / 2),
+    }
+}
+
+/// Per-lane zetas for the layer with half-width `len < 16`, for each of the eight chunks
+const fn chunk_zetas(forward: bool, len: usize) -> [Twiddles; 8] {
+    let mut out = [TWIDDLES_ZERO; 8];
+    let mut c = 0;
+    while c < 8 {
+        let mut lane = 0;
+        while lane < 16 {
+            let b = c * (16 / len) + lane_block(len, lane);
+            let zeta = Twiddle::new(ZETAS_MONT[zeta_index(forward, len, b)]);
+            out[c].mont[lane] = zeta.mont;
+            out[c].qinv[lane] = zeta.qinv;
+            lane += 1;
+        }
+        c += 1;
+    }
+    out
+}
+
+const NTT_ZETAS: [[Twiddles; 8]; 3] = [
+    chunk_zetas(true, 8),
+    chunk_zetas(true, 4),
+    chunk_zetas(true, 2),
+];
+
+const INVNTT_ZETAS: [[Twiddles; 8]; 3] = [
+    chunk_zetas(false, 2),
+    chunk_zetas(false, 4),
+    chunk_zetas(false, 8),
+];
+
+/// Per-lane gammas for `MultiplyNTTs` on each group of sixteen coefficients.  Only the odd lanes
+/// are used.
+const BASEMUL_GAMMAS: [Twiddles; 16] = {
+    let mut out = [TWIDDLES_ZERO; 16];
+    let mut r = 0;
+    while r < 16 {
+        let mut p = 0;
+        while p < 8 {
+            let gamma = Twiddle::new(GAMMA_MONT[8 * r + p]);
+            out[r].mont[2 * p + 1] = gamma.mont;
+            out[r].qinv[2 * p + 1] = gamma.qinv;
+            p += 1;
+        }
+        r += 1;
+    }
+    out
+};
+
+/// In-place NTT, if the CPU supports AVX2.  Returns `false` if it does not.
+pub(super) fn ntt(f: &mut Coefficients) -> bool {
+    if !avx2_cpuid::get() {
+        return false;
+    }
+
+    // SAFETY: AVX2 support was checked above
+    unsafe { ntt_avx2(f) };
+    true
+}
+
+/// In-place inverse NTT, if the CPU supports AVX2.  Returns `false` if it does not.
+pub(super) fn ntt_inverse(f: &mut Coefficients) -> bool {
+    if !avx2_cpuid::get() {
+        return false;
+    }
+
+    // SAFETY: AVX2 support was checked above
+    unsafe { ntt_inverse_avx2(f) };
+    true
+}
+
+/// `MultiplyNTTs`, if the CPU supports AVX2
+pub(super) fn multiply_ntts(a: &Coefficients, b: &Coefficients) -> Option<Coefficients> {
+    if !avx2_cpuid::get() {
+        return None;
+    }
+
+    // SAFETY: AVX2 support was checked above
+    Some(unsafe { multiply_ntts_avx2(a, b) })
+}
+
+// The unaligned load and store instructions have no alignment requirement
+#[allow(clippy::cast_ptr_alignment)]
+#[target_feature(enable = "avx2")]
+unsafe fn load(f: &Coefficients) -> [__m256i; 16] {
+    let ptr = f.as_ptr().cast::<__m256i>();
+    core::array::from_fn(|i| _mm256_loadu_si256(ptr.add(i)))
+}
+
+#[allow(clippy::cast_ptr_alignment)]
+#[target_feature(enable = "avx2")]
+unsafe fn store(f: &mut Coefficients, r: &[__m256i; 16]) {
+    let ptr = f.as_mut_ptr().cast::<__m256i>();
+    for (i, x) in r.iter().enumerate() {
+        _mm256_storeu_si256(ptr.add(i), *x);
+    }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn twiddles(t: &Twiddles) -> (__m256i, __m256i) {
+    (
+        _mm256_loadu_si256(t.mont.as_ptr().cast()),
+        _mm256_loadu_si256(t.qinv.as_ptr().cast()),
+    )
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn broadcast(t: Twiddle) -> (__m256i, __m256i) {
+    (_mm256_set1_epi16(t.mont), _mm256_set1_epi16(t.qinv))
+}
+
+/// `FieldElement::fqmul` on each lane, where `b_qinv = b q^{-1} mod 2^16`.  The low halves of
+/// `a b` and `t q` are equal, so the difference of their high halves is exactly the scalar
+/// result `(a b - t q) >> 16`.
+#[target_feature(enable = "avx2")]
+unsafe fn fqmul(a: __m256i, b: __m256i, b_qinv: __m256i) -> __m256i {
+    let q = _mm256_set1_epi16(FieldElement::Q);
+    let hi = _mm256_mulhi_epi16(a, b);
+    let t = _mm256_mullo_epi16(a, b_qinv);
+    _mm256_sub_epi16(hi, _mm256_mulhi_epi16(t, q))
+}
+
+/// `FieldElement::montgomery_reduce` on each 32-bit lane
+#[target_feature(enable = "avx2")]
+unsafe fn montgomery_reduce(a: __m256i) -> __m256i {
+    let q = _mm256_set1_epi32(FieldElement::Q.into());
+    let qinv = _mm256_set1_epi32(FieldElement::QINV.into());
+
+    // Sign-extend the low half of `a q^{-1}`
+    let t = _mm256_mullo_epi32(a, qinv);
+    let t = _mm256_srai_epi32(_mm256_slli_epi32(t, 16), 16);
+    _mm256_srai_epi32(_mm256_sub_epi32(a, _mm256_mullo_epi32(t, q)), 16)
+}
+
+/// `FieldElement::barrett_reduce` on each lane.  Adding `2^9` to the high half of the product
+/// before shifting by 10 rounds the same way as adding `2^25` before shifting by 26.
+#[target_feature(enable = "avx2")]
+unsafe fn barrett_reduce(a: __m256i) -> __m256i {
+    let q = _mm256_set1_epi16(FieldElement::Q);
+    #[allow(clippy::cast_possible_truncation)]
+    let v = _mm256_set1_epi16(FieldElement::BARRETT_MULTIPLIER as Integer);
+    let t = _mm256_mulhi_epi16(a, v);
+    let t = _mm256_srai_epi16(_mm256_add_epi16(t, _mm256_set1_epi16(1 << 9)), 10);
+    _mm256_sub_epi16(a, _mm256_mullo_epi16(t, q))
+}
+
+/// Cooley-Tukey butterfly, as in Algorithm 8
+#[target_feature(enable = "avx2")]
+unsafe fn butterfly(a: &mut __m256i, b: &mut __m256i, (z, zq): (__m256i, __m256i)) {
+    let t = fqmul(*b, z, zq);
+    *b = _mm256_sub_epi16(*a, t);
+    *a = _mm256_add_epi16(*a, t);
+}
+
+/// Gentleman-Sande butterfly, as in Algorithm 9
+#[target_feature(enable = "avx2")]
+unsafe fn butterfly_inverse(a: &mut __m256i, b: &mut __m256i, (z, zq): (__m256i, __m256i)) {
+    let t = *a;
+    *a = barrett_reduce(_mm256_add_epi16(t, *b));
+    *b = fqmul(_mm256_sub_epi16(*b, t), z, zq);
+}
+
+/// Rearrange a 32-coefficient chunk `(x, y)` so that the butterflies of the layer with half-width
+/// `len < 16` pair the lanes of the two output registers
+#[target_feature(enable = "avx2")]
+unsafe fn split(len: usize, x: __m256i, y: __m256i) -> (__m256i, __m256i) {
+    match len {
+        8 => (
+            _mm256_permute2x128_si256(x, y, 0x20),
+            _mm256_permute2x128_si256(x, y, 0x31),
+        ),
+        4 => (_mm256_unpacklo_epi64(x, y), _mm256_unpackhi_epi64(x, y)),
+        _ => (
+            _mm256_blend_epi32(x, _mm256_slli_epi64(y, 32), 0xaa),
+            _mm256_blend_epi32(_mm256_srli_epi64(x, 32), y, 0xaa),
+        ),
+    }
+}
+
+/// The inverse of [`split`]
+#[target_feature(enable = "avx2")]
+unsafe fn join(len: usize, a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+    // Each of the rearrangements is its own inverse
+    split(len, a, b)
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn wide_layer(r: &mut [__m256i; 16], forward: bool, len: usize) {
+    let dist = len / 16;
+    for blk in 0..(128 / len) {
+        let zeta = broadcast(Twiddle::new(ZETAS_MONT[zeta_index(forward, len, blk)]));
+        let base = blk * 2 * dist;
+        for j in base..(base + dist) {
+            let (lo, hi) = r.split_at_mut(j + dist);
+            if forward {
+                butterfly(&mut lo[j], &mut hi[0], zeta);
+            } else {
+                butterfly_inverse(&mut lo[j], &mut hi[0], zeta);
+            }
+        }
+    }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn narrow_layer(r: &mut [__m256i; 16], forward: bool, len: usize, zetas: &[Twiddles; 8]) {
+    for (c, zeta) in zetas.iter().enumerate() {
+        let (mut a, mut b) = split(len, r[2 * c], r[2 * c + 1]);
+        if forward {
+            butterfly(&mut a, &mut b, twiddles(zeta));
+        } else {
+            butterfly_inverse(&mut a, &mut b, twiddles(zeta));
+        }
+        (r[2 * c], r[2 * c + 1]) = join(len, a, b);
+    }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn ntt_avx2(f: &mut Coefficients) {
+    let mut r = load(f);
+    for len in [128, 64, 32, 16] {
+        wide_layer(&mut r, true, len);
+    }
+    for (len, zetas) in [8, 4, 2].into_iter().zip(&NTT_ZETAS) {
+        narrow_layer(&mut r, true, len, zetas);
+    }
+
+    for x in &mut r {
+        *x = barrett_reduce(*x);
+    }
+    store(f, &r);
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn ntt_inverse_avx2(f: &mut Coefficients) {
+    let mut r = load(f);
+    for (len, zetas) in [2, 4, 8].into_iter().zip(&INVNTT_ZETAS) {
+        narrow_layer(&mut r, false, len, zetas);
+    }
+    for len in [16, 32, 64, 128] {
+        wide_layer(&mut r, false, len);
+    }
+
+    let scale = broadcast(Twiddle::new(NTT_INVERSE_SCALE));
+    for x in &mut r {
+        *x = fqmul(*x, scale.0, scale.1);
+    }
+    store(f, &r);
+}
+
+/// Swap each pair of adjacent coefficients
+#[target_feature(enable = "avx2")]
+unsafe fn swap_pairs(x: __m256i) -> __m256i {
+    _mm256_shufflehi_epi16(_mm256_shufflelo_epi16(x, 0xb1), 0xb1)
+}
+
+// Algorithm 10. MultiplyNTTs
+//
+// With `(a0, a1)` and `(b0, b1)` in adjacent lanes, `vpmaddwd` computes the sums of products
+// `a0 b0 + a1 (b1 gamma)` and `a0 b1 + a1 b0` in 32-bit lanes, which are reduced as in
+// `FieldElement::base_case_multiply` and interleaved back into 16-bit lanes.
+#[target_feature(enable = "avx2")]
+unsafe fn multiply_ntts_avx2(a: &Coefficients, b: &Coefficients) -> Coefficients {
+    let a = load(a);
+    let b = load(b);
+    let r2 = broadcast(Twiddle::new(FieldElement::R2));
+
+    let mut out = [_mm256_setzero_si256(); 16];
+    for (i, gammas) in BASEMUL_GAMMAS.iter().enumerate() {
+        let (gamma, gamma_qinv) = twiddles(gammas);
+
+        // [b0, b1 gamma]
+        let b_gamma = _mm256_blend_epi16(b[i], fqmul(b[i], gamma, gamma_qinv), 0xaa);
+
+        let c0 = montgomery_reduce(_mm256_madd_epi16(a[i], b_gamma));
+        let c1 = montgomery_reduce(_mm256_madd_epi16(a[i], swap_pairs(b[i])));
+        let c = _mm256_blend_epi16(c0, _mm256_slli_epi32(c1, 16), 0xaa);
+        out[i] = fqmul(c, r2.0, r2.1);
+    }
+
+    let mut f = Coefficients::default();
+    store(&mut f, &out);
+    f
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algebra::{multiply_ntts_scalar, ntt_inverse_scalar, ntt_scalar};
+    use rand::Rng;
+
+    fn random_coefficients(rng: &mut impl Rng, bound: Integer) -> Coefficients {
+        Array::from_fn(|_| FieldElement(rng.gen_range(-bound..=bound)))
+    }
+
+    // Compare representatives, rather than field elements
+    fn raw(f: &Coefficients) -> [Integer; 256] {
+        core::array::from_fn(|i| f[i].0)
+    }
+
+    fn check(f: &Coefficients, g: &Coefficients) {
+        let mut expected = f.clone();
+        let mut actual = f.clone();
+        ntt_scalar(&mut expected);
+        assert!(ntt(&mut actual));
+        assert_eq!(raw(&actual), raw(&expected));
+
+        let mut expected = f.clone();
+        let mut actual = f.clone();
+        ntt_inverse_scalar(&mut expected);
+        assert!(ntt_inverse(&mut actual));
+        assert_eq!(raw(&actual), raw(&expected));
+
+        let expected = multiply_ntts_scalar(f, g);
+        let actual = multiply_ntts(f, g).unwrap();
+        assert_eq!(raw(&actual), raw(&expected));
+    }
+
+    #[test]
+    fn bit_exact() {
+        if !avx2_cpuid::get() {
+            return;
+        }
+
+        // Random values across the input bounds of all three operations
+        let mut rng = rand::thread_rng();
+        for _ in 0..1000 {
+            let f = random_coefficients(&mut rng, 2 * FieldElement::Q);
+            let g = random_coefficients(&mut rng, 2 * FieldElement::Q - 1);
+            check(&f, &g);
+        }
+
+        // Extreme values
+        for x in [
+            0,
+            1,
+            -1,
+            FieldElement::Q - 1,
+            2 * FieldElement::Q,
+            -2 * FieldElement::Q,
+        ] {
+            let f = Array::from_fn(|_| FieldElement(x));
+            check(&f, &f);
+        }
+    }
+}
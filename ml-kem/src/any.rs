@@ -0,0 +1,523 @@
+//! The keys and ciphertexts here wrap those of [`MlKem512`], [`MlKem768`], and [`MlKem1024`] in
+//! enums, and every operation delegates to the [`kem::Kem`] implementation for the parameter set
+//! of its inputs.  Keys and ciphertexts are decoded from and encoded to byte slices, whose
+//! lengths depend on the parameter set.
+//!
+//! ```
+//! # use ml_kem::any::{MlKemAny, ParameterId};
+//! # use ::kem::{Decapsulate, Encapsulate};
+//! let mut rng = rand::thread_rng();
+//!
+//! // The parameter set may come from a protocol negotiation
+//! let param = ParameterId::MlKem768;
+//! let (dk, ek) = MlKemAny::generate(param, &mut rng);
+//!
+//! // Keys and ciphertexts are exchanged as byte slices
+//! let mut ek_bytes = [0u8; 1184];
+//! ek.write_to_slice(&mut ek_bytes[..param.encapsulation_key_size()])?;
+//!
+//! let ek = ml_kem::any::EncapsulationKey::from_slice(param, &ek_bytes)?;
+//! let (ct, k_send) = ek.encapsulate(&mut rng)?;
+//!
+//! let k_recv = dk.decapsulate_slice(ct.as_ref())?;
+//! assert_eq!(k_send, k_recv);
+//! # Ok::<(), ml_kem::Error>(())
+//! ```
+
+use ::kem::{Decapsulate, Encapsulate};
+use core::fmt;
+use hybrid_array::{
+    typenum::{Unsigned, U32},
+    Array,
+};
+use rand_core::CryptoRngCore;
+use subtle::{Choice, ConstantTimeEq};
+
+use crate::param::{DecapsulationKeySize, EncapsulationKeySize, PkeParams};
+use crate::{
+    kem, DecapsulateInto, EncapsulateInto, EncodedSizeUser, Error, KemCore, MlKem1024,
+    MlKem1024Params, MlKem512, MlKem512Params, MlKem768, MlKem768Params, Seed,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// A shared key, which has the same size for every parameter set
+pub type SharedKey = Array<u8, U32>;
+
+/// An ML-KEM parameter set, identified at runtime
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ParameterId {
+    /// ML-KEM-512, for security category 1
+    MlKem512,
+
+    /// ML-KEM-768, for security category 3
+    MlKem768,
+
+    /// ML-KEM-1024, for security category 5
+    MlKem1024,
+}
+
+// Evaluate `$body` with the type `$P` set to the parameters of the parameter set `$id`
+macro_rules! with_params {
+    ($id:expr, $P:ident => $body:expr) => {
+        match $id {
+            ParameterId::MlKem512 => {
+                type $P = MlKem512Params;
+                $body
+            }
+            ParameterId::MlKem768 => {
+                type $P = MlKem768Params;
+                $body
+            }
+            ParameterId::MlKem1024 => {
+                type $P = MlKem1024Params;
+                $body
+            }
+        }
+    };
+}
+
+// Evaluate `$body` with `$x` bound to the value wrapped in `$value`, an enum of type `$enum`
+macro_rules! with_inner {
+    ($value:expr, $enum:ident, $x:ident => $body:expr) => {
+        match $value {
+            $enum::MlKem512($x) => $body,
+            $enum::MlKem768($x) => $body,
+            $enum::MlKem1024($x) => $body,
+        }
+    };
+}
+
+impl ParameterId {
+    /// All of the parameter sets, in order of increasing security
+    pub const ALL: [Self; 3] = [Self::MlKem512, Self::MlKem768, Self::MlKem1024];
+
+    /// The name of the parameter set in FIPS 203, e.g., `"ML-KEM-768"`
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::MlKem512 => "ML-KEM-512",
+            Self::MlKem768 => "ML-KEM-768",
+            Self::MlKem1024 => "ML-KEM-1024",
+        }
+    }
+
+    /// The size of an encoded encapsulation key, in bytes
+    #[must_use]
+    pub fn encapsulation_key_size(self) -> usize {
+        with_params!(self, P => EncapsulationKeySize::<P>::USIZE)
+    }
+
+    /// The size of an encoded decapsulation key, in bytes
+    #[must_use]
+    pub fn decapsulation_key_size(self) -> usize {
+        with_params!(self, P => DecapsulationKeySize::<P>::USIZE)
+    }
+
+    /// The size of a ciphertext, in bytes
+    #[must_use]
+    pub fn ciphertext_size(self) -> usize {
+        with_params!(self, P => <P as PkeParams>::CiphertextSize::USIZE)
+    }
+}
+
+impl fmt::Display for ParameterId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// ML-KEM with the parameter set chosen at runtime
+pub struct MlKemAny;
+
+impl MlKemAny {
+    /// Generate a new (decapsulation, encapsulation) key pair for the parameter set `param`.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`KemCore::generate`], including a failed pairwise
+    /// consistency test with the `pct` feature.
+    pub fn generate(
+        param: ParameterId,
+        rng: &mut impl CryptoRngCore,
+    ) -> (DecapsulationKey, EncapsulationKey) {
+        with_params!(param, P => {
+            let (dk, ek) = kem::Kem::<P>::generate(rng);
+            (dk.into(), ek.into())
+        })
+    }
+
+    /// Generate a new key pair for the parameter set `param`, reporting a failure of the RNG
+    /// instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`KemCore::try_generate`].
+    pub fn try_generate(
+        param: ParameterId,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<(DecapsulationKey, EncapsulationKey), Error> {
+        with_params!(param, P => {
+            let (dk, ek) = kem::Kem::<P>::try_generate(rng)?;
+            Ok((dk.into(), ek.into()))
+        })
+    }
+
+    /// Expand a key pair for the parameter set `param` from a 64-byte seed `(d || z)`.  No
+    /// pairwise consistency test is run, even with the `pct` feature.
+    #[must_use]
+    pub fn from_seed(param: ParameterId, seed: &Seed) -> (DecapsulationKey, EncapsulationKey) {
+        with_params!(param, P => {
+            let (dk, ek) = kem::Kem::<P>::from_seed(seed);
+            (dk.into(), ek.into())
+        })
+    }
+}
+
+/// Define an enum with one variant for each parameter set, wrapping `$inner<P>`
+macro_rules! define_any {
+    ($(#[$meta:meta])* $name:ident, $inner:ident<$P:ident>, $desc:literal) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, PartialEq)]
+        #[allow(clippy::large_enum_variant)] // Boxing would require `alloc`
+        pub enum $name {
+            #[doc = concat!("An ML-KEM-512 ", $desc)]
+            MlKem512(define_any!(@type $inner, MlKem512Params, MlKem512)),
+
+            #[doc = concat!("An ML-KEM-768 ", $desc)]
+            MlKem768(define_any!(@type $inner, MlKem768Params, MlKem768)),
+
+            #[doc = concat!("An ML-KEM-1024 ", $desc)]
+            MlKem1024(define_any!(@type $inner, MlKem1024Params, MlKem1024)),
+        }
+
+        impl From<define_any!(@type $inner, MlKem512Params, MlKem512)> for $name {
+            fn from(x: define_any!(@type $inner, MlKem512Params, MlKem512)) -> Self {
+                Self::MlKem512(x)
+            }
+        }
+
+        impl From<define_any!(@type $inner, MlKem768Params, MlKem768)> for $name {
+            fn from(x: define_any!(@type $inner, MlKem768Params, MlKem768)) -> Self {
+                Self::MlKem768(x)
+            }
+        }
+
+        impl From<define_any!(@type $inner, MlKem1024Params, MlKem1024)> for $name {
+            fn from(x: define_any!(@type $inner, MlKem1024Params, MlKem1024)) -> Self {
+                Self::MlKem1024(x)
+            }
+        }
+
+        impl ConstantTimeEq for $name {
+            fn ct_eq(&self, other: &Self) -> Choice {
+                match (self, other) {
+                    (Self::MlKem512(x), Self::MlKem512(y)) => x.ct_eq(y),
+                    (Self::MlKem768(x), Self::MlKem768(y)) => x.ct_eq(y),
+                    (Self::MlKem1024(x), Self::MlKem1024(y)) => x.ct_eq(y),
+                    _ => Choice::from(0),
+                }
+            }
+        }
+
+        impl $name {
+            /// The parameter set of this value
+            #[must_use]
+            pub fn param(&self) -> ParameterId {
+                match self {
+                    Self::MlKem512(_) => ParameterId::MlKem512,
+                    Self::MlKem768(_) => ParameterId::MlKem768,
+                    Self::MlKem1024(_) => ParameterId::MlKem1024,
+                }
+            }
+
+            /// Decode a value for the parameter set `param` from `bytes`, performing the same
+            /// validation checks as [`EncodedSizeUser::try_from_bytes`].
+            ///
+            /// # Errors
+            ///
+            /// Returns [`Error::InvalidLength`] if `bytes` has the wrong length for `param`, or
+            /// another error if it is not a valid encoding.
+            pub fn from_slice(param: ParameterId, bytes: &[u8]) -> Result<Self, Error> {
+                with_params!(param, $P => {
+                    <define_any!(@type $inner, $P, kem::Kem<$P>)>::try_from(bytes).map(Self::from)
+                })
+            }
+
+            /// The length of the encoded form of this value, in bytes
+            #[must_use]
+            pub fn encoded_len(&self) -> usize {
+                define_any!(@size $inner, self.param())
+            }
+
+            /// Encode this value to `bytes`
+            ///
+            /// # Errors
+            ///
+            /// Returns [`Error::InvalidLength`] if `bytes` is not [`Self::encoded_len`] long.
+            pub fn write_to_slice(&self, bytes: &mut [u8]) -> Result<(), Error> {
+                with_inner!(self, Self, x => x.write_to_slice(bytes))
+            }
+        }
+    };
+
+    (@type DecapsulationKey, $P:ty, $K:ty) => { kem::DecapsulationKey<$P> };
+    (@type EncapsulationKey, $P:ty, $K:ty) => { kem::EncapsulationKey<$P> };
+    (@type Ciphertext, $P:ty, $K:ty) => { crate::Ciphertext<$K> };
+
+    (@size DecapsulationKey, $param:expr) => { $param.decapsulation_key_size() };
+    (@size EncapsulationKey, $param:expr) => { $param.encapsulation_key_size() };
+    (@size Ciphertext, $param:expr) => { $param.ciphertext_size() };
+}
+
+// Decapsulation keys have no `to_vec`, since a plain vector would leave a copy of the secret key
+// that is never wiped.  They can be written to a buffer of the caller's with `write_to_slice`.
+macro_rules! define_to_vec {
+    ($name:ident) => {
+        impl $name {
+            /// Encode this value to a new vector
+            #[cfg(feature = "alloc")]
+            #[must_use]
+            pub fn to_vec(&self) -> Vec<u8> {
+                with_inner!(self, Self, x => x.as_bytes().to_vec())
+            }
+        }
+    };
+}
+
+define_any!(
+    /// An ML-KEM decapsulation key for a parameter set chosen at runtime
+    DecapsulationKey,
+    DecapsulationKey<P>,
+    "decapsulation key"
+);
+
+define_any!(
+    /// An ML-KEM encapsulation key for a parameter set chosen at runtime
+    EncapsulationKey,
+    EncapsulationKey<P>,
+    "encapsulation key"
+);
+
+define_any!(
+    /// An ML-KEM ciphertext for a parameter set chosen at runtime
+    Ciphertext,
+    Ciphertext<P>,
+    "ciphertext"
+);
+
+define_to_vec!(EncapsulationKey);
+define_to_vec!(Ciphertext);
+
+impl AsRef<[u8]> for Ciphertext {
+    fn as_ref(&self) -> &[u8] {
+        with_inner!(self, Self, x => x.as_ref())
+    }
+}
+
+impl DecapsulationKey {
+    /// The encapsulation key corresponding to this key
+    #[must_use]
+    pub fn encapsulation_key(&self) -> EncapsulationKey {
+        with_inner!(self, Self, x => x.encapsulation_key().clone().into())
+    }
+
+    /// Export the 64-byte seed `(d || z)` from which this key was expanded, as in
+    /// [`kem::DecapsulationKey::to_seed`]
+    #[must_use]
+    pub fn to_seed(&self) -> Option<Seed> {
+        with_inner!(self, Self, x => x.to_seed())
+    }
+
+    /// Decapsulate a ciphertext given as bytes, which must have the length of a ciphertext for
+    /// the parameter set of this key.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidLength`] if `ciphertext` has the wrong length, or with the
+    /// `self-test` feature, [`Error::SelfTest`] if the module is in the error state.
+    pub fn decapsulate_slice(&self, ciphertext: &[u8]) -> Result<SharedKey, Error> {
+        let mut shared_key = SharedKey::default();
+        self.decapsulate_into_slice(ciphertext, &mut shared_key)?;
+        Ok(shared_key)
+    }
+
+    /// Decapsulate a ciphertext given as bytes, writing the shared key to `shared_key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidLength`] if either slice has the wrong length, or with the
+    /// `self-test` feature, [`Error::SelfTest`] if the module is in the error state.
+    pub fn decapsulate_into_slice(
+        &self,
+        ciphertext: &[u8],
+        shared_key: &mut [u8],
+    ) -> Result<(), Error> {
+        with_inner!(self, Self, x => x.decapsulate_into_slice(ciphertext, shared_key))
+    }
+}
+
+impl Decapsulate<Ciphertext, SharedKey> for DecapsulationKey {
+    type Error = Error;
+
+    /// Decapsulate `encapsulated_key`, which must be for the parameter set of this key
+    fn decapsulate(&self, encapsulated_key: &Ciphertext) -> Result<SharedKey, Error> {
+        let k = match (self, encapsulated_key) {
+            (Self::MlKem512(dk), Ciphertext::MlKem512(ct)) => dk.decapsulate(ct)?,
+            (Self::MlKem768(dk), Ciphertext::MlKem768(ct)) => dk.decapsulate(ct)?,
+            (Self::MlKem1024(dk), Ciphertext::MlKem1024(ct)) => dk.decapsulate(ct)?,
+            _ => return Err(Error::ParameterMismatch),
+        };
+        Ok(k)
+    }
+}
+
+impl EncapsulationKey {
+    /// Encapsulate a fresh shared key, writing the ciphertext to `ciphertext` and the shared key
+    /// to `shared_key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidLength`] if either slice has the wrong length, or [`Error::Rng`]
+    /// if the RNG fails to provide randomness.
+    pub fn encapsulate_into_slice(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        ciphertext: &mut [u8],
+        shared_key: &mut [u8],
+    ) -> Result<(), Error> {
+        with_inner!(self, Self, x => x.encapsulate_into_slice(rng, ciphertext, shared_key))
+    }
+}
+
+impl Encapsulate<Ciphertext, SharedKey> for EncapsulationKey {
+    type Error = Error;
+
+    fn encapsulate(&self, rng: &mut impl CryptoRngCore) -> Result<(Ciphertext, SharedKey), Error> {
+        with_inner!(self, Self, x => {
+            let (ct, k) = x.encapsulate(rng)?;
+            Ok((ct.into(), k))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sizes() {
+        let sizes = ParameterId::ALL.map(|param| {
+            (
+                param.encapsulation_key_size(),
+                param.decapsulation_key_size(),
+                param.ciphertext_size(),
+            )
+        });
+        assert_eq!(
+            sizes,
+            [(800, 1632, 768), (1184, 2400, 1088), (1568, 3168, 1568)]
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut rng = rand::thread_rng();
+        for param in ParameterId::ALL {
+            let (dk, ek) = MlKemAny::generate(param, &mut rng);
+            assert_eq!(dk.param(), param);
+            assert_eq!(ek.param(), param);
+            assert_eq!(dk.encapsulation_key(), ek);
+
+            let (ct, k_send) = ek.encapsulate(&mut rng).unwrap();
+            assert_eq!(ct.param(), param);
+            assert_eq!(ct.encoded_len(), param.ciphertext_size());
+            assert_eq!(dk.decapsulate(&ct).unwrap(), k_send);
+            assert_eq!(dk.decapsulate_slice(ct.as_ref()).unwrap(), k_send);
+
+            let mut ct_bytes = [0u8; 1568];
+            let ct_bytes = &mut ct_bytes[..param.ciphertext_size()];
+            let mut k_bytes = [0u8; 32];
+            ek.encapsulate_into_slice(&mut rng, ct_bytes, &mut k_bytes)
+                .unwrap();
+            assert_eq!(dk.decapsulate_slice(ct_bytes).unwrap(), k_bytes);
+        }
+    }
+
+    #[test]
+    fn codec() {
+        let mut rng = rand::thread_rng();
+        for param in ParameterId::ALL {
+            let (dk, ek) = MlKemAny::try_generate(param, &mut rng).unwrap();
+            let (ct, _) = ek.encapsulate(&mut rng).unwrap();
+
+            assert_eq!(dk.encoded_len(), param.decapsulation_key_size());
+            assert_eq!(ek.encoded_len(), param.encapsulation_key_size());
+
+            let mut buf = [0u8; 3168];
+            let dk_bytes = &mut buf[..param.decapsulation_key_size()];
+            dk.write_to_slice(dk_bytes).unwrap();
+            assert_eq!(DecapsulationKey::from_slice(param, dk_bytes).unwrap(), dk);
+
+            let mut buf = [0u8; 1568];
+            let ek_bytes = &mut buf[..param.encapsulation_key_size()];
+            ek.write_to_slice(ek_bytes).unwrap();
+            assert_eq!(EncapsulationKey::from_slice(param, ek_bytes).unwrap(), ek);
+            assert_eq!(Ciphertext::from_slice(param, ct.as_ref()).unwrap(), ct);
+
+            // The length of the input determines nothing by itself
+            assert!(matches!(
+                EncapsulationKey::from_slice(param, &ek_bytes[1..]),
+                Err(Error::InvalidLength { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn seed() {
+        let seed = Seed::default();
+        for param in ParameterId::ALL {
+            let (dk, ek) = MlKemAny::from_seed(param, &seed);
+            assert_eq!(dk.to_seed(), Some(seed.clone()));
+
+            let (_, ek_768) = MlKem768::from_seed(&seed);
+            let ek_768 = EncapsulationKey::from(ek_768);
+            assert_eq!(ek == ek_768, param == ParameterId::MlKem768);
+            assert_eq!(
+                bool::from(ek.ct_eq(&ek_768)),
+                param == ParameterId::MlKem768
+            );
+        }
+    }
+
+    #[test]
+    fn mismatch() {
+        let mut rng = rand::thread_rng();
+        let (dk, _) = MlKemAny::generate(ParameterId::MlKem512, &mut rng);
+        let (_, ek) = MlKemAny::generate(ParameterId::MlKem768, &mut rng);
+        let (ct, _) = ek.encapsulate(&mut rng).unwrap();
+
+        assert_eq!(dk.decapsulate(&ct), Err(Error::ParameterMismatch));
+        assert_eq!(
+            dk.decapsulate_slice(ct.as_ref()),
+            Err(Error::InvalidLength {
+                expected: 768,
+                actual: 1088
+            })
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_vec() {
+        let mut rng = rand::thread_rng();
+        let (_, ek) = MlKemAny::generate(ParameterId::MlKem1024, &mut rng);
+        let bytes = ek.to_vec();
+        assert_eq!(bytes.len(), ek.encoded_len());
+        assert_eq!(
+            EncapsulationKey::from_slice(ParameterId::MlKem1024, &bytes).unwrap(),
+            ek
+        );
+    }
+}
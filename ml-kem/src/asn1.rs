@@ -0,0 +1,564 @@
+//! ML-KEM keys are identified by the NIST OIDs `id-alg-ml-kem-512`, `id-alg-ml-kem-768`, and
+//! `id-alg-ml-kem-1024`, with absent algorithm parameters.  An encapsulation key is carried
+//! directly in the `subjectPublicKey` BIT STRING of a `SubjectPublicKeyInfo`.  A decapsulation key
+//! is carried in the `privateKey` OCTET STRING of a `OneAsymmetricKey` as the DER encoding of:
+//!
+//! ```text
+//! ML-KEM-PrivateKey ::= CHOICE {
+//!   seed [0] IMPLICIT OCTET STRING (SIZE (64)),
+//!   expandedKey OCTET STRING (SIZE (1632 | 2400 | 3168)),
+//!   both SEQUENCE {
+//!     seed OCTET STRING (SIZE (64)),
+//!     expandedKey OCTET STRING (SIZE (1632 | 2400 | 3168))
+//!   }
+//! }
+//! ```
+
+use ::spki::{
+    der::{
+        asn1::AnyRef,
+        oid::{AssociatedOid, ObjectIdentifier},
+    },
+    AlgorithmIdentifier, AssociatedAlgorithmIdentifier, SubjectPublicKeyInfoRef,
+};
+
+#[cfg(feature = "alloc")]
+use ::spki::{der::asn1::BitStringRef, der::Document, EncodePublicKey, SubjectPublicKeyInfo};
+
+#[cfg(feature = "pkcs8")]
+use {
+    crate::{kem::DecapsulationKey, Seed},
+    ::pkcs8::{
+        der::{asn1::OctetStringRef, Decode, Tag, TagNumber, Tagged},
+        PrivateKeyInfo,
+    },
+    subtle::ConstantTimeEq,
+};
+
+#[cfg(all(feature = "pkcs8", feature = "alloc"))]
+use {
+    crate::util::wipe,
+    ::pkcs8::{
+        der::{
+            asn1::{ContextSpecificRef, SequenceOf},
+            zeroize::Zeroizing,
+            Encode, SecretDocument, TagMode,
+        },
+        EncodePrivateKey,
+    },
+};
+
+#[cfg(all(feature = "pkcs8", feature = "pem"))]
+use ::pkcs8::{der::pem::PemLabel, LineEnding};
+
+#[cfg(any(feature = "alloc", feature = "pkcs8"))]
+use crate::EncodedSizeUser;
+
+use crate::kem::EncapsulationKey;
+use crate::param::KemParams;
+use crate::{MlKem1024Params, MlKem512Params, MlKem768Params};
+
+impl AssociatedOid for MlKem512Params {
+    const OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.4.1");
+}
+
+impl AssociatedOid for MlKem768Params {
+    const OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.4.2");
+}
+
+impl AssociatedOid for MlKem1024Params {
+    const OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.4.3");
+}
+
+// The algorithm parameters MUST be absent
+fn check_algorithm<P>(algorithm: &AlgorithmIdentifier<AnyRef<'_>>) -> ::spki::Result<()>
+where
+    P: AssociatedOid,
+{
+    algorithm.assert_algorithm_oid(P::OID)?;
+    if algorithm.parameters.is_some() {
+        return Err(::spki::Error::KeyMalformed);
+    }
+    Ok(())
+}
+
+impl<P> AssociatedAlgorithmIdentifier for EncapsulationKey<P>
+where
+    P: KemParams + AssociatedOid,
+{
+    type Params = AnyRef<'static>;
+
+    const ALGORITHM_IDENTIFIER: AlgorithmIdentifier<Self::Params> = AlgorithmIdentifier {
+        oid: P::OID,
+        parameters: None,
+    };
+}
+
+impl<P> TryFrom<SubjectPublicKeyInfoRef<'_>> for EncapsulationKey<P>
+where
+    P: KemParams + AssociatedOid,
+{
+    type Error = ::spki::Error;
+
+    fn try_from(spki: SubjectPublicKeyInfoRef<'_>) -> ::spki::Result<Self> {
+        check_algorithm::<P>(&spki.algorithm)?;
+        let bytes = spki
+            .subject_public_key
+            .as_bytes()
+            .ok_or(::spki::Error::KeyMalformed)?;
+        Self::try_from(bytes).map_err(|_| ::spki::Error::KeyMalformed)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<P> EncodePublicKey for EncapsulationKey<P>
+where
+    P: KemParams + AssociatedOid,
+{
+    fn to_public_key_der(&self) -> ::spki::Result<Document> {
+        let public_key = self.as_bytes();
+        let subject_public_key = BitStringRef::new(0, &public_key)?;
+
+        SubjectPublicKeyInfo {
+            algorithm: Self::ALGORITHM_IDENTIFIER,
+            subject_public_key,
+        }
+        .try_into()
+    }
+}
+
+/// The choice of `ML-KEM-PrivateKey` encoding used when writing a decapsulation key to PKCS#8.
+#[cfg(feature = "pkcs8")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PrivateKeyFormat {
+    /// The 64-byte seed `(d || z)` alone.  This is the most compact form, and the one recommended
+    /// by the LAMPS specification.
+    Seed,
+
+    /// The expanded decapsulation key alone.  This is the only form available for a key that was
+    /// imported in expanded form, since the seed cannot be recovered from it.
+    Expanded,
+
+    /// Both the seed and the expanded key.  On import, the expanded key is checked against the one
+    /// derived from the seed.
+    Both,
+}
+
+#[cfg(feature = "pkcs8")]
+impl<P> AssociatedAlgorithmIdentifier for DecapsulationKey<P>
+where
+    P: KemParams + AssociatedOid,
+{
+    type Params = AnyRef<'static>;
+
+    const ALGORITHM_IDENTIFIER: AlgorithmIdentifier<Self::Params> = AlgorithmIdentifier {
+        oid: P::OID,
+        parameters: None,
+    };
+}
+
+#[cfg(feature = "pkcs8")]
+impl<P> TryFrom<PrivateKeyInfo<'_>> for DecapsulationKey<P>
+where
+    P: KemParams + AssociatedOid,
+{
+    type Error = ::pkcs8::Error;
+
+    fn try_from(pkcs8: PrivateKeyInfo<'_>) -> ::pkcs8::Result<Self> {
+        check_algorithm::<P>(&pkcs8.algorithm)?;
+
+        let private_key = AnyRef::from_der(pkcs8.private_key)?;
+        let dk = match private_key.tag() {
+            Tag::ContextSpecific {
+                constructed: false,
+                number: TagNumber::N0,
+            } => Self::from_seed(seed_from_slice(private_key.value())?),
+            Tag::OctetString => expanded_from_slice(private_key.value())?,
+            Tag::Sequence => {
+                let (seed, expanded) = private_key.sequence(|reader| {
+                    let seed = OctetStringRef::decode(reader)?;
+                    let expanded = OctetStringRef::decode(reader)?;
+                    Ok((seed, expanded))
+                })?;
+
+                let dk = Self::from_seed(seed_from_slice(seed.as_bytes())?);
+                let expanded = expanded_from_slice(expanded.as_bytes())?;
+                if !bool::from(dk.ct_eq(&expanded)) {
+                    return Err(::pkcs8::Error::KeyMalformed);
+                }
+                dk
+            }
+            tag => return Err(tag.value_error().into()),
+        };
+
+        // If the encoding includes a public key, it must be the one that goes with the private key
+        if let Some(public_key) = pkcs8.public_key {
+            if dk.encapsulation_key().as_bytes().as_slice() != public_key {
+                return Err(::pkcs8::Error::KeyMalformed);
+            }
+        }
+
+        Ok(dk)
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+fn seed_from_slice(bytes: &[u8]) -> ::pkcs8::Result<&Seed> {
+    bytes.try_into().map_err(|_| ::pkcs8::Error::KeyMalformed)
+}
+
+#[cfg(feature = "pkcs8")]
+fn expanded_from_slice<P>(bytes: &[u8]) -> ::pkcs8::Result<DecapsulationKey<P>>
+where
+    P: KemParams,
+{
+    DecapsulationKey::try_from(bytes).map_err(|_| ::pkcs8::Error::KeyMalformed)
+}
+
+#[cfg(all(feature = "pkcs8", feature = "alloc"))]
+impl<P> DecapsulationKey<P>
+where
+    P: KemParams + AssociatedOid,
+{
+    /// Serialize this key as a PKCS#8 `OneAsymmetricKey` using the specified private key format.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the seed is requested but this key does not know its seed, i.e., it
+    /// was imported from its expanded form.
+    pub fn to_pkcs8_der_with_format(
+        &self,
+        format: PrivateKeyFormat,
+    ) -> ::pkcs8::Result<SecretDocument> {
+        let mut seed = match format {
+            PrivateKeyFormat::Seed | PrivateKeyFormat::Both => {
+                Some(self.to_seed().ok_or(::pkcs8::Error::KeyMalformed)?)
+            }
+            PrivateKeyFormat::Expanded => None,
+        };
+        let mut expanded = match format {
+            PrivateKeyFormat::Expanded | PrivateKeyFormat::Both => Some(self.as_bytes()),
+            PrivateKeyFormat::Seed => None,
+        };
+
+        let private_key = encode_private_key(seed.as_ref(), expanded.as_deref());
+        wipe!(seed, expanded);
+
+        PrivateKeyInfo::new(Self::ALGORITHM_IDENTIFIER, &private_key?).try_into()
+    }
+
+    /// Serialize this key as a PEM-encoded PKCS#8 `OneAsymmetricKey` using the specified private
+    /// key format.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the seed is requested but this key does not know its seed, i.e., it
+    /// was imported from its expanded form.
+    #[cfg(feature = "pem")]
+    pub fn to_pkcs8_pem_with_format(
+        &self,
+        format: PrivateKeyFormat,
+        line_ending: LineEnding,
+    ) -> ::pkcs8::Result<Zeroizing<alloc::string::String>> {
+        let doc = self.to_pkcs8_der_with_format(format)?;
+        Ok(doc.to_pem(PrivateKeyInfo::PEM_LABEL, line_ending)?)
+    }
+}
+
+#[cfg(all(feature = "pkcs8", feature = "alloc"))]
+fn encode_private_key(
+    seed: Option<&Seed>,
+    expanded: Option<&[u8]>,
+) -> ::pkcs8::Result<Zeroizing<alloc::vec::Vec<u8>>> {
+    let der = match (seed, expanded) {
+        (Some(seed), None) => ContextSpecificRef {
+            tag_number: TagNumber::N0,
+            tag_mode: TagMode::Implicit,
+            value: &OctetStringRef::new(seed)?,
+        }
+        .to_der()?,
+        (None, Some(expanded)) => OctetStringRef::new(expanded)?.to_der()?,
+        (Some(seed), Some(expanded)) => {
+            let mut both = SequenceOf::<OctetStringRef<'_>, 2>::new();
+            both.add(OctetStringRef::new(seed)?)?;
+            both.add(OctetStringRef::new(expanded)?)?;
+            both.to_der()?
+        }
+        // There is no `ML-KEM-PrivateKey` without a seed or an expanded key
+        (None, None) => return Err(::pkcs8::Error::KeyMalformed),
+    };
+    Ok(Zeroizing::new(der))
+}
+
+// By default, keys are written in seed form if possible, and in expanded form otherwise.
+#[cfg(all(feature = "pkcs8", feature = "alloc"))]
+impl<P> EncodePrivateKey for DecapsulationKey<P>
+where
+    P: KemParams + AssociatedOid,
+{
+    fn to_pkcs8_der(&self) -> ::pkcs8::Result<SecretDocument> {
+        let format = if self.has_seed() {
+            PrivateKeyFormat::Seed
+        } else {
+            PrivateKeyFormat::Expanded
+        };
+        self.to_pkcs8_der_with_format(format)
+    }
+}
+
+#[cfg(all(test, feature = "pkcs8", feature = "pem"))]
+mod test {
+    use super::*;
+    use crate::{kem::Kem, KemCore};
+    use ::kem::{Decapsulate, Encapsulate};
+    use ::pkcs8::{DecodePrivateKey, EncodePrivateKey};
+    use ::spki::{DecodePublicKey, EncodePublicKey};
+    use alloc::vec;
+
+    fn round_trip_test<P>()
+    where
+        P: KemParams + AssociatedOid,
+    {
+        let mut rng = rand::thread_rng();
+        let (dk, ek) = Kem::<P>::generate(&mut rng);
+
+        let pem = ek.to_public_key_pem(LineEnding::LF).unwrap();
+        assert_eq!(
+            ek,
+            EncapsulationKey::<P>::from_public_key_pem(&pem).unwrap()
+        );
+
+        for format in [
+            PrivateKeyFormat::Seed,
+            PrivateKeyFormat::Expanded,
+            PrivateKeyFormat::Both,
+        ] {
+            let der = dk.to_pkcs8_der_with_format(format).unwrap();
+            let dk2 = DecapsulationKey::<P>::from_pkcs8_der(der.as_bytes()).unwrap();
+            assert_eq!(dk, dk2);
+            assert_eq!(dk2.has_seed(), format != PrivateKeyFormat::Expanded);
+        }
+
+        // Keys are written in seed form when possible, and in expanded form otherwise
+        let pem = dk.to_pkcs8_pem(LineEnding::LF).unwrap();
+        let seed = dk.to_pkcs8_pem_with_format(PrivateKeyFormat::Seed, LineEnding::LF);
+        assert_eq!(pem, seed.unwrap());
+
+        let dk = DecapsulationKey::<P>::from_bytes(&dk.as_bytes());
+        let pem = dk.to_pkcs8_pem(LineEnding::LF).unwrap();
+        let expanded = dk.to_pkcs8_pem_with_format(PrivateKeyFormat::Expanded, LineEnding::LF);
+        assert_eq!(pem, expanded.unwrap());
+        assert!(dk.to_pkcs8_der_with_format(PrivateKeyFormat::Seed).is_err());
+        assert!(dk.to_pkcs8_der_with_format(PrivateKeyFormat::Both).is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        round_trip_test::<MlKem512Params>();
+        round_trip_test::<MlKem768Params>();
+        round_trip_test::<MlKem1024Params>();
+    }
+
+    fn inconsistent_both_test<P>()
+    where
+        P: KemParams + AssociatedOid,
+    {
+        let mut rng = rand::thread_rng();
+        let (dk1, _) = Kem::<P>::generate(&mut rng);
+        let (dk2, _) = Kem::<P>::generate(&mut rng);
+
+        let seed = dk1.to_seed().unwrap();
+        let expanded = dk2.as_bytes();
+        let private_key = encode_private_key(Some(&seed), Some(&expanded)).unwrap();
+        let pkcs8 = PrivateKeyInfo::new(DecapsulationKey::<P>::ALGORITHM_IDENTIFIER, &private_key);
+        assert_eq!(
+            DecapsulationKey::<P>::try_from(pkcs8),
+            Err(::pkcs8::Error::KeyMalformed)
+        );
+    }
+
+    #[test]
+    fn inconsistent_both() {
+        inconsistent_both_test::<MlKem512Params>();
+        inconsistent_both_test::<MlKem768Params>();
+        inconsistent_both_test::<MlKem1024Params>();
+    }
+
+    // The DER encodings of the `seed` and `both` choices, built by hand from the ASN.1 module
+    fn private_key_der_test<P>()
+    where
+        P: KemParams + AssociatedOid,
+    {
+        let (dk, _) = Kem::<P>::generate(&mut rand::thread_rng());
+        let seed = dk.to_seed().unwrap();
+        let expanded = dk.as_bytes();
+        let expanded_len = u16::try_from(expanded.len()).unwrap().to_be_bytes();
+
+        // seed [0] IMPLICIT OCTET STRING (SIZE (64))
+        let mut seed_der = vec![0x80, 0x40];
+        seed_der.extend_from_slice(&seed);
+
+        // both SEQUENCE { seed OCTET STRING, expandedKey OCTET STRING }
+        let both_len = u16::try_from(2 + 64 + 4 + expanded.len()).unwrap();
+        let mut both_der = vec![0x30, 0x82];
+        both_der.extend_from_slice(&both_len.to_be_bytes());
+        both_der.extend_from_slice(&[0x04, 0x40]);
+        both_der.extend_from_slice(&seed);
+        both_der.extend_from_slice(&[0x04, 0x82]);
+        both_der.extend_from_slice(&expanded_len);
+        both_der.extend_from_slice(&expanded);
+
+        for (format, der) in [
+            (PrivateKeyFormat::Seed, &seed_der),
+            (PrivateKeyFormat::Both, &both_der),
+        ] {
+            let doc = dk.to_pkcs8_der_with_format(format).unwrap();
+            let pkcs8 = PrivateKeyInfo::try_from(doc.as_bytes()).unwrap();
+            assert_eq!(pkcs8.private_key, der.as_slice());
+
+            let pkcs8 = PrivateKeyInfo::new(DecapsulationKey::<P>::ALGORITHM_IDENTIFIER, der);
+            let dk2 = DecapsulationKey::<P>::try_from(pkcs8).unwrap();
+            assert_eq!(dk2, dk);
+            assert!(dk2.has_seed());
+        }
+
+        // A seed of the wrong length is rejected, in either choice
+        let mut short_seed = seed_der.clone();
+        short_seed[1] = 0x3f;
+        short_seed.pop();
+        let mut short_both = vec![0x30, 0x82];
+        short_both.extend_from_slice(&(both_len - 1).to_be_bytes());
+        short_both.extend_from_slice(&[0x04, 0x3f]);
+        short_both.extend_from_slice(&seed[1..]);
+        short_both.extend_from_slice(&both_der[4 + 2 + 64..]);
+        for der in [&short_seed, &short_both] {
+            let pkcs8 = PrivateKeyInfo::new(DecapsulationKey::<P>::ALGORITHM_IDENTIFIER, der);
+            assert!(DecapsulationKey::<P>::try_from(pkcs8).is_err());
+        }
+
+        // The seed must be implicitly tagged with [0]
+        let mut wrong_tag = seed_der.clone();
+        wrong_tag[0] = 0x81;
+        let pkcs8 = PrivateKeyInfo::new(DecapsulationKey::<P>::ALGORITHM_IDENTIFIER, &wrong_tag);
+        assert!(DecapsulationKey::<P>::try_from(pkcs8).is_err());
+
+        // Both halves of the `both` choice must describe the same key
+        let mut modified_expanded = both_der.clone();
+        let last = modified_expanded.len() - 1;
+        modified_expanded[last] ^= 1;
+        let pkcs8 = PrivateKeyInfo::new(
+            DecapsulationKey::<P>::ALGORITHM_IDENTIFIER,
+            &modified_expanded,
+        );
+        assert_eq!(
+            DecapsulationKey::<P>::try_from(pkcs8),
+            Err(::pkcs8::Error::KeyMalformed)
+        );
+    }
+
+    #[test]
+    fn private_key_der() {
+        private_key_der_test::<MlKem512Params>();
+        private_key_der_test::<MlKem768Params>();
+        private_key_der_test::<MlKem1024Params>();
+    }
+
+    #[test]
+    fn encode_nothing() {
+        assert_eq!(
+            encode_private_key(None, None),
+            Err(::pkcs8::Error::KeyMalformed)
+        );
+    }
+
+    #[test]
+    fn wrong_algorithm() {
+        let mut rng = rand::thread_rng();
+        let (dk, ek) = Kem::<MlKem768Params>::generate(&mut rng);
+
+        let der = ek.to_public_key_der().unwrap();
+        assert!(EncapsulationKey::<MlKem512Params>::from_public_key_der(der.as_bytes()).is_err());
+
+        let der = dk.to_pkcs8_der().unwrap();
+        assert!(DecapsulationKey::<MlKem1024Params>::from_pkcs8_der(der.as_bytes()).is_err());
+    }
+
+    // Keys, ciphertexts, and shared secrets generated with OpenSSL 3.5
+    fn openssl_test<P>(expanded_pem: &str, public_pem: &str, ct: &[u8], ss: &[u8])
+    where
+        P: KemParams + AssociatedOid,
+    {
+        let dk = DecapsulationKey::<P>::from_pkcs8_pem(expanded_pem).unwrap();
+        let ek = EncapsulationKey::<P>::from_public_key_pem(public_pem).unwrap();
+        assert_eq!(dk.encapsulation_key(), &ek);
+
+        // Our encodings match OpenSSL's byte for byte
+        let pem = dk.to_pkcs8_pem_with_format(PrivateKeyFormat::Expanded, LineEnding::LF);
+        assert_eq!(pem.unwrap().as_str(), expanded_pem);
+        assert_eq!(ek.to_public_key_pem(LineEnding::LF).unwrap(), public_pem);
+
+        let ct = crate::Ciphertext::<Kem<P>>::try_from(ct).unwrap();
+        assert_eq!(dk.decapsulate(&ct).unwrap().as_slice(), ss);
+
+        let (ct, k_send) = ek.encapsulate(&mut rand::thread_rng()).unwrap();
+        assert_eq!(dk.decapsulate(&ct).unwrap(), k_send);
+    }
+
+    // The same keys in seed and "both" form, which exercise the final FIPS 203 key generation
+    fn openssl_seed_test<P>(expanded_pem: &str, seed_pem: &str, both_pem: &str)
+    where
+        P: KemParams + AssociatedOid,
+    {
+        let expanded = DecapsulationKey::<P>::from_pkcs8_pem(expanded_pem).unwrap();
+        let seed = DecapsulationKey::<P>::from_pkcs8_pem(seed_pem).unwrap();
+        let both = DecapsulationKey::<P>::from_pkcs8_pem(both_pem).unwrap();
+        assert_eq!(seed, expanded);
+        assert_eq!(both, expanded);
+
+        let pem = seed.to_pkcs8_pem(LineEnding::LF).unwrap();
+        assert_eq!(pem.as_str(), seed_pem);
+        let pem = seed.to_pkcs8_pem_with_format(PrivateKeyFormat::Both, LineEnding::LF);
+        assert_eq!(pem.unwrap().as_str(), both_pem);
+    }
+
+    #[test]
+    fn openssl_seed() {
+        openssl_seed_test::<MlKem512Params>(
+            include_str!("../tests/examples/mlkem512-expanded.pem"),
+            include_str!("../tests/examples/mlkem512-seed.pem"),
+            include_str!("../tests/examples/mlkem512-both.pem"),
+        );
+        openssl_seed_test::<MlKem768Params>(
+            include_str!("../tests/examples/mlkem768-expanded.pem"),
+            include_str!("../tests/examples/mlkem768-seed.pem"),
+            include_str!("../tests/examples/mlkem768-both.pem"),
+        );
+        openssl_seed_test::<MlKem1024Params>(
+            include_str!("../tests/examples/mlkem1024-expanded.pem"),
+            include_str!("../tests/examples/mlkem1024-seed.pem"),
+            include_str!("../tests/examples/mlkem1024-both.pem"),
+        );
+    }
+
+    #[test]
+    fn openssl() {
+        openssl_test::<MlKem512Params>(
+            include_str!("../tests/examples/mlkem512-expanded.pem"),
+            include_str!("../tests/examples/mlkem512-pub.pem"),
+            include_bytes!("../tests/examples/mlkem512-ct.bin"),
+            include_bytes!("../tests/examples/mlkem512-ss.bin"),
+        );
+        openssl_test::<MlKem768Params>(
+            include_str!("../tests/examples/mlkem768-expanded.pem"),
+            include_str!("../tests/examples/mlkem768-pub.pem"),
+            include_bytes!("../tests/examples/mlkem768-ct.bin"),
+            include_bytes!("../tests/examples/mlkem768-ss.bin"),
+        );
+        openssl_test::<MlKem1024Params>(
+            include_str!("../tests/examples/mlkem1024-expanded.pem"),
+            include_str!("../tests/examples/mlkem1024-pub.pem"),
+            include_bytes!("../tests/examples/mlkem1024-ct.bin"),
+            include_bytes!("../tests/examples/mlkem1024-ss.bin"),
+        );
+    }
+}
@@ -6,6 +6,8 @@ use crate::util::Truncate;
 pub trait CompressionFactor: EncodingSize {
     const POW2_HALF: u32;
     const MASK: Integer;
+    const DIV_SHIFT: usize;
+    const DIV_MUL: u64;
 }
 
 impl<T> CompressionFactor for T
@@ -14,6 +16,13 @@ where
 {
     const POW2_HALF: u32 = 1 << (T::USIZE - 1);
     const MASK: Integer = ((1 as Integer) << T::USIZE) - 1;
+
+    // Compression divides values `y < 2^(12 + d)` by `q`.  We replace that division with a
+    // multiplication by `ceil(2^(24 + d) / q)` and a shift by `24 + d`.  The error introduced by
+    // rounding the multiplier up is less than `y / 2^(24 + d) < 2^-12 < 1/q`, which is not
+    // enough to push `y / q` past the next integer, so the quotient is exact.
+    const DIV_SHIFT: usize = 24 + T::USIZE;
+    const DIV_MUL: u64 = (1u64 << Self::DIV_SHIFT).div_ceil(FieldElement::Q64);
 }
 
 // Traits for objects that allow compression / decompression
@@ -28,16 +37,22 @@ impl Compress for FieldElement {
     // Here and in decompression, we leverage the following fact:
     //
     //   round(a / b) = floor((a + b/2) / b)
+    //
+    // The value being compressed may be secret, so we avoid the hardware division instruction,
+    // whose timing can depend on its operands (cf. KyberSlash).  Instead, we divide by `q` with a
+    // constant-time multiply and shift; see `CompressionFactor::DIV_MUL`.
     fn compress<D: CompressionFactor>(&mut self) -> &Self {
-        const Q_HALF: u32 = (FieldElement::Q32 - 1) / 2;
-        let x = u32::from(self.0);
-        let y = ((x << D::USIZE) + Q_HALF) / FieldElement::Q32;
-        self.0 = y.truncate() & D::MASK;
+        const Q_HALF: u64 = (FieldElement::Q64 - 1) / 2;
+        let x = u64::from(self.canonical());
+        let y: u32 = ((((x << D::USIZE) + Q_HALF) * D::DIV_MUL) >> D::DIV_SHIFT).truncate();
+        let y: Integer = y.truncate();
+        self.0 = y & D::MASK;
         self
     }
     // Equation 4.6: Decomporess_d(x) = round((q / 2^d) x)
     fn decompress<D: CompressionFactor>(&mut self) -> &Self {
-        let x = u32::from(self.0);
+        // Compressed values are in `[0, 2^d)`, so they are their own canonical representatives
+        let x = u32::from(self.0.unsigned_abs());
         let y = ((x * FieldElement::Q32) + D::POW2_HALF) >> D::USIZE;
         self.0 = y.truncate();
         self
@@ -107,6 +122,31 @@ pub(crate) mod test {
         }
     }
 
+    // Verify that the division-free compression routine produces the same results as the
+    // straightforward formula with a division, for every possible input.
+    fn compression_division_free_test<D: CompressionFactor>() {
+        const Q_HALF: u32 = (FieldElement::Q32 - 1) / 2;
+
+        for x in 0..FieldElement::Q {
+            let expected = ((u32::from(x.unsigned_abs()) << D::USIZE) + Q_HALF) / FieldElement::Q32;
+            let expected: Integer = expected.truncate();
+            let expected = expected & D::MASK;
+
+            let mut actual = FieldElement(x);
+            actual.compress::<D>();
+            assert_eq!(actual.0, expected);
+        }
+    }
+
+    #[test]
+    fn compress_division_free() {
+        compression_division_free_test::<U1>();
+        compression_division_free_test::<U4>();
+        compression_division_free_test::<U5>();
+        compression_division_free_test::<U10>();
+        compression_division_free_test::<U11>();
+    }
+
     #[test]
     fn compress_decompress() {
         compression_known_answer_test::<U1>();
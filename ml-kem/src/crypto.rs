@@ -7,8 +7,10 @@ use sha3::{
     Digest, Sha3_256, Sha3_512, Shake128, Shake256,
 };
 
+use crate::keccak::{Shake128X4, Shake256X4, LANES, SHAKE256_RATE};
 use crate::param::{CbdSamplingSize, EncodedPolynomial};
-use crate::util::B32;
+use crate::util::{wipe, B32};
+use crate::Error;
 
 pub fn rand<L: ArraySize>(rng: &mut impl CryptoRngCore) -> Array<u8, L> {
     let mut val = Array::default();
@@ -16,18 +18,25 @@ pub fn rand<L: ArraySize>(rng: &mut impl CryptoRngCore) -> Array<u8, L> {
     val
 }
 
+pub fn try_rand<L: ArraySize>(rng: &mut impl CryptoRngCore) -> Result<Array<u8, L>, Error> {
+    let mut val = Array::default();
+    rng.try_fill_bytes(&mut val).map_err(|_| Error::Rng)?;
+    Ok(val)
+}
+
 pub fn G(inputs: &[impl AsRef<[u8]>]) -> (B32, B32) {
     let mut h = Sha3_512::new();
     for x in inputs {
         Digest::update(&mut h, x);
     }
-    let out = h.finalize();
+    let mut out = h.finalize();
 
     let mut a = B32::default();
     let mut b = B32::default();
 
     a.copy_from_slice(&out[..32]);
     b.copy_from_slice(&out[32..]);
+    wipe!(*out.as_mut_slice());
     (a, b)
 }
 
@@ -78,6 +87,48 @@ pub fn XOF(rho: &B32, i: u8, j: u8) -> impl XofReader {
     h.finalize_xof()
 }
 
+/// `PRF` on up to four values of `b` at once.  Outputs for lanes beyond `b.len()` are left zero.
+pub fn PRF_x4<Eta>(s: &B32, b: &[u8]) -> [PrfOutput<Eta>; LANES]
+where
+    Eta: CbdSamplingSize,
+{
+    let mut inputs = [[0u8; 33]; LANES];
+    for (input, &b) in inputs.iter_mut().zip(b) {
+        input[..32].copy_from_slice(s);
+        input[32] = b;
+    }
+
+    let lanes: [&[u8]; LANES] = core::array::from_fn(|l| &inputs[l][..]);
+    let mut h = Shake256X4::new(&lanes[..b.len()]);
+
+    let mut out: [PrfOutput<Eta>; LANES] = Default::default();
+    let mut blocks = [[0u8; SHAKE256_RATE]; LANES];
+    for start in (0..PrfOutput::<Eta>::default().len()).step_by(SHAKE256_RATE) {
+        h.squeeze(&mut blocks);
+        for (out, block) in out.iter_mut().zip(&blocks).take(b.len()) {
+            let chunk = &mut out[start..];
+            let n = chunk.len().min(SHAKE256_RATE);
+            chunk[..n].copy_from_slice(&block[..n]);
+        }
+    }
+
+    wipe!(inputs, h, blocks);
+    out
+}
+
+/// `XOF` on up to four index pairs `(i, j)` at once
+pub fn XOF_x4(rho: &B32, ij: &[(u8, u8)]) -> Shake128X4 {
+    let mut inputs = [[0u8; 34]; LANES];
+    for (input, &(i, j)) in inputs.iter_mut().zip(ij) {
+        input[..32].copy_from_slice(rho);
+        input[32] = i;
+        input[33] = j;
+    }
+
+    let lanes: [&[u8]; LANES] = core::array::from_fn(|l| &inputs[l][..]);
+    Shake128X4::new(&lanes[..ij.len()])
+}
+
 // // A Go script to generate the test vector outputs
 //
 // package main
@@ -136,6 +187,8 @@ pub fn XOF(rho: &B32, i: u8, j: u8) -> impl XofReader {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::keccak::SHAKE128_RATE;
+    use crate::util::Truncate;
     use hex_literal::hex;
     use hybrid_array::typenum::{U2, U3};
 
@@ -207,4 +260,47 @@ mod test {
         let expected = hex!("0d2c3e65f754d074cb366cf1b099ae105cc40f018342509f15f1ba8a1a4144cb");
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn prf_x4() {
+        let s = B32::from_slice("Input s to an invocation of PRFs".as_bytes());
+        for lanes in 1..=LANES {
+            let b: [u8; LANES] = core::array::from_fn(|l| b'a' + l.truncate());
+            let b = &b[..lanes];
+
+            let actual = PRF_x4::<U2>(s, b);
+            for (l, actual) in actual.iter().enumerate() {
+                let expected = b.get(l).map_or_else(Default::default, |&b| PRF::<U2>(s, b));
+                assert_eq!(actual, &expected);
+            }
+
+            let actual = PRF_x4::<U3>(s, b);
+            for (l, actual) in actual.iter().enumerate() {
+                let expected = b.get(l).map_or_else(Default::default, |&b| PRF::<U3>(s, b));
+                assert_eq!(actual, &expected);
+            }
+        }
+    }
+
+    #[test]
+    fn xof_x4() {
+        let rho = B32::from_slice("Input rho, to an XOF invocation!".as_bytes());
+        let ij = [(0, 0), (0, 1), (1, 0), (b'i', b'j')];
+        for lanes in 1..=LANES {
+            let mut xof = XOF_x4(rho, &ij[..lanes]);
+            let mut actual = [[[0u8; SHAKE128_RATE]; LANES]; 2];
+            for blocks in &mut actual {
+                xof.squeeze(blocks);
+            }
+
+            for (l, &(i, j)) in ij.iter().enumerate().take(lanes) {
+                let mut reader = XOF(rho, i, j);
+                for blocks in &actual {
+                    let mut expected = [0u8; SHAKE128_RATE];
+                    reader.read(&mut expected);
+                    assert_eq!(blocks[l], expected);
+                }
+            }
+        }
+    }
 }
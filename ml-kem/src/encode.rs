@@ -15,24 +15,41 @@ type DecodedValue = Array<FieldElement, U256>;
 //
 // Note: This algorithm performs compression as well as encoding.
 fn byte_encode<D: EncodingSize>(vals: &DecodedValue) -> EncodedPolynomial<D> {
+    let mut bytes = EncodedPolynomial::<D>::default();
+    byte_encode_into::<D>(vals, &mut bytes);
+    bytes
+}
+
+// Algorithm 4 ByteEncode_d(F), writing the encoding to `bytes`
+fn byte_encode_into<D: EncodingSize>(vals: &DecodedValue, bytes: &mut [u8]) {
     let val_step = D::ValueStep::USIZE;
     let byte_step = D::ByteStep::USIZE;
 
-    let mut bytes = EncodedPolynomial::<D>::default();
-
     let vc = vals.chunks(val_step);
     let bc = bytes.chunks_mut(byte_step);
     for (v, b) in vc.zip(bc) {
         let mut x = 0u128;
         for (j, vj) in v.iter().enumerate() {
-            x |= u128::from(vj.0) << (D::USIZE * j);
+            x |= u128::from(vj.canonical()) << (D::USIZE * j);
         }
 
         let xb = x.to_le_bytes();
         b.copy_from_slice(&xb[..byte_step]);
     }
+}
 
-    bytes
+/// Encode `vector` with `ByteEncode_d` directly into `enc`, one polynomial at a time.  Unlike
+/// [`Encode::encode`], this leaves no intermediate copy of the encoding behind, so it is used for
+/// secret values.
+pub(crate) fn encode_ntt_vector_into<D, K>(vector: &NttVector<K>, enc: &mut [u8])
+where
+    D: EncodingSize,
+    K: ArraySize,
+{
+    let poly_size = D::EncodedPolynomialSize::USIZE;
+    for (poly, enc) in vector.0.iter().zip(enc.chunks_mut(poly_size)) {
+        byte_encode_into::<D>(&poly.0, enc);
+    }
 }
 
 // Algorithm 5 ByteDecode_d(F)
@@ -189,7 +206,10 @@ pub(crate) mod test {
             12 => FieldElement::Q,
             d => (1 as Integer) << d,
         };
-        let decoded = decoded.iter().map(|x| FieldElement(x % m)).collect();
+        let decoded = decoded
+            .iter()
+            .map(|x| FieldElement(x.rem_euclid(m)))
+            .collect();
 
         let actual_encoded = byte_encode::<D>(&decoded);
         let actual_decoded = byte_decode::<D>(&actual_encoded);
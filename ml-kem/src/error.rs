@@ -0,0 +1,73 @@
+use core::{convert::Infallible, fmt};
+
+/// Errors that can result from operations in this crate
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// An encapsulation key failed the FIPS 203 modulus check, i.e., it contains a coefficient
+    /// that is not reduced modulo `q`, so it does not re-encode to the same bytes.
+    InvalidEncapsulationKey,
+
+    /// The `dk_PKE` component of a decapsulation key contains a coefficient that is not reduced
+    /// modulo `q`.
+    InvalidDecryptionKey,
+
+    /// The hash `h` embedded in a decapsulation key does not match `H(ek)`, where `ek` is the
+    /// encapsulation key embedded in the same decapsulation key.
+    DecapsulationKeyHashMismatch,
+
+    /// A byte string had the wrong length for the object it was supposed to encode.
+    InvalidLength {
+        /// The length required by the object being decoded
+        expected: usize,
+
+        /// The length of the byte string that was provided
+        actual: usize,
+    },
+
+    /// The random number generator failed to provide randomness.
+    Rng,
+
+    /// A newly generated key pair failed the pairwise consistency test, i.e., decapsulating a
+    /// ciphertext encapsulated to the new encapsulation key did not recover the shared key.
+    PairwiseConsistency,
+
+    /// A cryptographic algorithm self-test failed, so the module is in the error state and
+    /// refuses to perform further operations.
+    SelfTest,
+
+    /// A key and a ciphertext, or two keys, belong to different parameter sets.
+    ParameterMismatch,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidEncapsulationKey => {
+                f.write_str("encapsulation key contains a coefficient that is not reduced mod q")
+            }
+            Self::InvalidDecryptionKey => {
+                f.write_str("decryption key contains a coefficient that is not reduced mod q")
+            }
+            Self::DecapsulationKeyHashMismatch => {
+                f.write_str("decapsulation key hash does not match its encapsulation key")
+            }
+            Self::InvalidLength { expected, actual } => {
+                write!(f, "invalid length: expected {expected} bytes, got {actual}")
+            }
+            Self::Rng => f.write_str("random number generator failure"),
+            Self::PairwiseConsistency => f.write_str("pairwise consistency test failed"),
+            Self::SelfTest => f.write_str("self-test failed; module is in the error state"),
+            Self::ParameterMismatch => f.write_str("inputs belong to different parameter sets"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+// Allows `?` to be used uniformly on operations that cannot fail, such as decapsulation.
+impl From<Infallible> for Error {
+    fn from(e: Infallible) -> Self {
+        match e {}
+    }
+}
@@ -0,0 +1,189 @@
+//! SHAKE128 and SHAKE256 on up to four independent inputs at once.  The Keccak-f[1600]
+//! permutation runs on all four states together with AVX2 where it is available; otherwise each
+//! active state is permuted in turn.
+//!
+//! This only supports what ML-KEM needs: each input is shorter than one block, and output is
+//! squeezed a whole block at a time.
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// A four-way Keccak-f[1600] implementation with AVX2
+#[cfg(target_arch = "x86_64")]
+mod avx2;
+
+/// The number of states processed together
+pub const LANES: usize = 4;
+
+/// Four Keccak states, interleaved so that `state[i][l]` is word `i` of the state of lane `l`
+type State = [[u64; LANES]; 25];
+
+/// A SHAKE instance with rate `RATE` bytes for each of up to four lanes
+pub struct ShakeX4<const RATE: usize> {
+    state: State,
+    lanes: usize,
+}
+
+/// The block size of SHAKE128, in bytes
+pub const SHAKE128_RATE: usize = 168;
+
+/// The block size of SHAKE256, in bytes
+pub const SHAKE256_RATE: usize = 136;
+
+/// SHAKE128, used for `XOF`
+pub type Shake128X4 = ShakeX4<SHAKE128_RATE>;
+
+/// SHAKE256, used for `PRF`
+pub type Shake256X4 = ShakeX4<SHAKE256_RATE>;
+
+#[cfg(feature = "zeroize")]
+impl<const RATE: usize> Zeroize for ShakeX4<RATE> {
+    fn zeroize(&mut self) {
+        self.state.zeroize();
+    }
+}
+
+impl<const RATE: usize> ShakeX4<RATE> {
+    /// Absorb and pad one input per lane.  Between one and four inputs may be given, each of
+    /// which must be shorter than a block.
+    pub fn new(inputs: &[&[u8]]) -> Self {
+        assert!((1..=LANES).contains(&inputs.len()));
+
+        let mut out = Self {
+            state: [[0; LANES]; 25],
+            lanes: inputs.len(),
+        };
+
+        for (lane, input) in inputs.iter().enumerate() {
+            assert!(input.len() < RATE);
+
+            for (i, &b) in input.iter().enumerate() {
+                out.xor_byte(lane, i, b);
+            }
+
+            // SHAKE domain separation and pad10*1
+            out.xor_byte(lane, input.len(), 0x1f);
+            out.xor_byte(lane, RATE - 1, 0x80);
+        }
+
+        out
+    }
+
+    fn xor_byte(&mut self, lane: usize, i: usize, b: u8) {
+        self.state[i / 8][lane] ^= u64::from(b) << (8 * (i % 8));
+    }
+
+    /// Squeeze the next block of output for each active lane.  Blocks for inactive lanes are left
+    /// untouched.
+    pub fn squeeze(&mut self, out: &mut [[u8; RATE]; LANES]) {
+        self.permute();
+
+        for (lane, block) in out.iter_mut().enumerate().take(self.lanes) {
+            for (word, chunk) in self.state.iter().zip(block.chunks_mut(8)) {
+                chunk.copy_from_slice(&word[lane].to_le_bytes()[..chunk.len()]);
+            }
+        }
+    }
+
+    fn permute(&mut self) {
+        #[cfg(target_arch = "x86_64")]
+        if avx2::f1600x4(&mut self.state) {
+            return;
+        }
+
+        permute_portable(&mut self.state, self.lanes);
+    }
+}
+
+/// Permute the first `lanes` states one at a time
+fn permute_portable(state: &mut State, lanes: usize) {
+    for lane in 0..lanes {
+        let mut words: [u64; 25] = core::array::from_fn(|i| state[i][lane]);
+        keccak::f1600(&mut words);
+
+        for (word, x) in state.iter_mut().zip(&words) {
+            word[lane] = *x;
+        }
+
+        #[cfg(feature = "zeroize")]
+        words.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::RngCore;
+    use sha3::digest::{ExtendableOutput, Update, XofReader};
+    use sha3::{Shake128, Shake256};
+
+    fn check<const RATE: usize, H: Default + Update + ExtendableOutput>() {
+        let mut rng = rand::thread_rng();
+        let mut inputs = [[0u8; 64]; LANES];
+        for input in &mut inputs {
+            rng.fill_bytes(input);
+        }
+
+        for lanes in 1..=LANES {
+            // Inputs of different lengths, including the empty one
+            let inputs: [&[u8]; LANES] = core::array::from_fn(|l| &inputs[l][..(l * 21) % 64]);
+
+            let mut shake = ShakeX4::<RATE>::new(&inputs[..lanes]);
+            let mut actual = [[[0u8; RATE]; LANES]; 3];
+            for blocks in &mut actual {
+                shake.squeeze(blocks);
+            }
+
+            for (lane, input) in inputs.iter().enumerate() {
+                let mut expected = [[0u8; RATE]; 3];
+                let mut h = H::default();
+                h.update(input);
+                let mut reader = h.finalize_xof();
+                for block in &mut expected {
+                    reader.read(block);
+                }
+
+                for (actual, expected) in actual.iter().zip(&expected) {
+                    if lane < lanes {
+                        assert_eq!(&actual[lane], expected);
+                    } else {
+                        assert_eq!(actual[lane], [0u8; RATE]);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn shake128() {
+        check::<SHAKE128_RATE, Shake128>();
+    }
+
+    #[test]
+    fn shake256() {
+        check::<SHAKE256_RATE, Shake256>();
+    }
+
+    #[test]
+    fn portable() {
+        let mut rng = rand::thread_rng();
+        let mut state: State = [[0; LANES]; 25];
+        for word in state.iter_mut().flatten() {
+            *word = rng.next_u64();
+        }
+
+        let mut actual = state;
+        permute_portable(&mut actual, 3);
+
+        for lane in 0..LANES {
+            let mut expected: [u64; 25] = core::array::from_fn(|i| state[i][lane]);
+            if lane < 3 {
+                keccak::f1600(&mut expected);
+            }
+
+            for (word, x) in actual.iter().zip(&expected) {
+                assert_eq!(word[lane], *x);
+            }
+        }
+    }
+}
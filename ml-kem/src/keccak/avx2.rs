@@ -0,0 +1,153 @@
+//! Keccak-f[1600] on four interleaved states, with each 64-bit word of the four states held in
+//! one AVX2 register.
+
+#[allow(clippy::wildcard_imports)]
+use core::arch::x86_64::*;
+
+use super::State;
+
+cpufeatures::new!(avx2_cpuid, "avx2");
+
+/// Round constants for the iota step
+const RC: [u64; 24] = [
+    0x0000_0000_0000_0001,
+    0x0000_0000_0000_8082,
+    0x8000_0000_0000_808a,
+    0x8000_0000_8000_8000,
+    0x0000_0000_0000_808b,
+    0x0000_0000_8000_0001,
+    0x8000_0000_8000_8081,
+    0x8000_0000_0000_8009,
+    0x0000_0000_0000_008a,
+    0x0000_0000_0000_0088,
+    0x0000_0000_8000_8009,
+    0x0000_0000_8000_000a,
+    0x0000_0000_8000_808b,
+    0x8000_0000_0000_008b,
+    0x8000_0000_0000_8089,
+    0x8000_0000_0000_8003,
+    0x8000_0000_0000_8002,
+    0x8000_0000_0000_0080,
+    0x0000_0000_0000_800a,
+    0x8000_0000_8000_000a,
+    0x8000_0000_8000_8081,
+    0x8000_0000_0000_8080,
+    0x0000_0000_8000_0001,
+    0x8000_0000_8000_8008,
+];
+
+/// Rotation offsets for the rho step, indexed by `x + 5 y`
+const RHO: [i32; 25] = [
+    0, 1, 62, 28, 27, //
+    36, 44, 6, 55, 20, //
+    3, 10, 43, 25, 39, //
+    41, 45, 15, 21, 8, //
+    18, 2, 61, 56, 14, //
+];
+
+/// Permute all four states, if the CPU supports AVX2.  Returns `false` if it does not.
+pub(super) fn f1600x4(state: &mut State) -> bool {
+    if !avx2_cpuid::get() {
+        return false;
+    }
+
+    // SAFETY: AVX2 support was checked above
+    unsafe { f1600x4_avx2(state) };
+    true
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn rotl(x: __m256i, n: i32) -> __m256i {
+    _mm256_or_si256(
+        _mm256_sll_epi64(x, _mm_cvtsi32_si128(n)),
+        _mm256_srl_epi64(x, _mm_cvtsi32_si128(64 - n)),
+    )
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn xor(a: __m256i, b: __m256i) -> __m256i {
+    _mm256_xor_si256(a, b)
+}
+
+// The unaligned load and store instructions have no alignment requirement
+#[allow(clippy::cast_ptr_alignment, clippy::cast_possible_wrap)]
+#[target_feature(enable = "avx2")]
+unsafe fn f1600x4_avx2(state: &mut State) {
+    let mut a: [__m256i; 25] =
+        core::array::from_fn(|i| _mm256_loadu_si256(state[i].as_ptr().cast()));
+
+    for rc in RC {
+        // theta
+        let c: [__m256i; 5] = core::array::from_fn(|x| {
+            xor(
+                xor(xor(a[x], a[x + 5]), xor(a[x + 10], a[x + 15])),
+                a[x + 20],
+            )
+        });
+        for x in 0..5 {
+            let d = xor(c[(x + 4) % 5], rotl(c[(x + 1) % 5], 1));
+            for y in 0..5 {
+                a[x + 5 * y] = xor(a[x + 5 * y], d);
+            }
+        }
+
+        // rho and pi
+        let mut b = [_mm256_setzero_si256(); 25];
+        for x in 0..5 {
+            for y in 0..5 {
+                b[y + 5 * ((2 * x + 3 * y) % 5)] = rotl(a[x + 5 * y], RHO[x + 5 * y]);
+            }
+        }
+
+        // chi
+        for y in 0..5 {
+            for x in 0..5 {
+                a[x + 5 * y] = xor(
+                    b[x + 5 * y],
+                    _mm256_andnot_si256(b[(x + 1) % 5 + 5 * y], b[(x + 2) % 5 + 5 * y]),
+                );
+            }
+        }
+
+        // iota
+        a[0] = xor(a[0], _mm256_set1_epi64x(rc as i64));
+    }
+
+    for (word, x) in state.iter_mut().zip(&a) {
+        _mm256_storeu_si256(word.as_mut_ptr().cast(), *x);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::keccak::LANES;
+    use rand::RngCore;
+
+    #[test]
+    fn bit_exact() {
+        if !avx2_cpuid::get() {
+            return;
+        }
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let mut state: State = [[0; LANES]; 25];
+            for word in state.iter_mut().flatten() {
+                *word = rng.next_u64();
+            }
+
+            let mut actual = state;
+            assert!(f1600x4(&mut actual));
+
+            for lane in 0..LANES {
+                let mut expected: [u64; 25] = core::array::from_fn(|i| state[i][lane]);
+                keccak::f1600(&mut expected);
+
+                for (word, x) in actual.iter().zip(&expected) {
+                    assert_eq!(word[lane], *x);
+                }
+            }
+        }
+    }
+}
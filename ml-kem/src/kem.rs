@@ -1,26 +1,85 @@
 use core::marker::PhantomData;
 use hybrid_array::typenum::U32;
 use rand_core::CryptoRngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 
-use crate::crypto::{rand, G, H, J};
+use crate::algebra::{MatrixRows, NttMatrix};
+use crate::crypto::{rand, try_rand, G, H, J};
 use crate::param::{DecapsulationKeySize, EncapsulationKeySize, EncodedCiphertext, KemParams};
 use crate::pke::{DecryptionKey, EncryptionKey};
-use crate::util::B32;
-use crate::{Encoded, EncodedSizeUser};
+use crate::util::{wipe, B32};
+use crate::{
+    encoded_from_slice, Ciphertext, DecapsulateInto, EncapsulateInto, Encoded, EncodedSizeUser,
+    Error, Seed,
+};
+
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /// A shared key resulting from an ML-KEM transaction
 pub(crate) type SharedKey = B32;
 
 /// A `DecapsulationKey` provides the ability to generate a new key pair, and decapsulate an
 /// encapsulated shared key.
-#[derive(Clone, Debug, PartialEq)]
+///
+/// A decapsulation key that was generated or expanded from a seed remembers the `d` half of the
+/// seed, so that it can be exported again in seed form with [`DecapsulationKey::to_seed`].  Keys
+/// parsed from the expanded encoding do not have a seed.
+#[derive(Clone, Debug)]
 pub struct DecapsulationKey<P>
 where
     P: KemParams,
 {
-    dk_pke: DecryptionKey<P>,
-    ek: EncapsulationKey<P>,
-    z: B32,
+    pub(crate) dk_pke: DecryptionKey<P>,
+    pub(crate) ek: EncapsulationKey<P>,
+    pub(crate) d: Option<B32>,
+    pub(crate) z: B32,
+}
+
+#[cfg(feature = "zeroize")]
+impl<P> Zeroize for DecapsulationKey<P>
+where
+    P: KemParams,
+{
+    fn zeroize(&mut self) {
+        self.dk_pke.zeroize();
+        self.d.zeroize();
+        self.z.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<P> Drop for DecapsulationKey<P>
+where
+    P: KemParams,
+{
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<P> ZeroizeOnDrop for DecapsulationKey<P> where P: KemParams {}
+
+// Two keys are equal if they behave the same, whether or not they remember their seed.
+impl<P> ConstantTimeEq for DecapsulationKey<P>
+where
+    P: KemParams,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.dk_pke.ct_eq(&other.dk_pke)
+            & self.ek.ct_eq(&other.ek)
+            & self.z.as_slice().ct_eq(other.z.as_slice())
+    }
+}
+
+impl<P> PartialEq for DecapsulationKey<P>
+where
+    P: KemParams,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
 }
 
 impl<P> EncodedSizeUser for DecapsulationKey<P>
@@ -34,8 +93,8 @@ where
         let (dk_pke, ek_pke, h, z) = P::split_dk(enc);
         let ek_pke = EncryptionKey::from_bytes(ek_pke);
 
-        // XXX(RLB): The encoding here is redundant, since `h` can be computed from `ek_pke`.
-        // Should we verify that the provided `h` value is valid?
+        // The encoding here is redundant, since `h` can be computed from `ek_pke`.  We trust the
+        // provided value here; `try_from_bytes` verifies it.
 
         Self {
             dk_pke: DecryptionKey::from_bytes(dk_pke),
@@ -43,38 +102,142 @@ where
                 ek_pke,
                 h: h.clone(),
             },
+            d: None,
             z: z.clone(),
         }
     }
 
+    #[allow(clippy::similar_names)] // allow dk_pke, ek_pke, following the spec
+    fn try_from_bytes(enc: &Encoded<Self>) -> Result<Self, Error> {
+        let (dk_pke, ek_pke, h, z) = P::split_dk(enc);
+
+        // Both K-PKE keys must be canonically encoded.  The encapsulation key embedded in the
+        // decapsulation key is subject to the same modulus check as a standalone one.
+        let dk_pke = DecryptionKey::try_from_bytes(dk_pke)?;
+        let ek_pke = EncryptionKey::try_from_bytes(ek_pke)?;
+
+        // FIPS 203 decapsulation key check: The embedded hash must match the embedded key
+        let ek = EncapsulationKey::new(ek_pke);
+        if ek.h != *h {
+            return Err(Error::DecapsulationKeyHashMismatch);
+        }
+
+        Ok(Self {
+            dk_pke,
+            ek,
+            d: None,
+            z: z.clone(),
+        })
+    }
+
     fn as_bytes(&self) -> Encoded<Self> {
-        let dk_pke = self.dk_pke.as_bytes();
-        let ek = self.ek.as_bytes();
-        P::concat_dk(dk_pke, ek, self.ek.h.clone(), self.z.clone())
+        let mut enc = Encoded::<Self>::default();
+        self.write_bytes(&mut enc);
+        enc
+    }
+
+    #[allow(clippy::similar_names)] // allow dk_pke, ek_pke, following the spec
+    fn write_bytes(&self, enc: &mut Encoded<Self>) {
+        let (dk_pke, ek_pke, h, z) = P::split_dk_mut(enc);
+        self.dk_pke.write_bytes(dk_pke);
+        self.ek.ek_pke.write_bytes(ek_pke);
+        h.copy_from_slice(&self.ek.h);
+        z.copy_from_slice(&self.z);
     }
 }
 
-// 0xff if x == y, 0x00 otherwise
-fn constant_time_eq(x: u8, y: u8) -> u8 {
-    let diff = x ^ y;
-    let is_zero = !diff & diff.wrapping_sub(1);
-    0u8.wrapping_sub(is_zero >> 7)
+#[cfg(feature = "serde")]
+impl<P> serdect::serde::Serialize for DecapsulationKey<P>
+where
+    P: KemParams,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serdect::serde::Serializer,
+    {
+        crate::serialize_encoded(self, serializer)
+    }
 }
 
-impl<P> ::kem::Decapsulate<EncodedCiphertext<P>, SharedKey> for DecapsulationKey<P>
+#[cfg(feature = "serde")]
+impl<'de, P> serdect::serde::Deserialize<'de> for DecapsulationKey<P>
 where
     P: KemParams,
 {
-    // Decapsulation is infallible
-    // XXX(RLB): Maybe we should reflect decryption failure as an error?
-    // TODO(RLB) Make Infallible
-    type Error = ();
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serdect::serde::Deserializer<'de>,
+    {
+        crate::deserialize_encoded(deserializer)
+    }
+}
 
-    fn decapsulate(&self, encapsulated_key: &EncodedCiphertext<P>) -> Result<SharedKey, ()> {
-        let mp = self.dk_pke.decrypt(encapsulated_key);
-        let (Kp, rp) = G(&[&mp, &self.ek.h]);
-        let Kbar = J(&[self.z.as_slice(), encapsulated_key.as_ref()]);
-        let cp = self.ek.ek_pke.encrypt(&mp, &rp);
+impl<P> TryFrom<&[u8]> for DecapsulationKey<P>
+where
+    P: KemParams,
+{
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        Self::try_from_bytes(encoded_from_slice::<Self>(bytes)?)
+    }
+}
+
+impl<P> ::kem::Decapsulate<Ciphertext<Kem<P>>, SharedKey> for DecapsulationKey<P>
+where
+    P: KemParams,
+{
+    // Decapsulation only fails in the self-test error state.  Decryption failures are handled by
+    // implicit rejection, which yields a pseudorandom shared key rather than an error.
+    type Error = Error;
+
+    fn decapsulate(&self, encapsulated_key: &Ciphertext<Kem<P>>) -> Result<SharedKey, Error> {
+        let mut K = SharedKey::default();
+        self.decapsulate_into(&encapsulated_key.0, &mut K)?;
+        Ok(K)
+    }
+}
+
+impl<P> DecapsulateInto<Kem<P>> for DecapsulationKey<P>
+where
+    P: KemParams,
+{
+    fn decapsulate_into(
+        &self,
+        ciphertext: &EncodedCiphertext<P>,
+        shared_key: &mut SharedKey,
+    ) -> Result<(), Error> {
+        #[cfg(feature = "self-test")]
+        crate::self_test::check()?;
+
+        self.decapsulate_unchecked(ciphertext, shared_key);
+        Ok(())
+    }
+}
+
+impl<P> DecapsulationKey<P>
+where
+    P: KemParams,
+{
+    /// Decapsulate without checking the module state, for the self-tests and the pairwise
+    /// consistency test, which run on behalf of a service that has already checked it
+    pub(crate) fn decapsulate_unchecked(&self, c: &EncodedCiphertext<P>, K: &mut SharedKey) {
+        self.ek
+            .ek_pke
+            .with_matrix_rows(|A_hat_t| self.decapsulate_with_rows(A_hat_t, c, K));
+    }
+
+    fn decapsulate_with_rows(
+        &self,
+        A_hat_t: &MatrixRows<'_, P::K>,
+        c: &EncodedCiphertext<P>,
+        K: &mut SharedKey,
+    ) {
+        let mut mp = self.dk_pke.decrypt(c);
+        let (mut Kp, mut rp) = G(&[&mp, &self.ek.h]);
+        let mut Kbar = J(&[self.z.as_slice(), c.as_slice()]);
+        let mut cp = EncodedCiphertext::<P>::default();
+        self.ek.ek_pke.encrypt_with_rows(A_hat_t, &mp, &rp, &mut cp);
 
         // Constant-time version of:
         //
@@ -83,16 +246,12 @@ where
         // } else {
         //     Kbar
         // }
-        let equal = cp
-            .iter()
-            .zip(encapsulated_key.iter())
-            .map(|(&x, &y)| constant_time_eq(x, y))
-            .fold(0xff, |x, y| x & y);
-        Ok(Kp
-            .iter()
-            .zip(Kbar.iter())
-            .map(|(x, y)| (equal & x) | (!equal & y))
-            .collect())
+        let equal = cp.as_slice().ct_eq(c.as_slice());
+        for (K, (Kbar, Kp)) in K.iter_mut().zip(Kbar.iter().zip(Kp.iter())) {
+            *K = u8::conditional_select(Kbar, Kp, equal);
+        }
+
+        wipe!(mp, Kp, rp, Kbar, cp);
     }
 }
 
@@ -101,49 +260,172 @@ where
     P: KemParams,
 {
     pub(crate) fn generate(rng: &mut impl CryptoRngCore) -> Self {
-        let d: B32 = rand(rng);
-        let z: B32 = rand(rng);
-        Self::generate_deterministic(&d, &z)
+        #[cfg(feature = "self-test")]
+        crate::self_test::assert_operational();
+
+        let mut d: B32 = rand(rng);
+        let mut z: B32 = rand(rng);
+        let dk = Self::generate_deterministic(&d, &z);
+        wipe!(d, z);
+
+        #[cfg(feature = "pct")]
+        if let Err(err) = dk.pairwise_consistency_test(rng) {
+            panic!("key generation failed: {err}");
+        }
+
+        dk
+    }
+
+    pub(crate) fn try_generate(rng: &mut impl CryptoRngCore) -> Result<Self, Error> {
+        #[cfg(feature = "self-test")]
+        crate::self_test::check()?;
+
+        let mut d: B32 = try_rand(rng)?;
+        let mut z: B32 = try_rand(rng)?;
+        let dk = Self::generate_deterministic(&d, &z);
+        wipe!(d, z);
+
+        #[cfg(feature = "pct")]
+        dk.pairwise_consistency_test(rng)?;
+
+        Ok(dk)
+    }
+
+    /// FIPS 140-3 IG 10.3.A pairwise consistency test: Encapsulate to the encapsulation key of a
+    /// fresh key pair, and check that decapsulation recovers the same shared key.
+    #[cfg(feature = "pct")]
+    fn pairwise_consistency_test(&self, rng: &mut impl CryptoRngCore) -> Result<(), Error> {
+        let mut m: B32 = try_rand(rng)?;
+        let mut c = EncodedCiphertext::<P>::default();
+        let mut K = SharedKey::default();
+        self.ek.encapsulate_deterministic_inner(&m, &mut c, &mut K);
+        let mut Kp = SharedKey::default();
+        self.decapsulate_unchecked(&c, &mut Kp);
+        let consistent: bool = K.as_slice().ct_eq(Kp.as_slice()).into();
+
+        wipe!(m, K, Kp);
+        if consistent {
+            Ok(())
+        } else {
+            Err(Error::PairwiseConsistency)
+        }
     }
 
     pub(crate) fn encapsulation_key(&self) -> &EncapsulationKey<P> {
         &self.ek
     }
 
+    #[cfg(all(feature = "pkcs8", feature = "alloc"))]
+    pub(crate) fn has_seed(&self) -> bool {
+        self.d.is_some()
+    }
+
+    /// Expand this key into a [`PrecomputedDecapsulationKey`], which samples the matrix
+    /// `A_hat^T` used for re-encryption once instead of on every decapsulation.
+    #[must_use]
+    pub fn precompute(&self) -> PrecomputedDecapsulationKey<P> {
+        PrecomputedDecapsulationKey::from(self.clone())
+    }
+
+    /// Expand a decapsulation key from a 64-byte seed `(d || z)`, as in `ML-KEM.KeyGen_internal`.
+    /// No pairwise consistency test is run, even with the `pct` feature.
+    ///
+    /// # Panics
+    ///
+    /// With the `self-test` feature, panics if the module is in the error state.
+    #[must_use]
+    pub fn from_seed(seed: &Seed) -> Self {
+        #[cfg(feature = "self-test")]
+        crate::self_test::assert_operational();
+
+        let (d, z) = seed.split_ref::<U32>();
+        Self::generate_deterministic(d, z)
+    }
+
+    /// Export the 64-byte seed `(d || z)` from which this key was expanded.  Returns `None` if
+    /// the key was parsed from its expanded encoding, since the seed cannot be recovered from it.
+    #[must_use]
+    pub fn to_seed(&self) -> Option<Seed> {
+        self.d.clone().map(|d| d.concat::<U32>(self.z.clone()))
+    }
+
     #[must_use]
     #[allow(clippy::similar_names)] // allow dk_pke, ek_pke, following the spec
     pub(crate) fn generate_deterministic(d: &B32, z: &B32) -> Self {
         let (dk_pke, ek_pke) = DecryptionKey::generate(d);
         let ek = EncapsulationKey::new(ek_pke);
+        let d = Some(d.clone());
         let z = z.clone();
-        Self { dk_pke, ek, z }
+        Self { dk_pke, ek, d, z }
     }
 }
 
 /// An `EncapsulationKey` provides the ability to encapsulate a shared key so that it can only be
 /// decapsulated by the holder of the corresponding decapsulation key.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct EncapsulationKey<P>
 where
     P: KemParams,
 {
-    ek_pke: EncryptionKey<P>,
-    h: B32,
+    pub(crate) ek_pke: EncryptionKey<P>,
+    pub(crate) h: B32,
+}
+
+impl<P> ConstantTimeEq for EncapsulationKey<P>
+where
+    P: KemParams,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.ek_pke.ct_eq(&other.ek_pke) & self.h.as_slice().ct_eq(other.h.as_slice())
+    }
+}
+
+impl<P> PartialEq for EncapsulationKey<P>
+where
+    P: KemParams,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
 }
 
 impl<P> EncapsulationKey<P>
 where
     P: KemParams,
 {
-    fn new(ek_pke: EncryptionKey<P>) -> Self {
+    pub(crate) fn new(ek_pke: EncryptionKey<P>) -> Self {
         let h = H(ek_pke.as_bytes());
         Self { ek_pke, h }
     }
 
-    fn encapsulate_deterministic_inner(&self, m: &B32) -> (EncodedCiphertext<P>, SharedKey) {
-        let (K, r) = G(&[m, &self.h]);
-        let c = self.ek_pke.encrypt(m, &r);
-        (c, K)
+    pub(crate) fn encapsulate_deterministic_inner(
+        &self,
+        m: &B32,
+        c: &mut EncodedCiphertext<P>,
+        K: &mut SharedKey,
+    ) {
+        self.ek_pke
+            .with_matrix_rows(|A_hat_t| self.encapsulate_with_rows(A_hat_t, m, c, K));
+    }
+
+    fn encapsulate_with_rows(
+        &self,
+        A_hat_t: &MatrixRows<'_, P::K>,
+        m: &B32,
+        c: &mut EncodedCiphertext<P>,
+        K: &mut SharedKey,
+    ) {
+        let mut r;
+        (*K, r) = G(&[m, &self.h]);
+        self.ek_pke.encrypt_with_rows(A_hat_t, m, &r, c);
+        wipe!(r);
+    }
+
+    /// Expand this key into a [`PrecomputedEncapsulationKey`], which samples the matrix
+    /// `A_hat^T` once instead of on every encapsulation.
+    #[must_use]
+    pub fn precompute(&self) -> PrecomputedEncapsulationKey<P> {
+        PrecomputedEncapsulationKey::from(self.clone())
     }
 }
 
@@ -157,41 +439,293 @@ where
         Self::new(EncryptionKey::from_bytes(enc))
     }
 
+    fn try_from_bytes(enc: &Encoded<Self>) -> Result<Self, Error> {
+        Ok(Self::new(EncryptionKey::try_from_bytes(enc)?))
+    }
+
     fn as_bytes(&self) -> Encoded<Self> {
         self.ek_pke.as_bytes()
     }
+
+    fn write_bytes(&self, enc: &mut Encoded<Self>) {
+        self.ek_pke.write_bytes(enc);
+    }
 }
 
-impl<P> ::kem::Encapsulate<EncodedCiphertext<P>, SharedKey> for EncapsulationKey<P>
+#[cfg(feature = "serde")]
+impl<P> serdect::serde::Serialize for EncapsulationKey<P>
 where
     P: KemParams,
 {
-    // TODO(RLB) Make Infallible
-    // TODO(RLB) Swap the order of the
-    type Error = ();
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serdect::serde::Serializer,
+    {
+        crate::serialize_encoded(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, P> serdect::serde::Deserialize<'de> for EncapsulationKey<P>
+where
+    P: KemParams,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serdect::serde::Deserializer<'de>,
+    {
+        crate::deserialize_encoded(deserializer)
+    }
+}
+
+impl<P> TryFrom<&[u8]> for EncapsulationKey<P>
+where
+    P: KemParams,
+{
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        Self::try_from_bytes(encoded_from_slice::<Self>(bytes)?)
+    }
+}
+
+impl<P> ::kem::Encapsulate<Ciphertext<Kem<P>>, SharedKey> for EncapsulationKey<P>
+where
+    P: KemParams,
+{
+    // The only way encapsulation can fail is if the RNG does
+    type Error = Error;
 
     fn encapsulate(
         &self,
         rng: &mut impl CryptoRngCore,
-    ) -> Result<(EncodedCiphertext<P>, SharedKey), Self::Error> {
-        let m: B32 = rand(rng);
-        Ok(self.encapsulate_deterministic_inner(&m))
+    ) -> Result<(Ciphertext<Kem<P>>, SharedKey), Self::Error> {
+        let mut c = EncodedCiphertext::<P>::default();
+        let mut K = SharedKey::default();
+        self.encapsulate_into(rng, &mut c, &mut K)?;
+        Ok((c.into(), K))
+    }
+}
+
+impl<P> EncapsulateInto<Kem<P>> for EncapsulationKey<P>
+where
+    P: KemParams,
+{
+    fn encapsulate_into(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        ciphertext: &mut EncodedCiphertext<P>,
+        shared_key: &mut SharedKey,
+    ) -> Result<(), Error> {
+        #[cfg(feature = "self-test")]
+        crate::self_test::check()?;
+
+        let mut m: B32 = try_rand(rng)?;
+        self.encapsulate_deterministic_inner(&m, ciphertext, shared_key);
+        wipe!(m);
+        Ok(())
     }
 }
 
 #[cfg(feature = "deterministic")]
-impl<P> crate::EncapsulateDeterministic<EncodedCiphertext<P>, SharedKey> for EncapsulationKey<P>
+impl<P> crate::EncapsulateDeterministic<Ciphertext<Kem<P>>, SharedKey> for EncapsulationKey<P>
 where
     P: KemParams,
 {
-    // TODO(RLB) Make Infallible
-    type Error = ();
+    type Error = Error;
 
     fn encapsulate_deterministic(
         &self,
         m: &B32,
-    ) -> Result<(EncodedCiphertext<P>, SharedKey), Self::Error> {
-        Ok(self.encapsulate_deterministic_inner(m))
+    ) -> Result<(Ciphertext<Kem<P>>, SharedKey), Self::Error> {
+        #[cfg(feature = "self-test")]
+        crate::self_test::check()?;
+
+        let mut c = EncodedCiphertext::<P>::default();
+        let mut K = SharedKey::default();
+        self.encapsulate_deterministic_inner(m, &mut c, &mut K);
+        Ok((c.into(), K))
+    }
+}
+
+/// A `DecapsulationKey` that keeps the matrix `A_hat^T` in memory, for repeated decapsulation with
+/// the same static key.  Decapsulation gives the same results as with the plain key, but the
+/// re-encryption step skips re-sampling the matrix from `rho`.
+// The implicit rejection key `J(z || c)` is computed from scratch.  `z` is shorter than the
+// SHAKE256 rate, so an absorbed `z` state would save no permutations, and it would leave a copy
+// of `z` in a hasher that cannot be zeroized.
+#[derive(Clone, Debug)]
+pub struct PrecomputedDecapsulationKey<P>
+where
+    P: KemParams,
+{
+    dk: DecapsulationKey<P>,
+    A_hat_t: NttMatrix<P::K>,
+}
+
+impl<P> PrecomputedDecapsulationKey<P>
+where
+    P: KemParams,
+{
+    /// The decapsulation key from which this key was expanded
+    pub fn decapsulation_key(&self) -> &DecapsulationKey<P> {
+        &self.dk
+    }
+
+    /// The encapsulation key corresponding to this key, with the same cached matrix
+    #[must_use]
+    pub fn encapsulation_key(&self) -> PrecomputedEncapsulationKey<P> {
+        PrecomputedEncapsulationKey {
+            ek: self.dk.ek.clone(),
+            A_hat_t: self.A_hat_t.clone(),
+        }
+    }
+}
+
+impl<P> From<DecapsulationKey<P>> for PrecomputedDecapsulationKey<P>
+where
+    P: KemParams,
+{
+    fn from(dk: DecapsulationKey<P>) -> Self {
+        Self {
+            A_hat_t: dk.ek.ek_pke.matrix(),
+            dk,
+        }
+    }
+}
+
+impl<P> ::kem::Decapsulate<Ciphertext<Kem<P>>, SharedKey> for PrecomputedDecapsulationKey<P>
+where
+    P: KemParams,
+{
+    type Error = Error;
+
+    fn decapsulate(&self, encapsulated_key: &Ciphertext<Kem<P>>) -> Result<SharedKey, Error> {
+        let mut K = SharedKey::default();
+        self.decapsulate_into(&encapsulated_key.0, &mut K)?;
+        Ok(K)
+    }
+}
+
+impl<P> DecapsulateInto<Kem<P>> for PrecomputedDecapsulationKey<P>
+where
+    P: KemParams,
+{
+    fn decapsulate_into(
+        &self,
+        ciphertext: &EncodedCiphertext<P>,
+        shared_key: &mut SharedKey,
+    ) -> Result<(), Error> {
+        #[cfg(feature = "self-test")]
+        crate::self_test::check()?;
+
+        self.dk
+            .decapsulate_with_rows(&MatrixRows::Sampled(&self.A_hat_t), ciphertext, shared_key);
+        Ok(())
+    }
+}
+
+/// An `EncapsulationKey` that keeps the matrix `A_hat^T` in memory, for repeated encapsulation to
+/// the same long-lived key.  Encapsulation gives the same results as with the plain key, but
+/// skips re-sampling the matrix from `rho`, at the cost of `k^2` polynomials of storage (8 KiB
+/// for ML-KEM-1024).
+#[derive(Clone, Debug)]
+pub struct PrecomputedEncapsulationKey<P>
+where
+    P: KemParams,
+{
+    ek: EncapsulationKey<P>,
+    A_hat_t: NttMatrix<P::K>,
+}
+
+impl<P> PrecomputedEncapsulationKey<P>
+where
+    P: KemParams,
+{
+    /// The encapsulation key from which this key was expanded
+    pub fn encapsulation_key(&self) -> &EncapsulationKey<P> {
+        &self.ek
+    }
+
+    fn encapsulate_deterministic_inner(
+        &self,
+        m: &B32,
+        c: &mut EncodedCiphertext<P>,
+        K: &mut SharedKey,
+    ) {
+        self.ek
+            .encapsulate_with_rows(&MatrixRows::Sampled(&self.A_hat_t), m, c, K);
+    }
+}
+
+impl<P> From<EncapsulationKey<P>> for PrecomputedEncapsulationKey<P>
+where
+    P: KemParams,
+{
+    fn from(ek: EncapsulationKey<P>) -> Self {
+        Self {
+            A_hat_t: ek.ek_pke.matrix(),
+            ek,
+        }
+    }
+}
+
+impl<P> ::kem::Encapsulate<Ciphertext<Kem<P>>, SharedKey> for PrecomputedEncapsulationKey<P>
+where
+    P: KemParams,
+{
+    type Error = Error;
+
+    fn encapsulate(
+        &self,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<(Ciphertext<Kem<P>>, SharedKey), Self::Error> {
+        let mut c = EncodedCiphertext::<P>::default();
+        let mut K = SharedKey::default();
+        self.encapsulate_into(rng, &mut c, &mut K)?;
+        Ok((c.into(), K))
+    }
+}
+
+impl<P> EncapsulateInto<Kem<P>> for PrecomputedEncapsulationKey<P>
+where
+    P: KemParams,
+{
+    fn encapsulate_into(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        ciphertext: &mut EncodedCiphertext<P>,
+        shared_key: &mut SharedKey,
+    ) -> Result<(), Error> {
+        #[cfg(feature = "self-test")]
+        crate::self_test::check()?;
+
+        let mut m: B32 = try_rand(rng)?;
+        self.encapsulate_deterministic_inner(&m, ciphertext, shared_key);
+        wipe!(m);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "deterministic")]
+impl<P> crate::EncapsulateDeterministic<Ciphertext<Kem<P>>, SharedKey>
+    for PrecomputedEncapsulationKey<P>
+where
+    P: KemParams,
+{
+    type Error = Error;
+
+    fn encapsulate_deterministic(
+        &self,
+        m: &B32,
+    ) -> Result<(Ciphertext<Kem<P>>, SharedKey), Self::Error> {
+        #[cfg(feature = "self-test")]
+        crate::self_test::check()?;
+
+        let mut c = EncodedCiphertext::<P>::default();
+        let mut K = SharedKey::default();
+        self.encapsulate_deterministic_inner(m, &mut c, &mut K);
+        Ok((c.into(), K))
     }
 }
 
@@ -213,6 +747,10 @@ where
     type DecapsulationKey = DecapsulationKey<P>;
     type EncapsulationKey = EncapsulationKey<P>;
 
+    // Only the final standard is approved, not the initial public draft
+    #[cfg(feature = "self-test")]
+    const APPROVED: bool = !P::IPD;
+
     /// Generate a new (decapsulation, encapsulation) key pair
     fn generate(rng: &mut impl CryptoRngCore) -> (Self::DecapsulationKey, Self::EncapsulationKey) {
         let dk = Self::DecapsulationKey::generate(rng);
@@ -220,22 +758,62 @@ where
         (dk, ek)
     }
 
+    fn try_generate(
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<(Self::DecapsulationKey, Self::EncapsulationKey), Error> {
+        let dk = Self::DecapsulationKey::try_generate(rng)?;
+        let ek = dk.encapsulation_key().clone();
+        Ok((dk, ek))
+    }
+
+    // The encapsulation key is encoded from the one inside the decapsulation key, without a copy
+    fn generate_into(
+        rng: &mut impl CryptoRngCore,
+        dk: &mut Encoded<Self::DecapsulationKey>,
+        ek: &mut Encoded<Self::EncapsulationKey>,
+    ) {
+        let dk_new = Self::DecapsulationKey::generate(rng);
+        dk_new.write_bytes(dk);
+        dk_new.ek.write_bytes(ek);
+    }
+
+    fn try_generate_into(
+        rng: &mut impl CryptoRngCore,
+        dk: &mut Encoded<Self::DecapsulationKey>,
+        ek: &mut Encoded<Self::EncapsulationKey>,
+    ) -> Result<(), Error> {
+        let dk_new = Self::DecapsulationKey::try_generate(rng)?;
+        dk_new.write_bytes(dk);
+        dk_new.ek.write_bytes(ek);
+        Ok(())
+    }
+
     #[cfg(feature = "deterministic")]
     fn generate_deterministic(
         d: &B32,
         z: &B32,
     ) -> (Self::DecapsulationKey, Self::EncapsulationKey) {
+        #[cfg(feature = "self-test")]
+        crate::self_test::assert_operational();
+
         let dk = Self::DecapsulationKey::generate_deterministic(d, z);
         let ek = dk.encapsulation_key().clone();
         (dk, ek)
     }
+
+    fn from_seed(seed: &Seed) -> (Self::DecapsulationKey, Self::EncapsulationKey) {
+        let dk = Self::DecapsulationKey::from_seed(seed);
+        let ek = dk.encapsulation_key().clone();
+        (dk, ek)
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{MlKem1024Params, MlKem512Params, MlKem768Params};
+    use crate::{KemCore, MlKem1024Params, MlKem512Params, MlKem768Params};
     use ::kem::{Decapsulate, Encapsulate};
+    use ::rand::{rngs::StdRng, SeedableRng};
 
     fn round_trip_test<P>()
     where
@@ -281,4 +859,376 @@ mod test {
         codec_test::<MlKem768Params>();
         codec_test::<MlKem1024Params>();
     }
+
+    fn seed_test<P>()
+    where
+        P: KemParams,
+    {
+        let mut rng = rand::thread_rng();
+        let seed: Seed = rand(&mut rng);
+
+        // Expanding a seed is the same as deterministic generation from its halves
+        let dk = DecapsulationKey::<P>::from_seed(&seed);
+        let (d, z) = seed.split_ref::<U32>();
+        assert_eq!(dk, DecapsulationKey::<P>::generate_deterministic(d, z));
+
+        // The seed survives a round trip, and randomly generated keys also have one
+        assert_eq!(dk.to_seed(), Some(seed.clone()));
+        let dk_random = DecapsulationKey::<P>::generate(&mut rng);
+        let seed_random = dk_random.to_seed().unwrap();
+        assert_eq!(dk_random, DecapsulationKey::<P>::from_seed(&seed_random));
+
+        // A key parsed from the expanded form has no seed, but is still the same key
+        let dk_decoded = DecapsulationKey::<P>::from_bytes(&dk.as_bytes());
+        assert_eq!(dk_decoded.to_seed(), None);
+        assert_eq!(dk, dk_decoded);
+    }
+
+    #[test]
+    fn seed() {
+        seed_test::<MlKem512Params>();
+        seed_test::<MlKem768Params>();
+        seed_test::<MlKem1024Params>();
+    }
+
+    #[cfg(feature = "pct")]
+    fn pairwise_consistency_test<P>()
+    where
+        P: KemParams,
+    {
+        let mut rng = rand::thread_rng();
+        let dk = DecapsulationKey::<P>::generate(&mut rng);
+        assert_eq!(dk.pairwise_consistency_test(&mut rng), Ok(()));
+
+        // A decryption key that does not match the encapsulation key fails the test
+        let other = DecapsulationKey::<P>::generate(&mut rng);
+        let mismatched = DecapsulationKey {
+            dk_pke: other.dk_pke.clone(),
+            ek: dk.ek.clone(),
+            d: dk.d.clone(),
+            z: dk.z.clone(),
+        };
+        assert_eq!(
+            mismatched.pairwise_consistency_test(&mut rng),
+            Err(Error::PairwiseConsistency)
+        );
+    }
+
+    #[cfg(feature = "pct")]
+    #[test]
+    fn pairwise_consistency() {
+        pairwise_consistency_test::<MlKem512Params>();
+        pairwise_consistency_test::<MlKem768Params>();
+        pairwise_consistency_test::<MlKem1024Params>();
+    }
+
+    fn precompute_test<P>()
+    where
+        P: KemParams,
+    {
+        let mut rng = rand::thread_rng();
+        let dk = DecapsulationKey::<P>::generate(&mut rng);
+        let ek = dk.encapsulation_key();
+        let ek_pre = ek.precompute();
+        assert_eq!(ek_pre.encapsulation_key(), ek);
+
+        // Encapsulation gives byte-identical results with and without the cached matrix
+        let m: B32 = rand(&mut rng);
+        let mut c = EncodedCiphertext::<P>::default();
+        let mut K = SharedKey::default();
+        ek.encapsulate_deterministic_inner(&m, &mut c, &mut K);
+        let mut c_pre = EncodedCiphertext::<P>::default();
+        let mut K_pre = SharedKey::default();
+        ek_pre.encapsulate_deterministic_inner(&m, &mut c_pre, &mut K_pre);
+        assert_eq!(c, c_pre);
+        assert_eq!(K, K_pre);
+
+        let (ct, k_send) = ek_pre.encapsulate(&mut rng).unwrap();
+        assert_eq!(dk.decapsulate(&ct).unwrap(), k_send);
+    }
+
+    fn precompute_decapsulation_test<P>()
+    where
+        P: KemParams,
+    {
+        let mut rng = rand::thread_rng();
+        let dk = DecapsulationKey::<P>::generate(&mut rng);
+        let dk_pre = dk.precompute();
+        assert_eq!(dk_pre.decapsulation_key(), &dk);
+
+        // Valid and invalid ciphertexts decapsulate to the same keys with the cached matrix
+        let (ct, k_send) = dk_pre.encapsulation_key().encapsulate(&mut rng).unwrap();
+        assert_eq!(dk_pre.decapsulate(&ct).unwrap(), k_send);
+
+        let mut bad = ct.as_bytes();
+        bad[0] ^= 0x01;
+        let bad = Ciphertext::from_bytes(&bad);
+        assert_eq!(
+            dk_pre.decapsulate(&bad).unwrap(),
+            dk.decapsulate(&bad).unwrap()
+        );
+    }
+
+    fn into_test<P>()
+    where
+        P: KemParams,
+    {
+        let mut rng = rand::thread_rng();
+        let seed: u64 = ::rand::Rng::gen(&mut rng);
+
+        // Writing to caller-provided buffers gives the same keys as returning them
+        let mut dk_enc = Encoded::<DecapsulationKey<P>>::default();
+        let mut ek_enc = Encoded::<EncapsulationKey<P>>::default();
+        Kem::<P>::generate_into(&mut StdRng::seed_from_u64(seed), &mut dk_enc, &mut ek_enc);
+        let (dk, ek) = Kem::<P>::generate(&mut StdRng::seed_from_u64(seed));
+        assert_eq!(dk_enc, dk.as_bytes());
+        assert_eq!(ek_enc, ek.as_bytes());
+
+        Kem::<P>::try_generate_into(&mut StdRng::seed_from_u64(seed), &mut dk_enc, &mut ek_enc)
+            .unwrap();
+        assert_eq!(dk_enc, dk.as_bytes());
+        assert_eq!(ek_enc, ek.as_bytes());
+
+        let mut dk_bytes = [0u8; 3200];
+        let dk_bytes = &mut dk_bytes[..dk_enc.len()];
+        dk.write_to_slice(dk_bytes).unwrap();
+        assert_eq!(dk_bytes, dk_enc.as_slice());
+
+        // The same goes for ciphertexts and shared keys
+        let (ct, K) = ek.encapsulate(&mut StdRng::seed_from_u64(seed)).unwrap();
+        let mut ct_enc = Encoded::<Ciphertext<Kem<P>>>::default();
+        let mut K_enc = SharedKey::default();
+        ek.encapsulate_into(&mut StdRng::seed_from_u64(seed), &mut ct_enc, &mut K_enc)
+            .unwrap();
+        assert_eq!(ct_enc, ct.as_bytes());
+        assert_eq!(K_enc, K);
+
+        let mut ct_bytes = [0u8; 1568];
+        let ct_bytes = &mut ct_bytes[..ct_enc.len()];
+        let mut K_bytes = [0u8; 32];
+        ek.encapsulate_into_slice(&mut StdRng::seed_from_u64(seed), ct_bytes, &mut K_bytes)
+            .unwrap();
+        assert_eq!(ct_bytes, ct_enc.as_slice());
+        assert_eq!(K_bytes, K.as_slice());
+
+        let mut Kp = SharedKey::default();
+        dk.decapsulate_into(&ct_enc, &mut Kp).unwrap();
+        assert_eq!(Kp, K);
+
+        let mut Kp_bytes = [0u8; 32];
+        dk.decapsulate_into_slice(ct_bytes, &mut Kp_bytes).unwrap();
+        assert_eq!(Kp_bytes, K.as_slice());
+
+        // Buffers of the wrong length are rejected
+        let short = ct_bytes.len() - 1;
+        assert_eq!(
+            ek.encapsulate_into_slice(&mut rng, &mut ct_bytes[..short], &mut K_bytes),
+            Err(Error::InvalidLength {
+                expected: ct_enc.len(),
+                actual: short
+            })
+        );
+        assert_eq!(
+            dk.decapsulate_into_slice(ct_bytes, &mut Kp_bytes[..31]),
+            Err(Error::InvalidLength {
+                expected: 32,
+                actual: 31
+            })
+        );
+        assert_eq!(
+            ek.write_to_slice(&mut dk_bytes[..]),
+            Err(Error::InvalidLength {
+                expected: ek_enc.len(),
+                actual: dk_enc.len()
+            })
+        );
+    }
+
+    #[test]
+    fn into() {
+        into_test::<MlKem512Params>();
+        into_test::<MlKem768Params>();
+        into_test::<MlKem1024Params>();
+    }
+
+    #[test]
+    fn precompute() {
+        precompute_decapsulation_test::<MlKem512Params>();
+        precompute_decapsulation_test::<MlKem768Params>();
+        precompute_decapsulation_test::<MlKem1024Params>();
+        precompute_test::<MlKem512Params>();
+        precompute_test::<MlKem768Params>();
+        precompute_test::<MlKem1024Params>();
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn zeroize() {
+        let mut rng = rand::thread_rng();
+        let mut dk = DecapsulationKey::<MlKem768Params>::generate(&mut rng);
+        dk.zeroize();
+
+        assert_eq!(dk.dk_pke, DecryptionKey::default());
+        assert_eq!(dk.d, None);
+        assert_eq!(dk.z, B32::default());
+    }
+
+    fn modulus_check_test<P>()
+    where
+        P: KemParams,
+    {
+        let mut rng = rand::thread_rng();
+        let dk = DecapsulationKey::<P>::generate(&mut rng);
+        let ek = dk.encapsulation_key().clone();
+
+        // Well-formed keys pass the check
+        let ek_encoded = ek.as_bytes();
+        assert_eq!(
+            EncapsulationKey::try_from_bytes(&ek_encoded),
+            Ok(ek.clone())
+        );
+
+        let dk_encoded = dk.as_bytes();
+        assert_eq!(
+            DecapsulationKey::try_from_bytes(&dk_encoded),
+            Ok(dk.clone())
+        );
+
+        // Setting the first coefficient of t_hat to 2^12 - 1 >= q makes the key invalid
+        let mut ek_encoded = ek_encoded;
+        ek_encoded[0] = 0xff;
+        ek_encoded[1] |= 0x0f;
+        assert_eq!(
+            EncapsulationKey::<P>::try_from_bytes(&ek_encoded),
+            Err(Error::InvalidEncapsulationKey)
+        );
+
+        let dk_pke = dk.dk_pke.as_bytes();
+        let dk_encoded = P::concat_dk(dk_pke, ek_encoded, dk.ek.h.clone(), dk.z.clone());
+        assert_eq!(
+            DecapsulationKey::<P>::try_from_bytes(&dk_encoded),
+            Err(Error::InvalidEncapsulationKey)
+        );
+    }
+
+    fn decapsulation_key_check_test<P>()
+    where
+        P: KemParams,
+    {
+        let mut rng = rand::thread_rng();
+        let dk = DecapsulationKey::<P>::generate(&mut rng);
+        let dk_pke = dk.dk_pke.as_bytes();
+        let ek = dk.ek.as_bytes();
+        let h = dk.ek.h.clone();
+        let z = dk.z.clone();
+
+        // A non-canonical coefficient in s_hat is reported against dk_pke
+        let mut bad_dk_pke = dk_pke.clone();
+        bad_dk_pke[0] = 0xff;
+        bad_dk_pke[1] |= 0x0f;
+        let enc = P::concat_dk(bad_dk_pke, ek.clone(), h.clone(), z.clone());
+        assert_eq!(
+            DecapsulationKey::<P>::try_from_bytes(&enc),
+            Err(Error::InvalidDecryptionKey)
+        );
+
+        // A corrupted hash is reported as a mismatch
+        let mut bad_h = h;
+        bad_h[0] ^= 0x01;
+        let enc = P::concat_dk(dk_pke, ek, bad_h, z);
+        assert_eq!(
+            DecapsulationKey::<P>::try_from_bytes(&enc),
+            Err(Error::DecapsulationKeyHashMismatch)
+        );
+    }
+
+    #[test]
+    fn decapsulation_key_check() {
+        decapsulation_key_check_test::<MlKem512Params>();
+        decapsulation_key_check_test::<MlKem768Params>();
+        decapsulation_key_check_test::<MlKem1024Params>();
+    }
+
+    fn ct_eq_test<P>()
+    where
+        P: KemParams,
+    {
+        let mut rng = rand::thread_rng();
+        let dk = DecapsulationKey::<P>::generate(&mut rng);
+        let ek = dk.encapsulation_key().clone();
+        let (ct, _) = ek.encapsulate(&mut rng).unwrap();
+
+        let dk2 = DecapsulationKey::<P>::generate(&mut rng);
+        let ek2 = dk2.encapsulation_key().clone();
+        let (ct2, _) = ek.encapsulate(&mut rng).unwrap();
+
+        assert!(bool::from(dk.ct_eq(&dk)));
+        assert!(bool::from(ek.ct_eq(&ek)));
+        assert!(bool::from(ct.ct_eq(&ct)));
+        assert!(!bool::from(dk.ct_eq(&dk2)));
+        assert!(!bool::from(ek.ct_eq(&ek2)));
+        assert!(!bool::from(ct.ct_eq(&ct2)));
+
+        // A key that has forgotten its seed is still the same key
+        let dk_bytes = DecapsulationKey::<P>::from_bytes(&dk.as_bytes());
+        assert!(bool::from(dk.ct_eq(&dk_bytes)));
+    }
+
+    struct FailingRng;
+
+    impl rand_core::RngCore for FailingRng {
+        fn next_u32(&mut self) -> u32 {
+            unreachable!("only try_fill_bytes is used")
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            unreachable!("only try_fill_bytes is used")
+        }
+
+        fn fill_bytes(&mut self, _dest: &mut [u8]) {
+            unreachable!("only try_fill_bytes is used")
+        }
+
+        fn try_fill_bytes(&mut self, _dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            let code = core::num::NonZeroU32::new(rand_core::Error::CUSTOM_START).unwrap();
+            Err(code.into())
+        }
+    }
+
+    impl rand_core::CryptoRng for FailingRng {}
+
+    fn rng_failure_test<P>()
+    where
+        P: KemParams,
+    {
+        assert!(matches!(
+            Kem::<P>::try_generate(&mut FailingRng),
+            Err(Error::Rng)
+        ));
+
+        let dk = DecapsulationKey::<P>::generate(&mut rand::thread_rng());
+        let ek = dk.encapsulation_key();
+        assert!(matches!(ek.encapsulate(&mut FailingRng), Err(Error::Rng)));
+    }
+
+    #[test]
+    fn rng_failure() {
+        rng_failure_test::<MlKem512Params>();
+        rng_failure_test::<MlKem768Params>();
+        rng_failure_test::<MlKem1024Params>();
+    }
+
+    #[test]
+    fn ct_eq() {
+        ct_eq_test::<MlKem512Params>();
+        ct_eq_test::<MlKem768Params>();
+        ct_eq_test::<MlKem1024Params>();
+    }
+
+    #[test]
+    fn modulus_check() {
+        modulus_check_test::<MlKem512Params>();
+        modulus_check_test::<MlKem768Params>();
+        modulus_check_test::<MlKem1024Params>();
+    }
 }
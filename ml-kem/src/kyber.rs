@@ -0,0 +1,504 @@
+//! This uses the same K-PKE scheme as ML-KEM, but a different KEM wrapper around it:
+//!
+//! * Key generation derives the K-PKE seeds from `d` without domain separation.
+//! * Encapsulation hashes the random message before use, `m = H(m)`.
+//! * The shared key is derived from both the pre-key and the ciphertext, `K = J(Kbar || H(c))`.
+//!
+//! Kyber is not interoperable with ML-KEM.  It is provided for compatibility with deployments
+//! that predate FIPS 203; new applications should use ML-KEM.
+
+use core::marker::PhantomData;
+use hybrid_array::typenum::U32;
+use rand_core::CryptoRngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+use crate::crypto::{rand, try_rand, G, H, J};
+use crate::kem;
+use crate::param::{DecapsulationKeySize, EncapsulationKeySize, EncodedCiphertext, KemParams};
+use crate::pke::DecryptionKey;
+use crate::util::{wipe, B32};
+use crate::{
+    encoded_from_slice, Ciphertext, DecapsulateInto, EncapsulateInto, Encoded, EncodedSizeUser,
+    Error, Seed,
+};
+
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// A shared key resulting from a Kyber transaction
+pub(crate) type SharedKey = B32;
+
+/// A Kyber `DecapsulationKey`.  The encoding is the same as for ML-KEM.
+#[derive(Clone, Debug)]
+pub struct DecapsulationKey<P>(kem::DecapsulationKey<P>)
+where
+    P: KemParams;
+
+#[cfg(feature = "zeroize")]
+impl<P> Zeroize for DecapsulationKey<P>
+where
+    P: KemParams,
+{
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<P> ZeroizeOnDrop for DecapsulationKey<P> where P: KemParams {}
+
+impl<P> ConstantTimeEq for DecapsulationKey<P>
+where
+    P: KemParams,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl<P> PartialEq for DecapsulationKey<P>
+where
+    P: KemParams,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl<P> EncodedSizeUser for DecapsulationKey<P>
+where
+    P: KemParams,
+{
+    type EncodedSize = DecapsulationKeySize<P>;
+
+    fn from_bytes(enc: &Encoded<Self>) -> Self {
+        Self(kem::DecapsulationKey::from_bytes(enc))
+    }
+
+    fn try_from_bytes(enc: &Encoded<Self>) -> Result<Self, Error> {
+        kem::DecapsulationKey::try_from_bytes(enc).map(Self)
+    }
+
+    fn as_bytes(&self) -> Encoded<Self> {
+        self.0.as_bytes()
+    }
+
+    fn write_bytes(&self, enc: &mut Encoded<Self>) {
+        self.0.write_bytes(enc);
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<P> serdect::serde::Serialize for DecapsulationKey<P>
+where
+    P: KemParams,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serdect::serde::Serializer,
+    {
+        crate::serialize_encoded(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, P> serdect::serde::Deserialize<'de> for DecapsulationKey<P>
+where
+    P: KemParams,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serdect::serde::Deserializer<'de>,
+    {
+        crate::deserialize_encoded(deserializer)
+    }
+}
+
+impl<P> TryFrom<&[u8]> for DecapsulationKey<P>
+where
+    P: KemParams,
+{
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        Self::try_from_bytes(encoded_from_slice::<Self>(bytes)?)
+    }
+}
+
+impl<P> ::kem::Decapsulate<Ciphertext<Kyber<P>>, SharedKey> for DecapsulationKey<P>
+where
+    P: KemParams,
+{
+    // As with ML-KEM, decryption failures are handled by implicit rejection.
+    type Error = Error;
+
+    fn decapsulate(&self, encapsulated_key: &Ciphertext<Kyber<P>>) -> Result<SharedKey, Error> {
+        let mut K = SharedKey::default();
+        self.decapsulate_into(&encapsulated_key.0, &mut K)?;
+        Ok(K)
+    }
+}
+
+impl<P> DecapsulateInto<Kyber<P>> for DecapsulationKey<P>
+where
+    P: KemParams,
+{
+    fn decapsulate_into(&self, c: &EncodedCiphertext<P>, K: &mut SharedKey) -> Result<(), Error> {
+        #[cfg(feature = "self-test")]
+        crate::self_test::check()?;
+
+        let dk = &self.0;
+        let mut mp = dk.dk_pke.decrypt(c);
+        let (mut Kbarp, mut rp) = G(&[&mp, &dk.ek.h]);
+        let mut cp = dk.ek.ek_pke.encrypt(&mp, &rp);
+
+        // On a failed re-encryption, the pre-key is replaced with `z`
+        let equal = cp.as_slice().ct_eq(c.as_slice());
+        let mut Kbar = B32::from_fn(|i| u8::conditional_select(&dk.z[i], &Kbarp[i], equal));
+        *K = J(&[&Kbar, &H(c)]);
+
+        wipe!(mp, Kbarp, rp, cp, Kbar);
+        Ok(())
+    }
+}
+
+impl<P> DecapsulationKey<P>
+where
+    P: KemParams,
+{
+    fn generate(rng: &mut impl CryptoRngCore) -> Self {
+        #[cfg(feature = "self-test")]
+        crate::self_test::assert_operational();
+
+        let mut d: B32 = rand(rng);
+        let mut z: B32 = rand(rng);
+        let dk = Self::generate_deterministic(&d, &z);
+        wipe!(d, z);
+        dk
+    }
+
+    fn try_generate(rng: &mut impl CryptoRngCore) -> Result<Self, Error> {
+        #[cfg(feature = "self-test")]
+        crate::self_test::check()?;
+
+        let mut d: B32 = try_rand(rng)?;
+        let mut z: B32 = try_rand(rng)?;
+        let dk = Self::generate_deterministic(&d, &z);
+        wipe!(d, z);
+        Ok(dk)
+    }
+
+    fn encapsulation_key(&self) -> EncapsulationKey<P> {
+        EncapsulationKey(self.0.ek.clone())
+    }
+
+    /// Expand a decapsulation key from a 64-byte seed `(d || z)`
+    ///
+    /// # Panics
+    ///
+    /// With the `self-test` feature, panics if the module is in the error state.
+    #[must_use]
+    pub fn from_seed(seed: &Seed) -> Self {
+        #[cfg(feature = "self-test")]
+        crate::self_test::assert_operational();
+
+        let (d, z) = seed.split_ref::<U32>();
+        Self::generate_deterministic(d, z)
+    }
+
+    /// Export the 64-byte seed `(d || z)` from which this key was expanded.  Returns `None` if
+    /// the key was parsed from its expanded encoding.
+    #[must_use]
+    pub fn to_seed(&self) -> Option<Seed> {
+        self.0.to_seed()
+    }
+
+    #[allow(clippy::similar_names)] // allow dk_pke, ek_pke, following the spec
+    fn generate_deterministic(d: &B32, z: &B32) -> Self {
+        let (dk_pke, ek_pke) = DecryptionKey::generate_with_domain_separation(d, false);
+        Self(kem::DecapsulationKey {
+            dk_pke,
+            ek: kem::EncapsulationKey::new(ek_pke),
+            d: Some(d.clone()),
+            z: z.clone(),
+        })
+    }
+}
+
+/// A Kyber `EncapsulationKey`.  The encoding is the same as for ML-KEM.
+#[derive(Clone, Debug)]
+pub struct EncapsulationKey<P>(kem::EncapsulationKey<P>)
+where
+    P: KemParams;
+
+impl<P> ConstantTimeEq for EncapsulationKey<P>
+where
+    P: KemParams,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl<P> PartialEq for EncapsulationKey<P>
+where
+    P: KemParams,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl<P> EncapsulationKey<P>
+where
+    P: KemParams,
+{
+    fn encapsulate_deterministic_inner(
+        &self,
+        coins: &B32,
+        c: &mut EncodedCiphertext<P>,
+        K: &mut SharedKey,
+    ) {
+        // Don't release the RNG output directly
+        let mut m = H(coins);
+        let (mut Kbar, mut r) = G(&[&m, &self.0.h]);
+        self.0.ek_pke.encrypt_into(&m, &r, c);
+        *K = J(&[&Kbar, &H(&*c)]);
+        wipe!(m, Kbar, r);
+    }
+}
+
+impl<P> EncodedSizeUser for EncapsulationKey<P>
+where
+    P: KemParams,
+{
+    type EncodedSize = EncapsulationKeySize<P>;
+
+    fn from_bytes(enc: &Encoded<Self>) -> Self {
+        Self(kem::EncapsulationKey::from_bytes(enc))
+    }
+
+    fn try_from_bytes(enc: &Encoded<Self>) -> Result<Self, Error> {
+        kem::EncapsulationKey::try_from_bytes(enc).map(Self)
+    }
+
+    fn as_bytes(&self) -> Encoded<Self> {
+        self.0.as_bytes()
+    }
+
+    fn write_bytes(&self, enc: &mut Encoded<Self>) {
+        self.0.write_bytes(enc);
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<P> serdect::serde::Serialize for EncapsulationKey<P>
+where
+    P: KemParams,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serdect::serde::Serializer,
+    {
+        crate::serialize_encoded(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, P> serdect::serde::Deserialize<'de> for EncapsulationKey<P>
+where
+    P: KemParams,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serdect::serde::Deserializer<'de>,
+    {
+        crate::deserialize_encoded(deserializer)
+    }
+}
+
+impl<P> TryFrom<&[u8]> for EncapsulationKey<P>
+where
+    P: KemParams,
+{
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        Self::try_from_bytes(encoded_from_slice::<Self>(bytes)?)
+    }
+}
+
+impl<P> ::kem::Encapsulate<Ciphertext<Kyber<P>>, SharedKey> for EncapsulationKey<P>
+where
+    P: KemParams,
+{
+    type Error = Error;
+
+    fn encapsulate(
+        &self,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<(Ciphertext<Kyber<P>>, SharedKey), Self::Error> {
+        let mut c = EncodedCiphertext::<P>::default();
+        let mut K = SharedKey::default();
+        self.encapsulate_into(rng, &mut c, &mut K)?;
+        Ok((c.into(), K))
+    }
+}
+
+impl<P> EncapsulateInto<Kyber<P>> for EncapsulationKey<P>
+where
+    P: KemParams,
+{
+    fn encapsulate_into(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        ciphertext: &mut EncodedCiphertext<P>,
+        shared_key: &mut SharedKey,
+    ) -> Result<(), Error> {
+        #[cfg(feature = "self-test")]
+        crate::self_test::check()?;
+
+        let mut coins: B32 = try_rand(rng)?;
+        self.encapsulate_deterministic_inner(&coins, ciphertext, shared_key);
+        wipe!(coins);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "deterministic")]
+impl<P> crate::EncapsulateDeterministic<Ciphertext<Kyber<P>>, SharedKey> for EncapsulationKey<P>
+where
+    P: KemParams,
+{
+    type Error = Error;
+
+    /// Encapsulate using the random coins `m`, which are hashed before use as in the round 3
+    /// reference implementation.
+    fn encapsulate_deterministic(
+        &self,
+        m: &B32,
+    ) -> Result<(Ciphertext<Kyber<P>>, SharedKey), Self::Error> {
+        #[cfg(feature = "self-test")]
+        crate::self_test::check()?;
+
+        let mut c = EncodedCiphertext::<P>::default();
+        let mut K = SharedKey::default();
+        self.encapsulate_deterministic_inner(m, &mut c, &mut K);
+        Ok((c.into(), K))
+    }
+}
+
+/// An implementation of overall Kyber functionality, using the ML-KEM parameter set `P`.
+pub struct Kyber<P>
+where
+    P: KemParams,
+{
+    _phantom: PhantomData<P>,
+}
+
+impl<P> crate::KemCore for Kyber<P>
+where
+    P: KemParams,
+{
+    type SharedKeySize = U32;
+    type CiphertextSize = P::CiphertextSize;
+    type DecapsulationKey = DecapsulationKey<P>;
+    type EncapsulationKey = EncapsulationKey<P>;
+
+    fn generate(rng: &mut impl CryptoRngCore) -> (Self::DecapsulationKey, Self::EncapsulationKey) {
+        let dk = Self::DecapsulationKey::generate(rng);
+        let ek = dk.encapsulation_key();
+        (dk, ek)
+    }
+
+    fn try_generate(
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<(Self::DecapsulationKey, Self::EncapsulationKey), Error> {
+        let dk = Self::DecapsulationKey::try_generate(rng)?;
+        let ek = dk.encapsulation_key();
+        Ok((dk, ek))
+    }
+
+    #[cfg(feature = "deterministic")]
+    fn generate_deterministic(
+        d: &B32,
+        z: &B32,
+    ) -> (Self::DecapsulationKey, Self::EncapsulationKey) {
+        #[cfg(feature = "self-test")]
+        crate::self_test::assert_operational();
+
+        let dk = Self::DecapsulationKey::generate_deterministic(d, z);
+        let ek = dk.encapsulation_key();
+        (dk, ek)
+    }
+
+    fn from_seed(seed: &Seed) -> (Self::DecapsulationKey, Self::EncapsulationKey) {
+        let dk = Self::DecapsulationKey::from_seed(seed);
+        let ek = dk.encapsulation_key();
+        (dk, ek)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Ipd, KemCore, MlKem1024Params, MlKem512Params, MlKem768Params};
+    use ::kem::{Decapsulate, Encapsulate};
+
+    fn round_trip_test<P>()
+    where
+        P: KemParams,
+    {
+        let mut rng = rand::thread_rng();
+
+        let (dk, ek) = Kyber::<P>::generate(&mut rng);
+        let (ct, k_send) = ek.encapsulate(&mut rng).unwrap();
+        let k_recv = dk.decapsulate(&ct).unwrap();
+        assert_eq!(k_send, k_recv);
+
+        let mut k_into = SharedKey::default();
+        dk.decapsulate_into(&ct.as_bytes(), &mut k_into).unwrap();
+        assert_eq!(k_send, k_into);
+
+        // Implicit rejection derives the key from `z` and the ciphertext
+        let mut bad = ct.as_bytes();
+        bad[0] ^= 0x01;
+        let k_reject = dk.decapsulate(&Ciphertext::from_bytes(&bad)).unwrap();
+        assert_eq!(k_reject, J(&[&dk.0.z, &H(&bad)]));
+    }
+
+    #[test]
+    fn round_trip() {
+        round_trip_test::<MlKem512Params>();
+        round_trip_test::<MlKem768Params>();
+        round_trip_test::<MlKem1024Params>();
+    }
+
+    fn not_ml_kem_test<P, Q>()
+    where
+        P: KemParams,
+        Q: KemParams,
+    {
+        let mut rng = rand::thread_rng();
+        let seed: Seed = rand(&mut rng);
+
+        // The same seed gives a different key pair than ML-KEM, but the same as the FIPS 203 draft
+        let (dk, ek) = Kyber::<P>::from_seed(&seed);
+        let (_, ek_mlkem) = kem::Kem::<P>::from_seed(&seed);
+        let (_, ek_ipd) = kem::Kem::<Q>::from_seed(&seed);
+        assert_ne!(ek.as_bytes(), ek_mlkem.as_bytes());
+        assert_eq!(ek.as_bytes().as_slice(), ek_ipd.as_bytes().as_slice());
+        assert_eq!(dk.to_seed(), Some(seed));
+
+        // Keys round-trip through the shared encoding
+        assert_eq!(DecapsulationKey::<P>::from_bytes(&dk.as_bytes()), dk);
+        assert_eq!(EncapsulationKey::<P>::from_bytes(&ek.as_bytes()), ek);
+    }
+
+    #[test]
+    fn not_ml_kem() {
+        not_ml_kem_test::<MlKem512Params, Ipd<MlKem512Params>>();
+        not_ml_kem_test::<MlKem768Params, Ipd<MlKem768Params>>();
+        not_ml_kem_test::<MlKem1024Params, Ipd<MlKem1024Params>>();
+    }
+}
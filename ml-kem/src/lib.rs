@@ -13,7 +13,7 @@
 //! # Usage
 //!
 //! This crate implements the Module-Latice-based Key Encapsulation Method (ML-KEM) algorithm
-//! being standardized by NIST in FIPS 203.  ML-KEM is a KEM in the sense that it creates an
+//! standardized by NIST in FIPS 203.  ML-KEM is a KEM in the sense that it creates an
 //! (decapsulation key, encapsulation key) pair, such that anyone can use the encapsulation key to
 //! establish a shared key with the holder of the decapsulation key.  ML-KEM is the first KEM
 //! algorithm standardized by NIST that is designed to be resistant to attacks using quantum
@@ -29,13 +29,29 @@
 //!
 //! // Encapsulate a shared key to the holder of the decapsulation key, receive the shared
 //! // secret `k_send` and the encapsulated form `ct`.
-//! let (ct, k_send) = ek.encapsulate(&mut rng).unwrap();
+//! let (ct, k_send) = ek.encapsulate(&mut rng)?;
 //!
 //! // Decapsulate the shared key and verify that it was faithfully received.
-//! let k_recv = dk.decapsulate(&ct).unwrap();
+//! let k_recv = dk.decapsulate(&ct)?;
 //! assert_eq!(k_send, k_recv);
+//! # Ok::<(), Error>(())
 //! ```
 //!
+//! The types above implement the final FIPS 203 standard.  Keys generated under the FIPS 203
+//! initial public draft, whose key generation differs from the final standard, can be used with
+//! [`MlKem512Ipd`], [`MlKem768Ipd`], and [`MlKem1024Ipd`].
+//!
+//! Kyber as submitted to round 3 of the NIST PQC process, which is not interoperable with ML-KEM,
+//! is available as [`Kyber512`], [`Kyber768`], and [`Kyber1024`].
+//!
+//! Every operation can also write its output to buffers provided by the caller instead of
+//! returning it: see [`KemCore::generate_into`], [`EncapsulateInto`], [`DecapsulateInto`], and
+//! [`EncodedSizeUser::write_bytes`].
+//!
+//! When the parameter set is only known at runtime, e.g., because it is negotiated by a protocol,
+//! [`MlKemAny`] selects it from a [`ParameterId`] and works with keys and ciphertexts as byte
+//! slices.
+//!
 //! [RFC 9180]: https://www.rfc-editor.org/info/rfc9180
 
 /// The inevitable utility module
@@ -49,6 +65,9 @@ mod algebra;
 /// Section 4.1. Crytographic Functions
 mod crypto;
 
+/// Keccak on four inputs at once, for batched `PRF` and `XOF` calls
+mod keccak;
+
 /// Section 4.2.1. Conversion and Compression Algorithms, Compression and decompression
 mod compress;
 
@@ -61,37 +80,156 @@ mod pke;
 /// Section 6. The ML-KEM Key-Encapsulation Mechanism
 pub mod kem;
 
+/// Kyber round 3, the predecessor of ML-KEM
+pub mod kyber;
+
+/// ML-KEM with the parameter set chosen at runtime
+pub mod any;
+
 /// Section 7. Parameter Sets
 mod param;
 
+/// Errors returned by fallible operations
+mod error;
+
+/// Cryptographic algorithm self-tests and the approved service indicator (FIPS 140-3)
+#[cfg(feature = "self-test")]
+mod self_test;
+
+/// SPKI and PKCS#8 key encodings (draft-ietf-lamps-kyber-certificates)
+#[cfg(feature = "spki")]
+mod asn1;
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use ::kem::{Decapsulate, Encapsulate};
 use core::fmt::Debug;
+use core::marker::PhantomData;
 use hybrid_array::{
-    typenum::{U10, U11, U2, U3, U4, U5},
+    typenum::{Unsigned, U10, U11, U2, U3, U4, U5},
     Array,
 };
 use rand_core::CryptoRngCore;
+use subtle::{Choice, ConstantTimeEq};
 
 #[cfg(feature = "deterministic")]
 pub use util::B32;
 
+pub use any::{MlKemAny, ParameterId};
+pub use error::Error;
+
+#[cfg(feature = "pkcs8")]
+pub use {asn1::PrivateKeyFormat, pkcs8};
+
 pub use param::{ArraySize, ParameterSet};
+#[cfg(feature = "self-test")]
+pub use self_test::{module_state, self_test, service_indicator, ModuleState, ServiceIndicator};
+#[cfg(feature = "spki")]
+pub use spki;
+
+/// The 64-byte seed `(d || z)` from which a decapsulation key can be deterministically expanded.
+/// This is the compact private key format used by the IETF/LAMPS private key encoding and by many
+/// hardware security modules.
+pub type Seed = util::B64;
 
 /// An object that knows what size it is
-pub trait EncodedSizeUser {
+pub trait EncodedSizeUser: Sized {
     /// The size of an encoded object
     type EncodedSize: ArraySize;
 
     /// Parse an object from its encoded form
     fn from_bytes(enc: &Encoded<Self>) -> Self;
 
+    /// Parse an object from its encoded form, performing the input validation checks required
+    /// by FIPS 203 instead of silently reducing non-canonical values.
+    ///
+    /// The default implementation performs no checks beyond the length enforced by the type, and
+    /// accepts every encoding that [`EncodedSizeUser::from_bytes`] does.  Types with invalid
+    /// encodings override it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `enc` is not a valid encoding of this object.
+    fn try_from_bytes(enc: &Encoded<Self>) -> Result<Self, Error> {
+        Ok(Self::from_bytes(enc))
+    }
+
     /// Serialize an object to its encoded form
     fn as_bytes(&self) -> Encoded<Self>;
+
+    /// Serialize an object to its encoded form in `enc`
+    fn write_bytes(&self, enc: &mut Encoded<Self>) {
+        *enc = self.as_bytes();
+    }
+
+    /// Serialize an object to its encoded form in `bytes`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidLength`] if `bytes` is not the length of the encoded form.
+    fn write_to_slice(&self, bytes: &mut [u8]) -> Result<(), Error> {
+        self.write_bytes(array_from_slice_mut(bytes)?);
+        Ok(())
+    }
 }
 
 /// A byte array encoding a value the indicated size
 pub type Encoded<T> = Array<u8, <T as EncodedSizeUser>::EncodedSize>;
 
+/// View a byte slice as the encoded form of a `T`, failing if it is the wrong length
+pub(crate) fn encoded_from_slice<T>(bytes: &[u8]) -> Result<&Encoded<T>, Error>
+where
+    T: EncodedSizeUser,
+{
+    bytes.try_into().map_err(|_| Error::InvalidLength {
+        expected: T::EncodedSize::USIZE,
+        actual: bytes.len(),
+    })
+}
+
+/// View a mutable byte slice as an array, failing if it is the wrong length
+pub(crate) fn array_from_slice_mut<N>(bytes: &mut [u8]) -> Result<&mut Array<u8, N>, Error>
+where
+    N: ArraySize,
+{
+    let actual = bytes.len();
+    bytes.try_into().map_err(|_| Error::InvalidLength {
+        expected: N::USIZE,
+        actual,
+    })
+}
+
+/// Serialize an object as hex in human-readable formats and as raw bytes otherwise
+#[cfg(feature = "serde")]
+pub(crate) fn serialize_encoded<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: EncodedSizeUser,
+    S: serdect::serde::Serializer,
+{
+    let mut enc = value.as_bytes();
+    let res = serdect::array::serialize_hex_lower_or_bin(&enc, serializer);
+    util::wipe!(*enc.as_mut_slice());
+    res
+}
+
+/// Deserialize an object serialized with [`serialize_encoded`], applying the FIPS 203 input
+/// validation checks
+#[cfg(feature = "serde")]
+pub(crate) fn deserialize_encoded<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: EncodedSizeUser,
+    D: serdect::serde::Deserializer<'de>,
+{
+    use serdect::serde::de::Error as _;
+
+    let mut enc = Encoded::<T>::default();
+    serdect::array::deserialize_hex_or_bin(&mut enc, deserializer)?;
+    let res = T::try_from_bytes(&enc).map_err(D::Error::custom);
+    util::wipe!(*enc.as_mut_slice());
+    res
+}
+
 /// A value that can be encapsulated to.  Note that this interface is not safe: In order for the
 /// KEM to be secure, the `m` input must be randomly generated.
 #[cfg(feature = "deterministic")]
@@ -107,6 +245,79 @@ pub trait EncapsulateDeterministic<EK, SS> {
     fn encapsulate_deterministic(&self, m: &B32) -> Result<(EK, SS), Self::Error>;
 }
 
+/// Encapsulation that writes the ciphertext and shared key to buffers provided by the caller,
+/// rather than returning them
+pub trait EncapsulateInto<K>
+where
+    K: KemCore + ?Sized,
+{
+    /// Encapsulate a fresh shared key to this key, writing the ciphertext to `ciphertext` and the
+    /// shared key to `shared_key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Rng`] if the RNG fails to provide randomness.
+    fn encapsulate_into(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        ciphertext: &mut Encoded<Ciphertext<K>>,
+        shared_key: &mut SharedKey<K>,
+    ) -> Result<(), Error>;
+
+    /// Encapsulate as in [`EncapsulateInto::encapsulate_into`], to byte slices.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidLength`] if either slice is not the length of the value written to
+    /// it, or [`Error::Rng`] if the RNG fails to provide randomness.
+    fn encapsulate_into_slice(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        ciphertext: &mut [u8],
+        shared_key: &mut [u8],
+    ) -> Result<(), Error> {
+        let ciphertext = array_from_slice_mut(ciphertext)?;
+        let shared_key = array_from_slice_mut(shared_key)?;
+        self.encapsulate_into(rng, ciphertext, shared_key)
+    }
+}
+
+/// Decapsulation that writes the shared key to a buffer provided by the caller, rather than
+/// returning it
+pub trait DecapsulateInto<K>
+where
+    K: KemCore + ?Sized,
+{
+    /// Decapsulate `ciphertext`, writing the shared key to `shared_key`
+    ///
+    /// # Errors
+    ///
+    /// With the `self-test` feature, returns [`Error::SelfTest`] if the module is in the error
+    /// state.  Decapsulation cannot fail otherwise.
+    fn decapsulate_into(
+        &self,
+        ciphertext: &Encoded<Ciphertext<K>>,
+        shared_key: &mut SharedKey<K>,
+    ) -> Result<(), Error>;
+
+    /// Decapsulate as in [`DecapsulateInto::decapsulate_into`], from and to byte slices.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidLength`] if either slice is not the length of the value it holds,
+    /// or the same errors as [`DecapsulateInto::decapsulate_into`].
+    fn decapsulate_into_slice(
+        &self,
+        ciphertext: &[u8],
+        shared_key: &mut [u8],
+    ) -> Result<(), Error> {
+        let ciphertext = encoded_from_slice::<Ciphertext<K>>(ciphertext)?;
+        let shared_key = array_from_slice_mut(shared_key)?;
+        self.decapsulate_into(ciphertext, shared_key)?;
+        Ok(())
+    }
+}
+
 /// A generic interface to a Key Encapsulation Method
 pub trait KemCore {
     /// The size of a shared key generated by this KEM
@@ -115,34 +326,119 @@ pub trait KemCore {
     /// The size of a ciphertext encapsulating a shared key
     type CiphertextSize: ArraySize;
 
+    /// Whether this KEM is approved for use in a FIPS 140-3 module
+    #[cfg(feature = "self-test")]
+    const APPROVED: bool = false;
+
     /// A decapsulation key for this KEM
-    type DecapsulationKey: Decapsulate<Ciphertext<Self>, SharedKey<Self>>
+    type DecapsulationKey: Decapsulate<Ciphertext<Self>, SharedKey<Self>, Error = Error>
+        + DecapsulateInto<Self>
         + EncodedSizeUser
+        + for<'a> TryFrom<&'a [u8], Error = Error>
         + Debug
-        + PartialEq;
+        + PartialEq
+        + ConstantTimeEq;
 
     /// An encapsulation key for this KEM
     #[cfg(not(feature = "deterministic"))]
-    type EncapsulationKey: Encapsulate<Ciphertext<Self>, SharedKey<Self>>
+    type EncapsulationKey: Encapsulate<Ciphertext<Self>, SharedKey<Self>, Error = Error>
+        + EncapsulateInto<Self>
         + EncodedSizeUser
+        + for<'a> TryFrom<&'a [u8], Error = Error>
         + Debug
-        + PartialEq;
+        + PartialEq
+        + ConstantTimeEq;
 
     /// An encapsulation key for this KEM
     #[cfg(feature = "deterministic")]
-    type EncapsulationKey: Encapsulate<Ciphertext<Self>, SharedKey<Self>>
-        + EncapsulateDeterministic<Ciphertext<Self>, SharedKey<Self>>
+    type EncapsulationKey: Encapsulate<Ciphertext<Self>, SharedKey<Self>, Error = Error>
+        + EncapsulateDeterministic<Ciphertext<Self>, SharedKey<Self>, Error = Error>
+        + EncapsulateInto<Self>
         + EncodedSizeUser
+        + for<'a> TryFrom<&'a [u8], Error = Error>
         + Debug
-        + PartialEq;
+        + PartialEq
+        + ConstantTimeEq;
 
-    /// Generate a new (decapsulation, encapsulation) key pair
+    /// Generate a new (decapsulation, encapsulation) key pair.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the RNG fails.  With the `pct` feature, also panics if the new key pair fails the
+    /// pairwise consistency test, and with the `self-test` feature, if the module is in the error
+    /// state.  Use [`KemCore::try_generate`] to handle these failures as errors; code built with
+    /// `pct` or `self-test` should prefer it.
     fn generate(rng: &mut impl CryptoRngCore) -> (Self::DecapsulationKey, Self::EncapsulationKey);
 
-    /// Generate a new (decapsulation, encapsulation) key pair deterministically
+    /// Generate a new (decapsulation, encapsulation) key pair, reporting a failure of the RNG
+    /// instead of panicking.
+    ///
+    /// With the `pct` feature, ML-KEM key pairs are checked with a pairwise consistency test
+    /// before being returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Rng`] if the RNG fails to provide randomness,
+    /// [`Error::PairwiseConsistency`] if the new key pair fails the pairwise consistency test, or
+    /// [`Error::SelfTest`] if the module is in the self-test error state.
+    fn try_generate(
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<(Self::DecapsulationKey, Self::EncapsulationKey), Error>;
+
+    /// Generate a new key pair as in [`KemCore::generate`], writing the encoded keys to `dk` and
+    /// `ek` instead of returning them.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`KemCore::generate`], including a failed pairwise
+    /// consistency test with the `pct` feature.
+    fn generate_into(
+        rng: &mut impl CryptoRngCore,
+        dk: &mut Encoded<Self::DecapsulationKey>,
+        ek: &mut Encoded<Self::EncapsulationKey>,
+    ) {
+        let (dk_new, ek_new) = Self::generate(rng);
+        dk_new.write_bytes(dk);
+        ek_new.write_bytes(ek);
+    }
+
+    /// Generate a new key pair as in [`KemCore::try_generate`], writing the encoded keys to `dk`
+    /// and `ek` instead of returning them.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`KemCore::try_generate`].
+    fn try_generate_into(
+        rng: &mut impl CryptoRngCore,
+        dk: &mut Encoded<Self::DecapsulationKey>,
+        ek: &mut Encoded<Self::EncapsulationKey>,
+    ) -> Result<(), Error> {
+        let (dk_new, ek_new) = Self::try_generate(rng)?;
+        dk_new.write_bytes(dk);
+        ek_new.write_bytes(ek);
+        Ok(())
+    }
+
+    /// Generate a new (decapsulation, encapsulation) key pair deterministically.
+    ///
+    /// No pairwise consistency test is run on the key pair, even with the `pct` feature.
+    ///
+    /// # Panics
+    ///
+    /// With the `self-test` feature, panics if the module is in the error state.
     #[cfg(feature = "deterministic")]
     fn generate_deterministic(d: &B32, z: &B32)
         -> (Self::DecapsulationKey, Self::EncapsulationKey);
+
+    /// Expand a (decapsulation, encapsulation) key pair from a 64-byte seed `(d || z)`.
+    ///
+    /// No pairwise consistency test is run on the key pair, even with the `pct` feature: The test
+    /// applies to newly generated keys, and expanding a stored seed is a form of key import.
+    ///
+    /// # Panics
+    ///
+    /// With the `self-test` feature, panics if the module is in the error state.
+    fn from_seed(seed: &Seed) -> (Self::DecapsulationKey, Self::EncapsulationKey);
 }
 
 /// `MlKem512` is the parameter set for security category 1, corresponding to key search on a block
@@ -184,11 +480,145 @@ impl ParameterSet for MlKem1024Params {
     type Dv = U5;
 }
 
-/// A shared key produced by the KEM `K`
+/// A shared key produced by the KEM `K`.  Shared keys are secret, so they should be compared using
+/// [`ConstantTimeEq`] on their byte slices rather than `==`.
 pub type SharedKey<K> = Array<u8, <K as KemCore>::SharedKeySize>;
 
-/// A ciphertext produced by the KEM `K`
-pub type Ciphertext<K> = Array<u8, <K as KemCore>::CiphertextSize>;
+/// A ciphertext produced by the KEM `K`.  Equality comparisons on ciphertexts run in constant
+/// time.
+pub struct Ciphertext<K>(Array<u8, K::CiphertextSize>)
+where
+    K: KemCore + ?Sized;
+
+impl<K> EncodedSizeUser for Ciphertext<K>
+where
+    K: KemCore + ?Sized,
+{
+    type EncodedSize = K::CiphertextSize;
+
+    // Every byte string of the right length is a well-formed ciphertext; the FIPS 203 ciphertext
+    // type check is just a length check, which the type system enforces for us.  So the default
+    // `try_from_bytes` applies.
+    fn from_bytes(enc: &Encoded<Self>) -> Self {
+        Self(enc.clone())
+    }
+
+    fn as_bytes(&self) -> Encoded<Self> {
+        self.0.clone()
+    }
+}
+
+impl<K> From<Array<u8, K::CiphertextSize>> for Ciphertext<K>
+where
+    K: KemCore + ?Sized,
+{
+    fn from(enc: Array<u8, K::CiphertextSize>) -> Self {
+        Self(enc)
+    }
+}
+
+impl<K> TryFrom<&[u8]> for Ciphertext<K>
+where
+    K: KemCore + ?Sized,
+{
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        Self::try_from_bytes(encoded_from_slice::<Self>(bytes)?)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<K> serdect::serde::Serialize for Ciphertext<K>
+where
+    K: KemCore + ?Sized,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serdect::serde::Serializer,
+    {
+        serialize_encoded(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, K> serdect::serde::Deserialize<'de> for Ciphertext<K>
+where
+    K: KemCore + ?Sized,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serdect::serde::Deserializer<'de>,
+    {
+        deserialize_encoded(deserializer)
+    }
+}
+
+impl<K> AsRef<[u8]> for Ciphertext<K>
+where
+    K: KemCore + ?Sized,
+{
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl<K> Clone for Ciphertext<K>
+where
+    K: KemCore + ?Sized,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<K> Debug for Ciphertext<K>
+where
+    K: KemCore + ?Sized,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Ciphertext").field(&self.0).finish()
+    }
+}
+
+impl<K> ConstantTimeEq for Ciphertext<K>
+where
+    K: KemCore + ?Sized,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.as_slice().ct_eq(other.0.as_slice())
+    }
+}
+
+impl<K> PartialEq for Ciphertext<K>
+where
+    K: KemCore + ?Sized,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl<K> Eq for Ciphertext<K> where K: KemCore + ?Sized {}
+
+/// `Ipd<P>` is the parameter set `P` as specified in the FIPS 203 initial public draft, which
+/// differs from the final standard in how key generation derives its seeds.  Encapsulation and
+/// decapsulation are computed in the same way, so a draft-era key in expanded form works with
+/// either, but a draft-era seed must be expanded using `Ipd<P>`.
+#[derive(Default, Clone, Debug, PartialEq)]
+pub struct Ipd<P>(PhantomData<P>);
+
+impl<P> ParameterSet for Ipd<P>
+where
+    P: ParameterSet,
+{
+    type K = P::K;
+    type Eta1 = P::Eta1;
+    type Eta2 = P::Eta2;
+    type Du = P::Du;
+    type Dv = P::Dv;
+    const IPD: bool = true;
+}
 
 /// ML-KEM with the parameter set for security category 1, corresponding to key search on a block
 /// cipher with a 128-bit key.
@@ -202,6 +632,24 @@ pub type MlKem768 = kem::Kem<MlKem768Params>;
 /// cipher with a 256-bit key.
 pub type MlKem1024 = kem::Kem<MlKem1024Params>;
 
+/// ML-KEM-512 as specified in the FIPS 203 initial public draft
+pub type MlKem512Ipd = kem::Kem<Ipd<MlKem512Params>>;
+
+/// ML-KEM-768 as specified in the FIPS 203 initial public draft
+pub type MlKem768Ipd = kem::Kem<Ipd<MlKem768Params>>;
+
+/// ML-KEM-1024 as specified in the FIPS 203 initial public draft
+pub type MlKem1024Ipd = kem::Kem<Ipd<MlKem1024Params>>;
+
+/// Kyber512 as submitted to round 3 of the NIST PQC process
+pub type Kyber512 = kyber::Kyber<MlKem512Params>;
+
+/// Kyber768 as submitted to round 3 of the NIST PQC process
+pub type Kyber768 = kyber::Kyber<MlKem768Params>;
+
+/// Kyber1024 as submitted to round 3 of the NIST PQC process
+pub type Kyber1024 = kyber::Kyber<MlKem1024Params>;
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -225,4 +673,60 @@ mod test {
         round_trip_test::<MlKem768>();
         round_trip_test::<MlKem1024>();
     }
+
+    fn try_from_slice_test<K>()
+    where
+        K: KemCore,
+    {
+        let mut rng = rand::thread_rng();
+        let (dk, ek) = K::generate(&mut rng);
+        let (ct, _) = ek.encapsulate(&mut rng).unwrap();
+
+        let dk_bytes = dk.as_bytes();
+        let ek_bytes = ek.as_bytes();
+        let ct_bytes = ct.as_bytes();
+
+        assert_eq!(
+            dk,
+            K::DecapsulationKey::try_from(dk_bytes.as_slice()).unwrap()
+        );
+        assert_eq!(
+            ek,
+            K::EncapsulationKey::try_from(ek_bytes.as_slice()).unwrap()
+        );
+        assert_eq!(ct, Ciphertext::<K>::try_from(ct_bytes.as_slice()).unwrap());
+
+        let short = &ek_bytes[..ek_bytes.len() - 1];
+        assert_eq!(
+            K::EncapsulationKey::try_from(short),
+            Err(Error::InvalidLength {
+                expected: ek_bytes.len(),
+                actual: ek_bytes.len() - 1
+            })
+        );
+
+        let long = [dk_bytes.as_slice(), &[0]].concat();
+        assert_eq!(
+            K::DecapsulationKey::try_from(long.as_slice()),
+            Err(Error::InvalidLength {
+                expected: dk_bytes.len(),
+                actual: dk_bytes.len() + 1
+            })
+        );
+
+        assert_eq!(
+            Ciphertext::<K>::try_from(&ct_bytes[1..]),
+            Err(Error::InvalidLength {
+                expected: ct_bytes.len(),
+                actual: ct_bytes.len() - 1
+            })
+        );
+    }
+
+    #[test]
+    fn try_from_slice() {
+        try_from_slice_test::<MlKem512>();
+        try_from_slice_test::<MlKem768>();
+        try_from_slice_test::<MlKem1024>();
+    }
 }
@@ -22,7 +22,7 @@ use hybrid_array::{
     Array,
 };
 
-use crate::algebra::{FieldElement, NttVector};
+use crate::algebra::{FieldElement, Integer, NttVector};
 use crate::encode::Encode;
 use crate::util::{Flatten, Unflatten, B32};
 
@@ -102,11 +102,11 @@ pub trait CbdSamplingSize: ArraySize {
 //
 // * Splitting a sampled integer into two parts
 // * Counting the ones in each part
-// * Taking the difference between the two counts mod q
+// * Taking the difference between the two counts, as a signed representative
 //
 // We have to allow the use of `as` here because we can't use our nice Truncate trait, because
 // const functions don't support traits.
-#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
 const fn ones_array<const B: usize, const N: usize, U>() -> Array<FieldElement, U>
 where
     U: ArraySize<ArrayType<FieldElement> = [FieldElement; N]>,
@@ -118,10 +118,10 @@ where
     while x < max {
         let mut y = 0usize;
         while y < max {
-            let x_ones = x.count_ones() as u16;
-            let y_ones = y.count_ones() as u16;
+            let x_ones = x.count_ones() as Integer;
+            let y_ones = y.count_ones() as Integer;
             let i = x + (y << B);
-            out[i] = FieldElement((x_ones + FieldElement::Q - y_ones) % FieldElement::Q);
+            out[i] = FieldElement(x_ones - y_ones);
 
             y += 1;
         }
@@ -161,6 +161,11 @@ pub trait ParameterSet: Default + Clone + Debug + PartialEq {
 
     /// The bit width of encoded integers in the `v` polynomial in a ciphertext
     type Dv: EncodingSize;
+
+    /// Whether to use the key generation of the FIPS 203 initial public draft, which derives the
+    /// K-PKE seeds from `G(d)` rather than from `G(d || k)` as in the final standard.  This is set
+    /// by [`Ipd`](crate::Ipd), and should otherwise be left at its default.
+    const IPD: bool = false;
 }
 
 type EncodedUSize<P> = EncodedPolynomialVectorSize<<P as ParameterSet>::Du, <P as ParameterSet>::K>;
@@ -178,11 +183,13 @@ pub trait PkeParams: ParameterSet {
     fn encode_u12(p: &NttVector<Self::K>) -> EncodedNttVector<Self>;
     fn decode_u12(v: &EncodedNttVector<Self>) -> NttVector<Self::K>;
 
-    fn concat_ct(u: EncodedU<Self>, v: EncodedV<Self>) -> EncodedCiphertext<Self>;
     fn split_ct(ct: &EncodedCiphertext<Self>) -> (&EncodedU<Self>, &EncodedV<Self>);
+    fn split_ct_mut(ct: &mut EncodedCiphertext<Self>)
+        -> (&mut EncodedU<Self>, &mut EncodedV<Self>);
 
-    fn concat_ek(t_hat: EncodedNttVector<Self>, rho: B32) -> EncodedEncryptionKey<Self>;
     fn split_ek(ek: &EncodedEncryptionKey<Self>) -> (&EncodedNttVector<Self>, &B32);
+    fn split_ek_mut(ek: &mut EncodedEncryptionKey<Self>)
+        -> (&mut EncodedNttVector<Self>, &mut B32);
 }
 
 pub type EncodedNttVector<P> = Array<u8, <P as PkeParams>::NttVectorSize>;
@@ -214,21 +221,25 @@ where
         Encode::<U12>::decode(v)
     }
 
-    fn concat_ct(u: EncodedU<Self>, v: EncodedV<Self>) -> EncodedCiphertext<Self> {
-        u.concat(v)
-    }
-
     fn split_ct(ct: &EncodedCiphertext<Self>) -> (&EncodedU<Self>, &EncodedV<Self>) {
         ct.split_ref()
     }
 
-    fn concat_ek(t_hat: EncodedNttVector<Self>, rho: B32) -> EncodedEncryptionKey<Self> {
-        t_hat.concat(rho)
+    fn split_ct_mut(
+        ct: &mut EncodedCiphertext<Self>,
+    ) -> (&mut EncodedU<Self>, &mut EncodedV<Self>) {
+        ct.split_ref_mut()
     }
 
     fn split_ek(ek: &EncodedEncryptionKey<Self>) -> (&EncodedNttVector<Self>, &B32) {
         ek.split_ref()
     }
+
+    fn split_ek_mut(
+        ek: &mut EncodedEncryptionKey<Self>,
+    ) -> (&mut EncodedNttVector<Self>, &mut B32) {
+        ek.split_ref_mut()
+    }
 }
 
 /// Derived parameters relevant to ML-KEM
@@ -250,6 +261,15 @@ pub trait KemParams: PkeParams {
         &B32,
         &B32,
     );
+
+    fn split_dk_mut(
+        enc: &mut EncodedDecapsulationKey<Self>,
+    ) -> (
+        &mut EncodedDecryptionKey<Self>,
+        &mut EncodedEncryptionKey<Self>,
+        &mut B32,
+        &mut B32,
+    );
 }
 
 pub type DecapsulationKeySize<P> = <P as KemParams>::DecapsulationKeySize;
@@ -294,4 +314,19 @@ where
         let (dk_pke, ek_pke) = enc.split_ref();
         (dk_pke, ek_pke, h, z)
     }
+
+    #[allow(clippy::similar_names)] // allow dk_pke, ek_pke, following the spec
+    fn split_dk_mut(
+        enc: &mut EncodedDecapsulationKey<Self>,
+    ) -> (
+        &mut EncodedDecryptionKey<Self>,
+        &mut EncodedEncryptionKey<Self>,
+        &mut B32,
+        &mut B32,
+    ) {
+        let (enc, z) = enc.split_ref_mut();
+        let (enc, h) = enc.split_ref_mut();
+        let (dk_pke, ek_pke) = enc.split_ref_mut();
+        (dk_pke, ek_pke, h, z)
+    }
 }
@@ -1,15 +1,20 @@
-use hybrid_array::typenum::{Unsigned, U1};
+use hybrid_array::typenum::{Unsigned, U1, U12};
+use subtle::{Choice, ConstantTimeEq};
 
-use crate::algebra::{NttMatrix, NttVector, Polynomial, PolynomialVector};
+use crate::algebra::{MatrixRows, NttMatrix, NttVector, Polynomial, PolynomialVector};
 use crate::compress::Compress;
-use crate::crypto::{G, PRF};
-use crate::encode::Encode;
+use crate::crypto::G;
+use crate::encode::{encode_ntt_vector_into, Encode};
 use crate::param::{EncodedCiphertext, EncodedDecryptionKey, EncodedEncryptionKey, PkeParams};
-use crate::util::B32;
+use crate::util::{wipe, B32};
+use crate::Error;
+
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /// A `DecryptionKey` provides the ability to generate a new key pair, and decrypt an
 /// encrypted value.
-#[derive(Clone, Default, Debug, PartialEq)]
+#[derive(Clone, Default, Debug)]
 pub struct DecryptionKey<P>
 where
     P: PkeParams,
@@ -17,27 +22,87 @@ where
     s_hat: NttVector<P::K>,
 }
 
+impl<P> ConstantTimeEq for DecryptionKey<P>
+where
+    P: PkeParams,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.s_hat.ct_eq(&other.s_hat)
+    }
+}
+
+impl<P> PartialEq for DecryptionKey<P>
+where
+    P: PkeParams,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<P> Zeroize for DecryptionKey<P>
+where
+    P: PkeParams,
+{
+    fn zeroize(&mut self) {
+        self.s_hat.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<P> Drop for DecryptionKey<P>
+where
+    P: PkeParams,
+{
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<P> ZeroizeOnDrop for DecryptionKey<P> where P: PkeParams {}
+
 impl<P> DecryptionKey<P>
 where
     P: PkeParams,
 {
     /// Generate a new random decryption key according to the `K-PKE.KeyGen` procedure.
-    // Algorithm 12. K-PKE.KeyGen()
     pub fn generate(d: &B32) -> (Self, EncryptionKey<P>) {
-        // Generate random seeds
-        let (rho, sigma) = G(&[d]);
+        // The final standard appends `k` to `d` for domain separation between parameter sets.
+        Self::generate_with_domain_separation(d, !P::IPD)
+    }
 
-        // Sample pseudo-random matrix and vectors
-        let A_hat: NttMatrix<P::K> = NttMatrix::sample_uniform(&rho, false);
-        let s: PolynomialVector<P::K> = PolynomialVector::sample_cbd::<P::Eta1>(&sigma, 0);
-        let e: PolynomialVector<P::K> = PolynomialVector::sample_cbd::<P::Eta1>(&sigma, P::K::U8);
+    /// Generate a new random decryption key, with or without the FIPS 203 domain separator.  The
+    /// FIPS 203 draft and Kyber round 3 derive the seeds from `d` alone.
+    // Algorithm 12. K-PKE.KeyGen()
+    pub fn generate_with_domain_separation(d: &B32, separate: bool) -> (Self, EncryptionKey<P>) {
+        // Generate random seeds
+        let (rho, mut sigma) = if separate {
+            G(&[d.as_slice(), &[P::K::U8]])
+        } else {
+            G(&[d.as_slice()])
+        };
 
-        // NTT the vectors
+        // Sample pseudo-random vectors
+        let mut s = PolynomialVector::<P::K>::default();
+        let mut e = PolynomialVector::<P::K>::default();
+        Polynomial::sample_cbd_into::<P::Eta1>(&sigma, 0, s.0.iter_mut().chain(e.0.iter_mut()));
         let s_hat = s.ntt();
-        let e_hat = e.ntt();
 
-        // Compute the public value
-        let t_hat = &(&A_hat * &s_hat) + &e_hat;
+        // Compute the public value `t_hat = A_hat s_hat + NTT(e)` one row of `A_hat` at a time
+        let mut t_hat = NttVector::<P::K>::default();
+        MatrixRows::with(&rho, false, |A_hat| {
+            for (i, (t_hat_i, e_i)) in t_hat.0.iter_mut().zip(e.0.iter()).enumerate() {
+                let mut As_hat_i = A_hat.row_product(i, &s_hat);
+                let mut e_hat_i = e_i.ntt();
+                *t_hat_i = &As_hat_i + &e_hat_i;
+                t_hat_i.reduce();
+                wipe!(As_hat_i, e_hat_i);
+            }
+        });
+
+        wipe!(sigma, s, e);
 
         // Assemble the keys
         let dk = DecryptionKey { s_hat };
@@ -57,14 +122,27 @@ where
         v.decompress::<P::Dv>();
 
         let u_hat = u.ntt();
-        let sTu = (&self.s_hat * &u_hat).ntt_inverse();
+        let mut sTu_hat = &self.s_hat * &u_hat;
+        let mut sTu = sTu_hat.ntt_inverse();
         let mut w = &v - &sTu;
-        Encode::<U1>::encode(w.compress::<U1>())
+        let m = Encode::<U1>::encode(w.compress::<U1>());
+
+        wipe!(sTu_hat, sTu, w);
+        m
     }
 
     /// Represent this decryption key as a byte array `(s_hat)`
+    #[cfg(test)]
     pub fn as_bytes(&self) -> EncodedDecryptionKey<P> {
-        P::encode_u12(&self.s_hat)
+        let mut enc = EncodedDecryptionKey::<P>::default();
+        self.write_bytes(&mut enc);
+        enc
+    }
+
+    /// Write this decryption key to `enc` as a byte array `(s_hat)`, without leaving a copy of the
+    /// encoding anywhere else
+    pub fn write_bytes(&self, enc: &mut EncodedDecryptionKey<P>) {
+        encode_ntt_vector_into::<U12, P::K>(&self.s_hat, enc);
     }
 
     /// Parse an decryption key from a byte array `(s_hat)`
@@ -72,6 +150,22 @@ where
         let s_hat = P::decode_u12(enc);
         Self { s_hat }
     }
+
+    /// Parse a decryption key from a byte array `(s_hat)`, verifying that every coefficient of
+    /// `s_hat` is reduced modulo `q`.
+    pub fn try_from_bytes(enc: &EncodedDecryptionKey<P>) -> Result<Self, Error> {
+        let dk = Self::from_bytes(enc);
+        let mut reencoded = EncodedDecryptionKey::<P>::default();
+        dk.write_bytes(&mut reencoded);
+        let canonical = reencoded.as_slice().ct_eq(enc.as_slice());
+        wipe!(reencoded);
+
+        if !bool::from(canonical) {
+            return Err(Error::InvalidDecryptionKey);
+        }
+
+        Ok(dk)
+    }
 }
 
 /// An `EncryptionKey` provides the ability to encrypt a value so that it can only be
@@ -85,6 +179,15 @@ where
     rho: B32,
 }
 
+impl<P> ConstantTimeEq for EncryptionKey<P>
+where
+    P: PkeParams,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.t_hat.ct_eq(&other.t_hat) & self.rho.as_slice().ct_eq(other.rho.as_slice())
+    }
+}
+
 impl<P> EncryptionKey<P>
 where
     P: PkeParams,
@@ -92,32 +195,90 @@ where
     /// Encrypt the specified message for the holder of the corresponding decryption key, using the
     /// provided randomness, according the `K-PKE.Encrypt` procedure.
     pub fn encrypt(&self, message: &B32, randomness: &B32) -> EncodedCiphertext<P> {
-        let r = PolynomialVector::<P::K>::sample_cbd::<P::Eta1>(randomness, 0);
-        let e1 = PolynomialVector::<P::K>::sample_cbd::<P::Eta2>(randomness, P::K::U8);
+        let mut ciphertext = EncodedCiphertext::<P>::default();
+        self.encrypt_into(message, randomness, &mut ciphertext);
+        ciphertext
+    }
+
+    /// Encrypt as in [`EncryptionKey::encrypt`], writing the ciphertext to `ciphertext`
+    pub fn encrypt_into(
+        &self,
+        message: &B32,
+        randomness: &B32,
+        ciphertext: &mut EncodedCiphertext<P>,
+    ) {
+        self.with_matrix_rows(|A_hat_t| {
+            self.encrypt_with_rows(A_hat_t, message, randomness, ciphertext);
+        });
+    }
+
+    /// Call `f` with the rows of the transposed matrix `A_hat^T`, sampled from `rho` as selected
+    /// by the `low-memory` feature.
+    pub fn with_matrix_rows<R>(&self, f: impl FnOnce(&MatrixRows<'_, P::K>) -> R) -> R {
+        MatrixRows::with(&self.rho, true, f)
+    }
+
+    /// Sample the transposed matrix `A_hat^T` from `rho`, for use with
+    /// [`EncryptionKey::encrypt_with_rows`].
+    pub fn matrix(&self) -> NttMatrix<P::K> {
+        NttMatrix::sample_uniform(&self.rho, true)
+    }
 
-        let prf_output = PRF::<P::Eta2>(randomness, 2 * P::K::U8);
-        let e2: Polynomial = Polynomial::sample_cbd::<P::Eta2>(&prf_output);
+    /// Encrypt as in [`EncryptionKey::encrypt`], taking the rows of `A_hat^T` from `A_hat_t` and
+    /// writing the ciphertext to `ciphertext`
+    pub fn encrypt_with_rows(
+        &self,
+        A_hat_t: &MatrixRows<'_, P::K>,
+        message: &B32,
+        randomness: &B32,
+        ciphertext: &mut EncodedCiphertext<P>,
+    ) {
+        let mut r = PolynomialVector::<P::K>::sample_cbd::<P::Eta1>(randomness, 0);
+        let mut u = PolynomialVector::<P::K>::default();
+        let mut e2 = Polynomial::default();
+        Polynomial::sample_cbd_into::<P::Eta2>(
+            randomness,
+            P::K::U8,
+            u.0.iter_mut().chain(core::iter::once(&mut e2)),
+        );
 
-        let A_hat_t = NttMatrix::<P::K>::sample_uniform(&self.rho, true);
-        let r_hat: NttVector<P::K> = r.ntt();
-        let ATr: PolynomialVector<P::K> = (&A_hat_t * &r_hat).ntt_inverse();
-        let mut u = ATr + e1;
+        // `u` holds `e1` until `u = NTT^-1(A_hat^T r_hat) + e1` is computed in its place, one row
+        // of `A_hat^T` at a time
+        let mut r_hat: NttVector<P::K> = r.ntt();
+        for (i, u_i) in u.0.iter_mut().enumerate() {
+            let mut ATr_hat_i = A_hat_t.row_product(i, &r_hat);
+            let mut ATr_i = ATr_hat_i.ntt_inverse();
+            *u_i = &ATr_i + &*u_i;
+            wipe!(ATr_hat_i, ATr_i);
+        }
 
         let mut mu: Polynomial = Encode::<U1>::decode(message);
         mu.decompress::<U1>();
 
-        let tTr: Polynomial = (&self.t_hat * &r_hat).ntt_inverse();
-        let mut v = &(&tTr + &e2) + &mu;
+        let mut tTr_hat = &self.t_hat * &r_hat;
+        let mut tTr: Polynomial = tTr_hat.ntt_inverse();
+        let mut tTr_e2 = &tTr + &e2;
+        let mut v = &tTr_e2 + &mu;
 
-        let c1 = Encode::<P::Du>::encode(u.compress::<P::Du>());
-        let c2 = Encode::<P::Dv>::encode(v.compress::<P::Dv>());
-        P::concat_ct(c1, c2)
+        let (c1, c2) = P::split_ct_mut(ciphertext);
+        *c1 = Encode::<P::Du>::encode(u.compress::<P::Du>());
+        *c2 = Encode::<P::Dv>::encode(v.compress::<P::Dv>());
+
+        wipe!(r, e2, r_hat, u, mu, tTr_hat, tTr, tTr_e2, v);
     }
 
     /// Represent this encryption key as a byte array `(t_hat || rho)`
     pub fn as_bytes(&self) -> EncodedEncryptionKey<P> {
-        let t_hat = P::encode_u12(&self.t_hat);
-        P::concat_ek(t_hat, self.rho.clone())
+        let mut enc = EncodedEncryptionKey::<P>::default();
+        self.write_bytes(&mut enc);
+        enc
+    }
+
+    /// Write this encryption key to `enc` as a byte array `(t_hat || rho)`
+    pub fn write_bytes(&self, enc: &mut EncodedEncryptionKey<P>) {
+        let (t_hat, rho) = P::split_ek_mut(enc);
+        *t_hat = P::encode_u12(&self.t_hat);
+        rho.copy_from_slice(&self.rho);
     }
 
     /// Parse an encryption key from a byte array `(t_hat || rho)`
@@ -129,6 +290,18 @@ where
             rho: rho.clone(),
         }
     }
+
+    /// Parse an encryption key from a byte array `(t_hat || rho)`, performing the modulus check
+    /// from FIPS 203: Decoding reduces each coefficient of `t_hat` modulo `q`, so the key is
+    /// valid only if it re-encodes to the same bytes.
+    pub fn try_from_bytes(enc: &EncodedEncryptionKey<P>) -> Result<Self, Error> {
+        let ek = Self::from_bytes(enc);
+        if ek.as_bytes() != *enc {
+            return Err(Error::InvalidEncapsulationKey);
+        }
+
+        Ok(ek)
+    }
 }
 
 #[cfg(test)]
@@ -168,6 +341,7 @@ mod test {
         let (dk_original, ek_original) = DecryptionKey::<P>::generate(&d);
 
         let dk_encoded = dk_original.as_bytes();
+        assert_eq!(dk_encoded, P::encode_u12(&dk_original.s_hat));
         let dk_decoded = DecryptionKey::from_bytes(&dk_encoded);
         assert_eq!(dk_original, dk_decoded);
 
@@ -0,0 +1,306 @@
+use core::sync::atomic::{AtomicU8, Ordering};
+use hex_literal::hex;
+use hybrid_array::typenum::{U2, U32};
+use sha3::digest::XofReader;
+
+use crate::crypto::{G, H, J, PRF, XOF};
+use crate::kem::DecapsulationKey;
+use crate::param::{EncodedCiphertext, KemParams};
+use crate::util::{Truncate, B32};
+use crate::{EncodedSizeUser, Error, KemCore, MlKem1024Params, MlKem512Params};
+use crate::{MlKem768Params, Seed};
+
+/// The state of the module with respect to its self-tests
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ModuleState {
+    /// The self-tests have not yet run.  They run automatically before the first service, or
+    /// when [`self_test`] is called.
+    Untested,
+
+    /// [`self_test`] has passed, and no self-test has failed since.
+    Operational,
+
+    /// A self-test has failed.  This state is permanent: fallible operations return
+    /// [`Error::SelfTest`], and infallible ones panic.
+    Error,
+}
+
+/// Whether operations of a KEM run as an approved service
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ServiceIndicator {
+    /// The algorithm is approved, and the module is operational
+    Approved,
+
+    /// The algorithm is not approved, or the module has not passed its self-tests
+    NotApproved,
+}
+
+/// A module state that, once it has entered the error state, stays there
+struct Latch(AtomicU8);
+
+impl Latch {
+    const UNTESTED: u8 = 0;
+    const OPERATIONAL: u8 = 1;
+    const ERROR: u8 = 2;
+
+    const fn new() -> Self {
+        Self(AtomicU8::new(Self::UNTESTED))
+    }
+
+    fn get(&self) -> ModuleState {
+        match self.0.load(Ordering::SeqCst) {
+            Self::UNTESTED => ModuleState::Untested,
+            Self::OPERATIONAL => ModuleState::Operational,
+            _ => ModuleState::Error,
+        }
+    }
+
+    /// Run the self-tests with `run` if they have not yet run, and fail if the module is in the
+    /// error state
+    fn check(&self, run: impl FnOnce() -> bool) -> Result<(), Error> {
+        match self.get() {
+            ModuleState::Untested => self.record(run()),
+            ModuleState::Operational => Ok(()),
+            ModuleState::Error => Err(Error::SelfTest),
+        }
+    }
+
+    fn record(&self, passed: bool) -> Result<(), Error> {
+        if passed {
+            // A concurrent failure must not be overwritten, so only leave the untested state
+            let _ = self.0.compare_exchange(
+                Self::UNTESTED,
+                Self::OPERATIONAL,
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            );
+        } else {
+            self.0.store(Self::ERROR, Ordering::SeqCst);
+        }
+
+        match self.get() {
+            ModuleState::Error => Err(Error::SelfTest),
+            _ => Ok(()),
+        }
+    }
+}
+
+static STATE: Latch = Latch::new();
+
+/// Run the cryptographic algorithm self-tests (CASTs): known-answer tests of the hash functions
+/// `G`, `H`, `J`, `PRF` and `XOF`, and of ML-KEM key generation, encapsulation and decapsulation
+/// for each parameter set.
+///
+/// The self-tests run automatically before the first service, so calling this is only needed to
+/// run them on demand.  On success the module becomes [`ModuleState::Operational`].  On failure
+/// it enters [`ModuleState::Error`] and stays there.
+///
+/// # Errors
+///
+/// Returns [`Error::SelfTest`] if a self-test fails, or if one has failed before.
+pub fn self_test() -> Result<(), Error> {
+    if module_state() == ModuleState::Error {
+        return Err(Error::SelfTest);
+    }
+    STATE.record(run_casts())
+}
+
+/// The current state of the module
+#[must_use]
+pub fn module_state() -> ModuleState {
+    STATE.get()
+}
+
+/// Whether operations of the KEM `K` currently run as an approved service.  Only the final
+/// FIPS 203 parameter sets are approved, and only once the self-tests have passed.  Every service
+/// runs the self-tests first if they have not yet run, so after a service returns this reports
+/// whether that service was approved.
+#[must_use]
+pub fn service_indicator<K: KemCore>() -> ServiceIndicator {
+    if K::APPROVED && module_state() == ModuleState::Operational {
+        ServiceIndicator::Approved
+    } else {
+        ServiceIndicator::NotApproved
+    }
+}
+
+/// Run the self-tests if they have not yet run, and fail if the module is in the error state
+pub(crate) fn check() -> Result<(), Error> {
+    STATE.check(run_casts)
+}
+
+/// As [`check`], but panic on failure, for operations that cannot report an error
+pub(crate) fn assert_operational() {
+    assert!(check().is_ok(), "ML-KEM self-test failed");
+}
+
+fn run_casts() -> bool {
+    cast_primitives()
+        && cast_kem::<MlKem512Params>(&CAST_512)
+        && cast_kem::<MlKem768Params>(&CAST_768)
+        && cast_kem::<MlKem1024Params>(&CAST_1024)
+}
+
+fn cast_primitives() -> bool {
+    let (g_a, g_b) = G(&[b"Input to an invocation of G"]);
+    let g_ok = g_a == hex!("07dfced2a3a3feb3277cee1709818828ea6d2f42800152e9c312e848122231c2")
+        && g_b == hex!("272969098a1bbd5a0a9844e2f89f206d8f7f4599e36aecaa4793af400fd880d8");
+
+    let h_ok = H(b"Input to an invocation of H")
+        == hex!("0ee3ce94213d7dd0069b24b8b15cdd0bcf8eb1c6b3c21c441dc6a19e979cc7eb");
+
+    let j_ok = J(&[b"Input to an invocation of J"])
+        == hex!("a5292293d70c8eca049cbb475c48fabd625ed2b20785a18248504d3741196b52");
+
+    let prf_s = B32::from_slice(b"Input s to an invocation of PRF2");
+    let prf_ok = PRF::<U2>(prf_s, b'b')
+        == hex!(
+            "54c002415c2219b564d5c17b0df0c82f83ddf3fdecc7d814ed5d85457c06c2c3\
+             ed0b0584f926dffb1e57c6105f8604e81c4605b93f8284e44585104101042075\
+             568113c861516d91bed227638654fc7f872df205c113b8364091755b62284eec\
+             a6124f2cd4c1cdf598cb8324a4f373470a8f81ee618c75cc33f66facee01c213"
+        );
+
+    let rho = B32::from_slice(b"Input rho, to an XOF invocation!");
+    let mut xof = [0u8; 32];
+    XOF(rho, b'i', b'j').read(&mut xof);
+    let xof_ok = xof == hex!("0d2c3e65f754d074cb366cf1b099ae105cc40f018342509f15f1ba8a1a4144cb");
+
+    g_ok && h_ok && j_ok && prf_ok && xof_ok
+}
+
+/// Known answers for one parameter set, generated with OpenSSL 3.5 from the seed `0x00..0x3f`
+/// and the encapsulation randomness `0x40..0x5f`.  The rejection case flips the lowest bit of the
+/// first byte of the ciphertext.  Keys and ciphertexts are compared by their SHA3-256 digests.
+/// The self-tests call the unchecked internals, since the public services would run them again.
+struct KemCast {
+    ek_digest: [u8; 32],
+    dk_digest: [u8; 32],
+    ct_digest: [u8; 32],
+    shared_key: [u8; 32],
+    rejection_key: [u8; 32],
+}
+
+const CAST_512: KemCast = KemCast {
+    ek_digest: hex!("82f101ff648063b376e2bb6c5b7455f655a50c2feadade150efa0e0e6f365aea"),
+    dk_digest: hex!("0bd3f5df01098ac9c29d687c7f1bd0588a5573feeef8f1e3b4573fa7f6ab57c8"),
+    ct_digest: hex!("e3fdddb90255869185c07cdf1c1880b2efe08b6f04da4997b693c0dea61503bd"),
+    shared_key: hex!("14cace3e48771b316676afad2cfcfe8488daaa4fad954e57236caa3f24a42cf7"),
+    rejection_key: hex!("32ee1fb3f7bd2915218e9c1b2d0d2da88f0edce6804278bab3a6123c5bb64fc4"),
+};
+
+const CAST_768: KemCast = KemCast {
+    ek_digest: hex!("a24e16d8f8f9383a95b77050f4d9fd2f5733eec1d63ef3c23ebf9918173669a7"),
+    dk_digest: hex!("1149f17c3c4ac6ab1e3e2d9d8bd0171355ac0fa31bb8855c48ceade874c0864b"),
+    ct_digest: hex!("b4cfbd24cef67afd3764276c6980e0f88f8e9ca57f59b7f12fe1a9c1e72f4710"),
+    shared_key: hex!("9cddd089ffe70e3996e76f7c8d06746df34d07e8657bc0fcf2bb0e1c3084aea1"),
+    rejection_key: hex!("dcfc80c6db46ff7028e3a4398651c063ae7a42c107a6dc8cb07141861698ab92"),
+};
+
+const CAST_1024: KemCast = KemCast {
+    ek_digest: hex!("61349e5c131a7e116a0463861d7d18663c5627c38c7147ddaadfd48acd7a4535"),
+    dk_digest: hex!("f0db5d938027fcd9bad87847d52c14cf0c4abcf0703b749793f212111ffb303b"),
+    ct_digest: hex!("c1579fa02c614f3762b2a799b51e41cebb8f820f34fa736af02c56de2460ce3c"),
+    shared_key: hex!("0ad8d1ea1b8dd788979b4379581218df9321bdce5567eca42ae6be7d395f1a54"),
+    rejection_key: hex!("8f2c880890996c587aa500cf8b6da03372de706a9f96075744bb0956ea6fbaac"),
+};
+
+fn cast_kem<P>(cast: &KemCast) -> bool
+where
+    P: KemParams,
+{
+    let seed = Seed::from_fn(Truncate::truncate);
+    let m = B32::from_fn(|i| (64 + i).truncate());
+
+    let (d, z) = seed.split_ref::<U32>();
+    let dk = DecapsulationKey::<P>::generate_deterministic(d, z);
+    let ek = dk.encapsulation_key();
+    let keygen = H(dk.as_bytes()) == cast.dk_digest && H(ek.as_bytes()) == cast.ek_digest;
+
+    let mut ct = EncodedCiphertext::<P>::default();
+    let mut k = B32::default();
+    ek.encapsulate_deterministic_inner(&m, &mut ct, &mut k);
+    let encaps = H(&ct) == cast.ct_digest && k == cast.shared_key;
+
+    let mut bad = ct.clone();
+    bad[0] ^= 0x01;
+    let decaps = |ct| -> B32 {
+        let mut k = B32::default();
+        dk.decapsulate_unchecked(ct, &mut k);
+        k
+    };
+    let decaps = decaps(&ct) == cast.shared_key && decaps(&bad) == cast.rejection_key;
+
+    keygen && encaps && decaps
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Kyber768, MlKem768, MlKem768Ipd};
+    use core::cell::Cell;
+
+    #[test]
+    fn first_service_runs_self_tests() {
+        let _ = MlKem768::generate(&mut rand::thread_rng());
+        assert_eq!(module_state(), ModuleState::Operational);
+        assert_eq!(service_indicator::<MlKem768>(), ServiceIndicator::Approved);
+    }
+
+    #[test]
+    fn self_test_passes() {
+        assert_eq!(self_test(), Ok(()));
+        assert_eq!(module_state(), ModuleState::Operational);
+
+        assert_eq!(service_indicator::<MlKem768>(), ServiceIndicator::Approved);
+        assert_eq!(
+            service_indicator::<MlKem768Ipd>(),
+            ServiceIndicator::NotApproved
+        );
+        assert_eq!(
+            service_indicator::<Kyber768>(),
+            ServiceIndicator::NotApproved
+        );
+    }
+
+    #[test]
+    fn wrong_answer_fails() {
+        let mut cast = CAST_768;
+        cast.rejection_key[0] ^= 0x01;
+        assert!(cast_kem::<MlKem768Params>(&CAST_768));
+        assert!(!cast_kem::<MlKem768Params>(&cast));
+    }
+
+    // The global state is shared by all tests, so the latch is exercised on a local one
+    #[test]
+    fn error_state_latches() {
+        let latch = Latch::new();
+        assert_eq!(latch.get(), ModuleState::Untested);
+        assert_eq!(latch.record(true), Ok(()));
+        assert_eq!(latch.get(), ModuleState::Operational);
+        assert_eq!(latch.record(false), Err(Error::SelfTest));
+        assert_eq!(latch.record(true), Err(Error::SelfTest));
+        assert_eq!(latch.get(), ModuleState::Error);
+    }
+
+    #[test]
+    fn checks_run_once() {
+        let runs = Cell::new(0);
+        let run = |passed| {
+            runs.set(runs.get() + 1);
+            passed
+        };
+
+        let latch = Latch::new();
+        assert_eq!(latch.check(|| run(true)), Ok(()));
+        assert_eq!(latch.check(|| run(true)), Ok(()));
+        assert_eq!(latch.get(), ModuleState::Operational);
+        assert_eq!(runs.get(), 1);
+
+        let latch = Latch::new();
+        assert_eq!(latch.check(|| run(false)), Err(Error::SelfTest));
+        assert_eq!(latch.check(|| run(true)), Err(Error::SelfTest));
+        assert_eq!(latch.get(), ModuleState::Error);
+        assert_eq!(runs.get(), 2);
+    }
+}
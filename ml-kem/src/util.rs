@@ -4,7 +4,7 @@ use core::ptr;
 use hybrid_array::{
     typenum::{
         operator_aliases::{Prod, Quot},
-        Unsigned, U0, U32,
+        Unsigned, U0, U32, U64,
     },
     Array, ArraySize,
 };
@@ -12,6 +12,25 @@ use hybrid_array::{
 /// A 32-byte array, defined here for brevity because it is used several times
 pub type B32 = Array<u8, U32>;
 
+/// A 64-byte array, defined here for brevity because it is used several times
+pub type B64 = Array<u8, U64>;
+
+/// Wipe secret intermediate values from memory when the `zeroize` feature is enabled.  Without
+/// the feature, this is a no-op that still borrows its arguments mutably, so that call sites
+/// compile identically in both configurations.
+macro_rules! wipe {
+    ($($x:expr),+ $(,)?) => {
+        $(
+            #[cfg(feature = "zeroize")]
+            zeroize::Zeroize::zeroize(&mut $x);
+            #[cfg(not(feature = "zeroize"))]
+            let _ = &mut $x;
+        )+
+    };
+}
+
+pub(crate) use wipe;
+
 /// Safely truncate an unsigned integer value to shorter representation
 pub trait Truncate<T> {
     fn truncate(self) -> T;
@@ -37,6 +56,25 @@ define_truncate!(usize, u8);
 define_truncate!(u128, u16);
 define_truncate!(u128, u8);
 
+// Truncation to a signed type keeps only the bits of its non-negative range
+macro_rules! define_truncate_signed {
+    ($from:ident, $to:ident) => {
+        impl Truncate<$to> for $from {
+            fn truncate(self) -> $to {
+                // As above, the masked value always fits
+                unsafe {
+                    (self & $from::from($to::MAX.unsigned_abs()))
+                        .try_into()
+                        .unwrap_unchecked()
+                }
+            }
+        }
+    };
+}
+
+define_truncate_signed!(u32, i16);
+define_truncate_signed!(u128, i16);
+
 /// Defines a sequence of sequences that can be merged into a bigger overall seequence
 pub trait Flatten<T, M: ArraySize> {
     type OutputSize: ArraySize;
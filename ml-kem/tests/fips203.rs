@@ -0,0 +1,1327 @@
+#![cfg(feature = "deterministic")]
+
+mod vectors;
+
+use hex_literal::hex;
+use ml_kem::*;
+use vectors::*;
+
+// Cross-implementation test vectors for the final FIPS 203 standard.  These are not NIST values:
+// The NIST values for encapsulation and decapsulation are checked against the final parameter sets
+// in `nist.rs`.
+//
+// The ML-KEM-512 values are the known-answer self-test vectors of the AWS-LC FIPS module
+// (crypto/fipsmodule/self_check/self_check.c).  The ML-KEM-768 and ML-KEM-1024 values were
+// generated with the ML-KEM implementation in OpenSSL 3.5, using its deterministic key generation
+// (`hexseed`) and encapsulation (`hexikme`) options.  The rejection vectors decapsulate a
+// ciphertext that does not match the decapsulation key, exercising implicit rejection.
+
+#[test]
+fn mlkem512() {
+    GEN_VECTORS[0].verify::<MlKem512>();
+    ENC_VECTORS[0].verify::<MlKem512>();
+    DEC_VECTORS[0].verify::<MlKem512>();
+    REJ_VECTORS[0].verify::<MlKem512>();
+}
+
+#[test]
+fn mlkem768() {
+    GEN_VECTORS[1].verify::<MlKem768>();
+    ENC_VECTORS[1].verify::<MlKem768>();
+    DEC_VECTORS[1].verify::<MlKem768>();
+    REJ_VECTORS[1].verify::<MlKem768>();
+}
+
+#[test]
+fn mlkem1024() {
+    GEN_VECTORS[2].verify::<MlKem1024>();
+    ENC_VECTORS[2].verify::<MlKem1024>();
+    DEC_VECTORS[2].verify::<MlKem1024>();
+    REJ_VECTORS[2].verify::<MlKem1024>();
+}
+
+// Key generation in the initial public draft does not match the final standard, but
+// encapsulation and decapsulation do
+#[test]
+fn ipd() {
+    let v = &GEN_VECTORS[0];
+    let (d, z) = (v.d.into(), v.z.into());
+    let (_, ek) = MlKem512Ipd::generate_deterministic(&d, &z);
+    assert_ne!(ek.as_bytes().as_slice(), v.ek);
+
+    ENC_VECTORS[0].verify::<MlKem512Ipd>();
+    DEC_VECTORS[0].verify::<MlKem512Ipd>();
+    REJ_VECTORS[0].verify::<MlKem512Ipd>();
+}
+
+const GEN_VECTORS: [GenerateVector; 3] = [
+    GenerateVector {
+        d: hex!("ecdb9762f478b2fa263df46de447f3d152a1bc0e02ee953677301164d15d20d7"),
+        z: hex!("1b074bff8044445e11660b1b6b26df242b8fc02b9e8df538db17a639d7c46132"),
+        dk: &hex!("88c12ceaa6cb91f589acb86d913c7a60f7cdabe3b7b590091d0084e29a049b43"
+            "6841f2473b03165ae9c6a9826d6c650d04b388eff594505b7e54709530546825"
+            "a070a625b0e5fa866e6aaf40c2414246240973c7598aae7c363e4303abb7a111"
+            "31b464a943996de7592ca04922ea8a4d73b443ea048c06acc4e55a8f254bf6d2"
+            "71fd827119ec5b5580498bfcc09eb0266f8c2b45988ae98c1e5402b7003463f2"
+            "0359470159c0509fa971153443ce2580c0b2443f8ac2b0810401e73052d626bf"
+            "58c674ee48880c408d1f313a94b1667f897628c55a83e28634a20710d25da88b"
+            "2ac90c0f5d6b0ed6e080fd2c24bb11816b5c607957781db2287966717ffa500a"
+            "03025839164115ba5ea7b717344588169e8a85a9e83c516cab5ee6e63b7c736c"
+            "e90628ddeca99a6bc547588681bc2223f39fbc4464b41c63d924afeba1098960"
+            "bd43c12fd0080f490c8c50f4843222303c6821ad7b663d8844a1368a19549e5c"
+            "fc83d14a8491205c44f5492bf609b69c526c95aa5e508ecf714b7955b61f6cab"
+            "340b06200432ce3b1ecd0a075a8332046865959a25698a081df3532691348a25"
+            "81d606a0e0b2cf2e8b3c9afc594e926eb0d7c702c0c3e5b54ee2c86ca20cc476"
+            "aaaca3208f32a20190562c27338202071e11665f134273bdaacbc952b994ba94"
+            "62671226514b446113e7ab7edb9c54c311c4da94104d262a80280e39201e7551"
+            "91763983c439a95aeaafa767c2cb594829e6313e386982d6621acc4bb0991a60"
+            "790a2b0c5f3139aadd7045f7d849aa209bf60bc15ed82667414b70b71a7e184e"
+            "164280af00bf95a9ad3de41dcf19623610cfb30687a5a082a2458870eb33d649"
+            "b3fce3317e0362ee6175fb811c7fe3647ca21020673aedd23bf0470cd8735ce5"
+            "3a78082668e49a5132d1336a084360187fcff9227cdc0d7f205b2af2c88dc6bc"
+            "9a04b41f429fa9a386f58b8f216cbf6714a619597caa5a9bf668048d7135105a"
+            "9425cb46fe807a406459b067b716f5a47730974b207826716fe3f747fd74cd8c"
+            "025720b1003f27b2dea695f221971cd5af81a1a795001f125c285c150ddc9959"
+            "e9e0220a694556bc0a4b5aade032b2aa877c0da816ef32a84d77bbff4656e680"
+            "854e6961a417082bd46f5023c47cea09bba2145d415188e13803bb5e7fc19d39"
+            "819e59ca7fdc38051d969401d4c411dac00a47ab57527daa897a37759351b4b1"
+            "185b1496d79aa33229bea3c0e8dbcf1d7a64730928775c74c946181bb88e0a38"
+            "a165ebbdc7860b014c59894c7211bc23d39077e62944d3c48c434b737b1a64b5"
+            "f9a1646a18a5195a54603ffc280bf5ea1bf3d8cb4112aa82fab89e2633665597"
+            "bed30de6c8897a9613ea8c11736524a7086266523fbab887465494ebfc96fbe4"
+            "466775bc0d707e624bb3de700eaac9b37c2bc12b52aa2dfb73b7006bd956c0b9"
+            "324c47295e4e4c4cc47ba9b5a899694b38a64a29120684bf64c648211b18d017"
+            "ce34849c4a092b24bcb8960d870422ebd954bc7264ba95a727a31926f31c1238"
+            "1d0d051e1e04190b949322442845f3aa6fb17940442a9c1a183b2397dcc0770c"
+            "b2b4a07aaf0af52636778ac9949d2db42f3d036bc842b2f3b0b11d278ea025cc"
+            "16ac8b6986a9b18b3356790dbc821d0ac7b5eae965fcf14b0e202b00ec5d707c"
+            "45af52be2d9771bcc53b31f2525ab53f95a3b00242c11c494e2554438b0a4f68"
+            "194019181bbdb194910307bc19afae553dd666564332c078604fbd8bc4fdc92f"
+            "9201809ab495c1b535530bad2eb35753f91956b58bfbf62d1e33c861f7384633"
+            "4c4a2ac5576984ef1163a50454a3e4ce838898f225868189bdae5a03efd8001d"
+            "2a9f557cb6cd93a46fbb1379d81645b853cb98b47ba87a66fcbc0ada76506b8d"
+            "cd659042640ef616820ff20b26433399371dc4c50287349ca2763e3073865c5b"
+            "c9aaa1437b86b20b25b6f088bd6a4c9483842d7c65257cc42ad7c7b26f58c8ed"
+            "199b848b8c6f408d487637751888a95a449cab47b7a2ace6a82c7d4691052641"
+            "4ab149b11343b0ea32d969a508a81cf896b081087f4d52a2a9e077878a43e597"
+            "1d74863a7bf20037b2974703140ea8160a5ba72227c15bec5df171140198896d"
+            "31732a241f5b938ff233cba2dc82de91c6b493782b6163bc1282df855ee6a6a6"
+            "5975b33cb6a27d258bd317d04cef8eb557ba02d49471923cd60e9917966be90f"
+            "56d2d53fa3bc2629740abbd16720a9a7069a64de7a26328817d74a7c2f55a246"
+            "1b074bff8044445e11660b1b6b26df242b8fc02b9e8df538db17a639d7c46132"),
+        ek: &hex!("e9e0220a694556bc0a4b5aade032b2aa877c0da816ef32a84d77bbff4656e680"
+            "854e6961a417082bd46f5023c47cea09bba2145d415188e13803bb5e7fc19d39"
+            "819e59ca7fdc38051d969401d4c411dac00a47ab57527daa897a37759351b4b1"
+            "185b1496d79aa33229bea3c0e8dbcf1d7a64730928775c74c946181bb88e0a38"
+            "a165ebbdc7860b014c59894c7211bc23d39077e62944d3c48c434b737b1a64b5"
+            "f9a1646a18a5195a54603ffc280bf5ea1bf3d8cb4112aa82fab89e2633665597"
+            "bed30de6c8897a9613ea8c11736524a7086266523fbab887465494ebfc96fbe4"
+            "466775bc0d707e624bb3de700eaac9b37c2bc12b52aa2dfb73b7006bd956c0b9"
+            "324c47295e4e4c4cc47ba9b5a899694b38a64a29120684bf64c648211b18d017"
+            "ce34849c4a092b24bcb8960d870422ebd954bc7264ba95a727a31926f31c1238"
+            "1d0d051e1e04190b949322442845f3aa6fb17940442a9c1a183b2397dcc0770c"
+            "b2b4a07aaf0af52636778ac9949d2db42f3d036bc842b2f3b0b11d278ea025cc"
+            "16ac8b6986a9b18b3356790dbc821d0ac7b5eae965fcf14b0e202b00ec5d707c"
+            "45af52be2d9771bcc53b31f2525ab53f95a3b00242c11c494e2554438b0a4f68"
+            "194019181bbdb194910307bc19afae553dd666564332c078604fbd8bc4fdc92f"
+            "9201809ab495c1b535530bad2eb35753f91956b58bfbf62d1e33c861f7384633"
+            "4c4a2ac5576984ef1163a50454a3e4ce838898f225868189bdae5a03efd8001d"
+            "2a9f557cb6cd93a46fbb1379d81645b853cb98b47ba87a66fcbc0ada76506b8d"
+            "cd659042640ef616820ff20b26433399371dc4c50287349ca2763e3073865c5b"
+            "c9aaa1437b86b20b25b6f088bd6a4c9483842d7c65257cc42ad7c7b26f58c8ed"
+            "199b848b8c6f408d487637751888a95a449cab47b7a2ace6a82c7d4691052641"
+            "4ab149b11343b0ea32d969a508a81cf896b081087f4d52a2a9e077878a43e597"
+            "1d74863a7bf20037b2974703140ea8160a5ba72227c15bec5df171140198896d"
+            "31732a241f5b938ff233cba2dc82de91c6b493782b6163bc1282df855ee6a6a6"
+            "5975b33cb6a27d258bd317d04cef8eb557ba02d49471923cd60e9917966be90f"),
+    },
+    GenerateVector {
+        d: hex!("b178a99a29f8ee69a51991470983538a8acfeec3211ce09a295fc6cde0445354"),
+        z: hex!("e95e2ce56aaa837d86d9e74665c26774bca280f3c0069ab1273e1d46f19bcb33"),
+        dk: &hex!("9d7986cb3a949816470da05e8c13481c57bce748a9d2114ef4e74db9457a5d33"
+            "9028c55c803332ba6546fa5b7e7291ccee363fd3d1cf805017b28bbf0eb19552"
+            "ac2dfca09819541cab624c2ec18e1a4081c4b62f214866b57575cfcb6d5a6011"
+            "56195dd3a75cd86b464cd2aa83f674bca0a9d0cb225ed14bb2d657ed316b5806"
+            "39c44c2a368b4786632097647da330b3b081810b4a90edf7c692a137b2e9a919"
+            "0a30e58877d5fa9380d759ddcb04e440659024803625a814408ba66873adab85"
+            "93e41e9469a299a910d4f873a1925e79247978b70835dac94901bbf05464f5f4"
+            "526de7748a471232e18372f77e7ab59c168b6498864a745a4d92faacc0e89dc3"
+            "76ac2fb1bd7fd66b94c1b48ccaa0547886795bb85ee8718eba39595279943547"
+            "a7c5b59bb0a19972ab4d8c880664125286bfc646293d88a772f53c6023238ee7"
+            "45257bc86227c852955b9304b603376bded2a59dba63c1ca3b07021de10778a2"
+            "91102830a3c45005d09bc1cf1776108535d8d100731593f83caa4d1cb0352200"
+            "f6456dab9182b33479bb0117df619e0103984fe2015ef9a92d014905bca20179"
+            "6ae7e48866320bdbb5027b7b27327bbf68d65255ccc898cb3c50ec2dd9953c2c"
+            "b1c3bfa995b22616e455c018d5800c3ba35f66aaf687b3530aca3abb4e38a537"
+            "2b25034fa07421348ed485a04e7636fbf35791c8a5d1d88a7f940a44235a65e4"
+            "8131fb98496b5b97e7216176c93e0480033b8ae792a5a24c576d08ca010c835b"
+            "b5243651c01398af2661bc56833d31d76526582a9da29763bbcfb51a12c91560"
+            "8042c2b8ba35f647a95307ae6efc9dbc46256631c72a13c7a48c88ed98a13fc2"
+            "3b9a664bd6d2756c2c48e3a3bf2c48b27436c9a5057b00e43591585b3d2c8785"
+            "388f8d373cee9a567f2a57d184bd1840ac9142bac7d9b033759f1e275cabb469"
+            "1f6c7327b3a6783256d9330b3efac84077cc5217ac5965a0f6b62a791ab0e004"
+            "6a7b794271e4b0ea7514b223b895570b302097f3b61aa252bc13e89b510c308b"
+            "4712a8e234c11cbe86253a1e1c59d442344ed60236b9a5ed38398ca337a9e623"
+            "7186700707b9e435270ed460b3bc3772e8433161c3290a85836802dda588035a"
+            "47a25374700b9f274383e402b21e18853519c401714fc51caef504375be084a5"
+            "7b0c7c0a3d3577aed576b004e455d3773e2760590fe9a4cf729b30166071e655"
+            "de22cfb2164fef8657b330360ed52f33a2a9505361be5522404146dda1b1d67a"
+            "a74596a99fa04ed441c3401432c5cb6d329178b3e7b9f134cf3c0520560bc4d4"
+            "95cca1a33223d8012dbc5a1f76c0bd7995a52a95cdc9a47691802b7670f9ac59"
+            "39724f272b07fa613ab4575af4b7cbdc3216f9b70189a33bfac8a6eb348602b0"
+            "54256c46a7fa313786b1a372734349cc3dc3c1cc672f0cb014e42241319a8bc5"
+            "fa6c8cbb8c838139ec81425f32381cb01407ea66cfb0a4c905110e19ad224134"
+            "ac65c7e297b44a1c94effa6077aa946b5341a484399f624e7b920dc5d12698f5"
+            "0e9d2960dd6b80ca4b5fa452082ceac7cbca174af67f3c3b12aad47586483bb2"
+            "4a926e744430361380c4286eb37cd451335542443c034c852994710a10a761c1"
+            "e11bbe959b55238976ea4a257c3c4e528071460439572b37692592f91297f588"
+            "4f59438edf997dda56bcf0858f66475d8e52545f33211606598a694da3d047b2"
+            "e8bbc667b15225ce0c3b1f05ba46a7a2b60e608099c109c42382836c69504c53"
+            "d4b416391b33fa10c8d29aa58a2027a9d72b2f61149594cb924591ed6637edb6"
+            "c5c71b22ebaca49581876c59c92b79cb2fc08552331c584795f3a8c94e8c4ce7"
+            "487d03a46b8f22013b58215994c321ea1e3fca42655205ecd96060a4bde9158b"
+            "9ff2983036800a7b6ec751833553c2cf5215e78c481c396666104a72582a04bc"
+            "305b704c48e45dd97aa564a28dbbd51b53d69a65bca010e93a142a2043da80d3"
+            "fa36cff0747856c8c0f9bb301231325098416a094d434f2a967565209d86880b"
+            "7414bff60a1f96a6c994657316da681c3a922d4c3f308c6b1708abde9ccf9f01"
+            "ac02485d832bca97fa997db743d5faa9ff6541091ac972141169f1578381a374"
+            "a3beecb648cd9c363506d0e065320ec66c6a5633378748d9d3aaf0277b15187b"
+            "aed358190735c500ad6f0035c5d405482c4a5fe17f2b17112c136841e42e6c49"
+            "9f944430fc00c7f700101547c706d82eb5075ddf108bc3e70adb2454ef840fa4"
+            "918f29dc7a67e9b983453f01353f44660f1d539dab089778ecbfbe4866315c3c"
+            "bc9544e69808fe2857a3e6867c605c17da866974c5f8386ffaba704e51621851"
+            "b686fc0e437657e6b824e30961cca39a87db5ac8c97387d27d970390c0e4c455"
+            "f673dab920dfb084ebf1a0d022af4b76baf97983a928600336658fa2a82d020a"
+            "5e6621aa64bc375a25c93b5bd6e2876e609682d119a1d27fd4fb88cdd34f4dd9"
+            "170b1a6729f2571ea115c3c03a093298ea1587645aa5b7ca92b3b86c81f96a0b"
+            "8c83e14b5ede8b844df409777991a3d2cd65a77011000556457e4a176adecb85"
+            "a402009f276a7f829ba3cc4969a0096da80f1ef8050b9886b3fa912cccc8de95"
+            "a112475193275637b4a3968580fef4be559464eaf27daaa517cd7200059b0478"
+            "f78ea608a5ec56a90ef0cc9cc760dd923ee11ba20eb5acfcb157643671304296"
+            "00563ea1a341abf60e6f760609e2c0ca98c62a68ad19f67705d6b85143a4c8f0"
+            "9d5a8a095e1818ae94217c7256c4e420ec82479d27be3de28cfda098afa460b5"
+            "572e4ef694eed58e7e45c8265627b4580a97c22209c180f9c6552f58c2f5a78d"
+            "c00b09c8fb6155e3a483d4c225139f24ec0b8f9216b2341c0b2088efdb65ec49"
+            "94b9eb2cc50bbe37a30561a16b3481137d0643c6a69391101c44320798503aaf"
+            "125b26fc18e80386ef573b98ec7d2eb89b3ee48ce0186690680153d16791a216"
+            "ed4c4e4be07bb30baf33f05bd208681ea355cd38093b12a96ad52fd13859c218"
+            "257d2640fb485852582f8aab0d5f6c258a862843148bc8961135726bf337bb98"
+            "d41fa576a49ce78748545f609375d5050b3146002f4a2208d24652882f2cc94f"
+            "e9a776efc5732c681fd54007dbca311c2338f4f18d7882131603177a2c7eb774"
+            "99f82b0e7a4aa60b36907b4783a894474ff940aea928eeb77a01b3557a00a21a"
+            "437566f473c3b466e708679c5041012ace27b9acc96b53378cc8ec9bb42f382b"
+            "536f3f1427ff4366a441cd9d5349abb1072c9fcc7b096d0135e5c339f201c2bf"
+            "784534dc554b11f5ed6b57b334984ff12f3a6b09806b78e62755598b2a471a32"
+            "e95e2ce56aaa837d86d9e74665c26774bca280f3c0069ab1273e1d46f19bcb33"),
+        ek: &hex!("e11bbe959b55238976ea4a257c3c4e528071460439572b37692592f91297f588"
+            "4f59438edf997dda56bcf0858f66475d8e52545f33211606598a694da3d047b2"
+            "e8bbc667b15225ce0c3b1f05ba46a7a2b60e608099c109c42382836c69504c53"
+            "d4b416391b33fa10c8d29aa58a2027a9d72b2f61149594cb924591ed6637edb6"
+            "c5c71b22ebaca49581876c59c92b79cb2fc08552331c584795f3a8c94e8c4ce7"
+            "487d03a46b8f22013b58215994c321ea1e3fca42655205ecd96060a4bde9158b"
+            "9ff2983036800a7b6ec751833553c2cf5215e78c481c396666104a72582a04bc"
+            "305b704c48e45dd97aa564a28dbbd51b53d69a65bca010e93a142a2043da80d3"
+            "fa36cff0747856c8c0f9bb301231325098416a094d434f2a967565209d86880b"
+            "7414bff60a1f96a6c994657316da681c3a922d4c3f308c6b1708abde9ccf9f01"
+            "ac02485d832bca97fa997db743d5faa9ff6541091ac972141169f1578381a374"
+            "a3beecb648cd9c363506d0e065320ec66c6a5633378748d9d3aaf0277b15187b"
+            "aed358190735c500ad6f0035c5d405482c4a5fe17f2b17112c136841e42e6c49"
+            "9f944430fc00c7f700101547c706d82eb5075ddf108bc3e70adb2454ef840fa4"
+            "918f29dc7a67e9b983453f01353f44660f1d539dab089778ecbfbe4866315c3c"
+            "bc9544e69808fe2857a3e6867c605c17da866974c5f8386ffaba704e51621851"
+            "b686fc0e437657e6b824e30961cca39a87db5ac8c97387d27d970390c0e4c455"
+            "f673dab920dfb084ebf1a0d022af4b76baf97983a928600336658fa2a82d020a"
+            "5e6621aa64bc375a25c93b5bd6e2876e609682d119a1d27fd4fb88cdd34f4dd9"
+            "170b1a6729f2571ea115c3c03a093298ea1587645aa5b7ca92b3b86c81f96a0b"
+            "8c83e14b5ede8b844df409777991a3d2cd65a77011000556457e4a176adecb85"
+            "a402009f276a7f829ba3cc4969a0096da80f1ef8050b9886b3fa912cccc8de95"
+            "a112475193275637b4a3968580fef4be559464eaf27daaa517cd7200059b0478"
+            "f78ea608a5ec56a90ef0cc9cc760dd923ee11ba20eb5acfcb157643671304296"
+            "00563ea1a341abf60e6f760609e2c0ca98c62a68ad19f67705d6b85143a4c8f0"
+            "9d5a8a095e1818ae94217c7256c4e420ec82479d27be3de28cfda098afa460b5"
+            "572e4ef694eed58e7e45c8265627b4580a97c22209c180f9c6552f58c2f5a78d"
+            "c00b09c8fb6155e3a483d4c225139f24ec0b8f9216b2341c0b2088efdb65ec49"
+            "94b9eb2cc50bbe37a30561a16b3481137d0643c6a69391101c44320798503aaf"
+            "125b26fc18e80386ef573b98ec7d2eb89b3ee48ce0186690680153d16791a216"
+            "ed4c4e4be07bb30baf33f05bd208681ea355cd38093b12a96ad52fd13859c218"
+            "257d2640fb485852582f8aab0d5f6c258a862843148bc8961135726bf337bb98"
+            "d41fa576a49ce78748545f609375d5050b3146002f4a2208d24652882f2cc94f"
+            "e9a776efc5732c681fd54007dbca311c2338f4f18d7882131603177a2c7eb774"
+            "99f82b0e7a4aa60b36907b4783a894474ff940aea928eeb77a01b3557a00a21a"
+            "437566f473c3b466e708679c5041012ace27b9acc96b53378cc8ec9bb42f382b"
+            "536f3f1427ff4366a441cd9d5349abb1072c9fcc7b096d0135e5c339f201c2bf"),
+    },
+    GenerateVector {
+        d: hex!("fef0c1b24a298fa64079972b6e6ecd40d651d2c7742e8ef0954f0d3c4faf9470"),
+        z: hex!("0476848d8c528305638774a11da3080cba145ae6aaec5f925c1be3c5028b3d3e"),
+        dk: &hex!("34ba40f4498dea7796e73b38dbe035b3dc671d0657520c47c8d026dcfcc9c636"
+            "2723aa429d1359fd319148e28d362898799253b0448be715b35024bd69574a1f"
+            "6b652580060b64b442a5063a6251946c8e388c76108418562c27c80a91e078ae"
+            "81d38bfa39bb569193042a053b436fac4b1877373bb1a20dd77681bba1b86cd9"
+            "07660439a433b4b00c2a78293c3fa70f2c8b88d53280cca75d0c23b7f6387284"
+            "3739e7939f55a57cf7911a1a1977220492555c4da5ebb80f13268c81ce2c49cd"
+            "5e6a5820694fc2802b07e0817158c167c1a7d935691393ab31c4602302c9e9f4"
+            "b520f76a06997c51a59540d73569dc4cf7259b19bab2ab2b4675a41ebbf92dbc"
+            "09004b473998c144fb9894493a14813705d07895ce983981397bb2dab7aaa664"
+            "2af11e3664c4a6fb09bd1b3159b759da71cb233247695b861e5056d3d0c780b5"
+            "1c2158940d48b86d3766844c70f3d9551c1b3d042a24a879117a78740702a019"
+            "f25b4c43a4e4a32150131913b2a9a8755b4387934f0bb57989c6d4d7a2b13b6b"
+            "4cac488090172868b44a0aad18bc72dc7174c66299c6f564f3b96167b05020c0"
+            "ab7447323098255403128a76469be272107b3c8be7c068552732ea3a847c0aa1"
+            "72c68e8b06003554da3b527099cc9200029a64132015429d74903e70c57295af"
+            "cc0449472101800aba1f115105269c3eb32dbda6163ca056ce3366d84608599b"
+            "3876d9b765799aa7c5b5cc6363eb672696f43b188724c9355dbbecb208fc4d62"
+            "55648c575c21e305ee315349c848e6942ff71b8fc9ca8a31c2207cf65b27f3b2"
+            "d3d253704730d397ac99abaf94a068733c75b2886b91b14999120f0532988bdb"
+            "1c99a9630519c045e03a88324c6c127756f0298b5721178a0bc4a036ed50564e"
+            "85336d681eedd4a4c1b8c17b039fa06753d320a7a0d239ce0b3e5ed424789633"
+            "3d0cb9dc2ba32a8285919a4464c77b7b8432e226339f3668f7c15fd3b4c1357a"
+            "644b5b52bd3c87fc83c4daca60af2c65d6c603f064a159c4b6774056fb90214a"
+            "30953802a68d9b97011794fcdbcd9f088651667ca5c3571a5c135fd0cbd51304"
+            "3cb80ae7748a6205a2c9f346cee0988de5540d54a6fb3ba7823b3286825469b6"
+            "68a9d9946ef485ae6578bdb6a42d0cb95001a12f74994e219aa0b78557c299ad"
+            "60b06f40c249a3cd530071a5c98b79371510c7306f296c6e506ed5690cd2fc29"
+            "cc2354d9ba039ab8119c839771a156ec666bb4866f8cc7c0baa98d2ef9c93481"
+            "5c679c5983d7257a2912a40609b62573104335560a449a28a9431b83ce0c43c1"
+            "03073c88a7ebdb05f1b7901c34cdd408128d3b8d85033447d831a6cb5fd8969c"
+            "d2f272dc13566cd37fdc0a8eebe3926d88ca72c154007d6d7e88651fcb29de95"
+            "1064d7c473793ae9f8b50e92c13197082e728adb088508b7adc22b67d6704c27"
+            "47058b624c879b52f45b657f47a360350af30bb8e56606eef9166b71a6689b1e"
+            "e26a64cbe20b3d35ba6485c9de2a4316925abb858277ea66d3e56c44b12c2257"
+            "08fd184b5458ab6db25f9a00b0a4623abc8b9fba78a2ff3b255fa33ddefa26f9"
+            "96c9b9fb34d64976b9316e59193ea4647230677876d4cdced7bc037204a8c266"
+            "52979e90420d3e12ac82e5771cb53b8d66c9bd564e8fe923d6a13e3cb6c73906"
+            "a1459c6c9fd48a2711aab223b518e955ad77055d86749ea11af6d8cd393c03e2"
+            "d86aa3737eb823731d9832387ba0af82afdb5a73cb28044df9a689b99d2c5164"
+            "28222540b280ce9a43dea98c3c637f69892720c83fd16c7c38958404abcc09b5"
+            "4929ea45340643f2248a408aceb1a8730b459e6c689f7434ab2ca15cc3212780"
+            "5b67b3c053666605e297689ad2c1f0f082b38327b7208d0a84a690c727965ab6"
+            "0c3204c2601712005068d80230b7a729e14a0d1195c61701106842fe423ca19a"
+            "8cc8768d4273781a596428203c0564c3986c391a2c2e764640773c09f26027bf"
+            "8b32b566ca5b4789029c547d9004d845ce91809e6fd29623baa6a8198519a50a"
+            "0c7c9d645b0e11d4ade1539f904a8f5f8822e230004e29b20d826b4f0aae2dec"
+            "b7c896ba9725208fa45d9607717cc632160163a3f86c2ad063422aa2c3ac5074"
+            "ca8db31ac25638bbb09128f0018e2e04a6c832adc05831a5e4024ad3035cf72c"
+            "fe975438ba88c317215d1bb7a793c6e7a6848abb57d43655656155c9168dcc10"
+            "8ea2207c943a7723c32eeec38ec8b0290645a2dce47ba08c385743be74ba7dd7"
+            "538e86b178ab72bf44213c84e112cef26aee337087eb0975eb261641b215e888"
+            "e3925cc47807a642cfdb4275f1e197b75b94984499bdababd0222f5fda05e672"
+            "c9c1a50a08cb6b9e900f82f5568783571904cb8732c4791a95ee55c5c28a549c"
+            "85cc203198635a94a262c1598a383a8c87f70c91b8fa2eaac0a216fbc6ee9b69"
+            "6c56a4a0a14dc7a1032fb7bd77b636eed32214f15500c29b01cbbe734aa8bcac"
+            "633a4ba08bf441c0145f9738b584123321611e6b74ba1aa05b671323345868f6"
+            "68aaf11b13338876a8c00d9fb431e599078ccb27761c4b4463a306882e71e6cf"
+            "0c2117a64b375702a58d90354b7bb67336bf0be73b82f883717aa95d2943451b"
+            "38bb55b24cb4543be45bdd5758ce3c8632baa19c5b38674b98661b3b5fa2b09d"
+            "77377de580ae0376474c0f6da7cffde2aabb96c3e58aafebb75f3e37cfb7cb8e"
+            "fb5c1cabe977c506cb8d0b8897a71d9db488a73180d53c3165aca1eadcbf1352"
+            "0ff9a072de6ac4c4e69f28f609087b197f954834c4317f6523110b136ba0a65b"
+            "062c3db769f2617ac0c5536b307872fa87bfb1a6a6b46c7734b36fdc8d16319e"
+            "863283bd9bc05a68680b3592798144273ca4cbc36d7c5850fc3b18830bbdbac0"
+            "6a9e51817bb697ddd22fb3271269c637a8e07f5ca6cdaa15ab1847baf9f7533c"
+            "7acc8ea583dc4142c1c54eebfc6fa9e973bb672c1886823489cd6e2666b5830b"
+            "0b1c0b458bac07453bc6933f70e41a6f354f8d9743ce574d5fe16b1445c88f10"
+            "8a28c4867eab4011b161f3f2c40929a5b360094c111630b22505c9a296837de9"
+            "c0c23cb9ac01dc93f3d833cdfb43fd02afdad9310490373d8186a2acb1d3243d"
+            "d5271a20c6403dd14764206cc60410b67965a0010f6ab9429b7149fba32c65c4"
+            "304d107f4022c74ed90ee6c05137099c9e781127e284d8b8c17026699a212694"
+            "73b9e9741acd70942d620441231dd8f11f2fca1ed7c01549141cffea8a90fa58"
+            "46b77ec89b7ca030cfa6f145448acc07e46b2546719e201294e9a464d8bc07dc"
+            "21a01c00b655bfa6d342afa139d79c711c924d83a050b0b95a52d8a2b8d19928"
+            "7a24f99067ccc86d68536673eb0fcd634a21ca2f5d42aa8b5495b3ca9e1e1542"
+            "68d52d8686c9e663cef7926abe9676ba571ae3252eada85094e69084483ebaba"
+            "2d6db313f8d298822aa5cb16beb08c093ddaa05b96ccb1ac667656c4c1e18abc"
+            "85958fe336515a3d41e993ba9cbba05a77aa5037b169a99c2c3ced457120fabc"
+            "d12470e3272099e631324b51b90c74e650c0ea8800a37210fed12c0c9b109e09"
+            "372a2a5847b28e721bc39dc3ad0e6646b94338421c699d7c893e2378bd5000a9"
+            "39838ccca69362918808a68699689e6248d4521510fa77ede98af856b11c680c"
+            "be9c7d7537ad78aab98a952159e1a6dc2c52a43b64d2ecc2b9a49fb539256c96"
+            "9b2ea86d9fc40a2414b57c851c86583eea38a17597af150a4d06a88b8ecb3cde"
+            "0562a1db7b382b7f294896bc6783511bb52f39ababf6b2922652c20b4e8ee7a8"
+            "226bcb1b040b5b92591f5b5b6ba0589846629c92a8d7639573f383cd28984749"
+            "c9c3f8a479756b602aafc84869bfdc569ac866b31343c9e69ee5608a81214715"
+            "1176e94a2f2676309d743436987ceab72c07a3a3e79a67fa682bf557695ce566"
+            "23f11a19b671df67c9cdbc16fb5b8023a1185ed714651800c83c031ec1563b81"
+            "afe995175f675b7fc1c5b5e84edce429d71595fd82575cf75ee6b7bb97b55d30"
+            "15879727281527b45d8607f8f1c246a44b1516bbcb55791e840fff3bbbf8c70e"
+            "97c1bbc1e3948736aae8985896312d24fb20ddf73a99f7157f884bbac1c27ee5"
+            "1cea240a431396417254cee819abbc8da430c24878830ab08178e590f8463aa1"
+            "7139d91368b878ac71229fcecb3339823ab14c6739c2b7bb17749a02b1ec60c9"
+            "d2b5903ca2a81dc74226c707b39067dcd52709591c6babc8843aa7a1241e477b"
+            "ae1b7387b141621c504328b521c738380fa7074ef51987402ad7d22aa29b2785"
+            "7b6b8a610503315670823ea0259d67cc7a4b0ab65f89a712cbaa71e57c529184"
+            "e1dc198817490b45b97b0346327e5165e6c1c687bf47361cd4537631c6ad7809"
+            "547798c5dbbce8f158f5e03cd727079cb9e4b771f662592c3fac28cdbcb7a43f"
+            "0476848d8c528305638774a11da3080cba145ae6aaec5f925c1be3c5028b3d3e"),
+        ek: &hex!("fe975438ba88c317215d1bb7a793c6e7a6848abb57d43655656155c9168dcc10"
+            "8ea2207c943a7723c32eeec38ec8b0290645a2dce47ba08c385743be74ba7dd7"
+            "538e86b178ab72bf44213c84e112cef26aee337087eb0975eb261641b215e888"
+            "e3925cc47807a642cfdb4275f1e197b75b94984499bdababd0222f5fda05e672"
+            "c9c1a50a08cb6b9e900f82f5568783571904cb8732c4791a95ee55c5c28a549c"
+            "85cc203198635a94a262c1598a383a8c87f70c91b8fa2eaac0a216fbc6ee9b69"
+            "6c56a4a0a14dc7a1032fb7bd77b636eed32214f15500c29b01cbbe734aa8bcac"
+            "633a4ba08bf441c0145f9738b584123321611e6b74ba1aa05b671323345868f6"
+            "68aaf11b13338876a8c00d9fb431e599078ccb27761c4b4463a306882e71e6cf"
+            "0c2117a64b375702a58d90354b7bb67336bf0be73b82f883717aa95d2943451b"
+            "38bb55b24cb4543be45bdd5758ce3c8632baa19c5b38674b98661b3b5fa2b09d"
+            "77377de580ae0376474c0f6da7cffde2aabb96c3e58aafebb75f3e37cfb7cb8e"
+            "fb5c1cabe977c506cb8d0b8897a71d9db488a73180d53c3165aca1eadcbf1352"
+            "0ff9a072de6ac4c4e69f28f609087b197f954834c4317f6523110b136ba0a65b"
+            "062c3db769f2617ac0c5536b307872fa87bfb1a6a6b46c7734b36fdc8d16319e"
+            "863283bd9bc05a68680b3592798144273ca4cbc36d7c5850fc3b18830bbdbac0"
+            "6a9e51817bb697ddd22fb3271269c637a8e07f5ca6cdaa15ab1847baf9f7533c"
+            "7acc8ea583dc4142c1c54eebfc6fa9e973bb672c1886823489cd6e2666b5830b"
+            "0b1c0b458bac07453bc6933f70e41a6f354f8d9743ce574d5fe16b1445c88f10"
+            "8a28c4867eab4011b161f3f2c40929a5b360094c111630b22505c9a296837de9"
+            "c0c23cb9ac01dc93f3d833cdfb43fd02afdad9310490373d8186a2acb1d3243d"
+            "d5271a20c6403dd14764206cc60410b67965a0010f6ab9429b7149fba32c65c4"
+            "304d107f4022c74ed90ee6c05137099c9e781127e284d8b8c17026699a212694"
+            "73b9e9741acd70942d620441231dd8f11f2fca1ed7c01549141cffea8a90fa58"
+            "46b77ec89b7ca030cfa6f145448acc07e46b2546719e201294e9a464d8bc07dc"
+            "21a01c00b655bfa6d342afa139d79c711c924d83a050b0b95a52d8a2b8d19928"
+            "7a24f99067ccc86d68536673eb0fcd634a21ca2f5d42aa8b5495b3ca9e1e1542"
+            "68d52d8686c9e663cef7926abe9676ba571ae3252eada85094e69084483ebaba"
+            "2d6db313f8d298822aa5cb16beb08c093ddaa05b96ccb1ac667656c4c1e18abc"
+            "85958fe336515a3d41e993ba9cbba05a77aa5037b169a99c2c3ced457120fabc"
+            "d12470e3272099e631324b51b90c74e650c0ea8800a37210fed12c0c9b109e09"
+            "372a2a5847b28e721bc39dc3ad0e6646b94338421c699d7c893e2378bd5000a9"
+            "39838ccca69362918808a68699689e6248d4521510fa77ede98af856b11c680c"
+            "be9c7d7537ad78aab98a952159e1a6dc2c52a43b64d2ecc2b9a49fb539256c96"
+            "9b2ea86d9fc40a2414b57c851c86583eea38a17597af150a4d06a88b8ecb3cde"
+            "0562a1db7b382b7f294896bc6783511bb52f39ababf6b2922652c20b4e8ee7a8"
+            "226bcb1b040b5b92591f5b5b6ba0589846629c92a8d7639573f383cd28984749"
+            "c9c3f8a479756b602aafc84869bfdc569ac866b31343c9e69ee5608a81214715"
+            "1176e94a2f2676309d743436987ceab72c07a3a3e79a67fa682bf557695ce566"
+            "23f11a19b671df67c9cdbc16fb5b8023a1185ed714651800c83c031ec1563b81"
+            "afe995175f675b7fc1c5b5e84edce429d71595fd82575cf75ee6b7bb97b55d30"
+            "15879727281527b45d8607f8f1c246a44b1516bbcb55791e840fff3bbbf8c70e"
+            "97c1bbc1e3948736aae8985896312d24fb20ddf73a99f7157f884bbac1c27ee5"
+            "1cea240a431396417254cee819abbc8da430c24878830ab08178e590f8463aa1"
+            "7139d91368b878ac71229fcecb3339823ab14c6739c2b7bb17749a02b1ec60c9"
+            "d2b5903ca2a81dc74226c707b39067dcd52709591c6babc8843aa7a1241e477b"
+            "ae1b7387b141621c504328b521c738380fa7074ef51987402ad7d22aa29b2785"
+            "7b6b8a610503315670823ea0259d67cc7a4b0ab65f89a712cbaa71e57c529184"
+            "e1dc198817490b45b97b0346327e5165e6c1c687bf47361cd4537631c6ad7809"),
+    },
+];
+
+const ENC_VECTORS: [EncapsulateVector; 3] = [
+    EncapsulateVector {
+        ek: &hex!("57c3ba4cd781d8690b4c390d9a58b35d69a52d52cd19012a25e158a2c19b7547"
+            "0a039a05c59833c5d2a8280b33de95b60c1bb5c633af71138fb06428143712b7"
+            "a033ebaa3c0d6a841211204c67564bc516f34373b30470a1a5033b852a2c3a80"
+            "adfc8f8c1762fb06290e55759d466d67d27efd3bb8f65c7f1ad011b99ac696e0"
+            "ba78f456c1910330430da6cc9186cb219e47a8cefbc2be580552c30af1e8734c"
+            "572f4890c1ec14225b2ac641e6c23c94942984323fc54af9299a72e92d056837"
+            "c450545a8849417b40a3236862b407d09a7022dc544755155b23af0f306dac83"
+            "37347803412ccd57db4bebf97870d5b861dbac6c4a8d7fdbc926923faea171be"
+            "172354f54c11b68c9ff6c0fe0586932461168ba90188c86cbace804982e18812"
+            "16401166c65a556425ab2145516c7699031df56b14781ba244572947f63f7ce9"
+            "3562b10901780401e244174797cfe6ab5d5acafd07486d133630631e3e009fda"
+            "23c427f5b4c205643147c0ef17cc4a674c969177b50a372425adbe654a74caa8"
+            "87f87cdde492ec502705609b9e318ae6bb5ce4a9a282f12444779bbae0320d12"
+            "97d9cbbb876100030789c905215f386903a94ee68cbc977310cba02b79375c4a"
+            "8a5eec5569528a1002c08a909bca05f1323a34aef2081f6676939b826eb7748b"
+            "80d45a1ea15a6fb31963f618199630a2d3301af113def428bf107518fc3055f2"
+            "4d28a38cb4544348160bc6301dd3e76082039c12b29251e903c68c7bd841a453"
+            "5c5dd7c95aacfa8066f73ba8c9aca4d303a9f1b76fc03692a9bf1115b8518662"
+            "48661cfaf539c1399e5de03b86b02cb99b2da7d007ce3543d4bb94fb1cc99e40"
+            "cde871767529144da3297947555645333ef058377098b2a151d5110fce09217d"
+            "5a0055a208f3b583e2c7136772241b85b5bb367db43c07e54809b7a30c82d9af"
+            "7479ab37d849d7789139d6bcfaf83146775f6f1a632d17910f0a49f67472c18a"
+            "3439cc037ca42d7eb86109e3c47bd94ec7bc99d5b82a8f4c4d1d7247c84799a9"
+            "058d01842fed6957ceb48dd2029122aa8ff4388410e1b3bba276c2aa544ef991"
+            "973f15e7c59b894aab27f5ceaddde825333f44039b02e7bdec21fc0d8f9b3a22"),
+        m: hex!("2c87aa8b1176755474df763b2ae0463539e953e004c46a1183fd53cf84ef8103"),
+        k: hex!("a772df2de250ac7d896bbb820b57f2ae05f9a412ab55baa421d4af6dac62662a"),
+        c: &hex!("431a4f1b2d2c6c00f1690bbe482541ef3d563774daff83207f96de7e5e4a59d5"
+            "d936d9443ad422e645793e7a60a9b0a76cd672d20c69b82a5563df52d96f9a6c"
+            "dfc56fbd4fd8d5a8afeb2a09d92ec854094794b4ed2db381f04c68439608aa99"
+            "02a4d1689e2eb1e5f07a4a1c709262d7c2ff2f81f6eeaab2a86a41ba210eb1bf"
+            "8e75febccd1a15b4d7a7b60257c89d00bd81d39fcb8d1ce3278102595dd652f7"
+            "fb7d5584874f3327b174043b350ebd4d41fe08bd0e854d41cbb027c481da64dc"
+            "6151b88dececcf022ddac2e22736c147e0773294231c0589967154c526b0b7cd"
+            "d59568eeff5749a40cb100c60c6480897655d96e9f64d61684c0b3150646732c"
+            "19409fe565540a31894703cf0179cae85bc8c1a5732649836e48e676405b9591"
+            "b65ba25f9b489b9e5772aa1ed5a00143cb9f5449fd013457a3c13874cb58c75b"
+            "52c9b6a9ae495ccb504a89cb5f145695b921632fb85b0316b30d4ad17fef0862"
+            "d6b1e6ca6a611c8a6a7234b4362c5ca0ad9f7697687798cf624dc9f35fbb376e"
+            "09953156532a9033709df755b46cc6d83de3a111e19a76b361e0ef14c91db8d9"
+            "1c6c6d9e3e46f42291fd6cbf5cfd122716fb0675698e602ab39ee98e0d8145ee"
+            "baaa9374f5b3bb0df4d0fd83a40e0d25038c39e9bee01cf79c86f3086158d031"
+            "d5c5e86bc7e7eb16e622505f2888213884c0b5252289b11fce5bfeebfbef0a32"
+            "ceaf9c14c6250090028463db6f8d19684f541108fe934d88e7ef5cce9daebb32"
+            "700b9397691a684298c9bf1b7c22d1bcec3fcacfbb17f2ed2b98b85e6a8fe248"
+            "2996b5e099e9d0211cb9412614de87dc18d23613ed7f6c29cc37b727116dd901"
+            "c2817938c29fcd026089336addc09eca90de9a25a6374fee86bcdd06ae3daaf0"
+            "b1bc5b3b2790d4d9f759bef8ac743612a2bbf6e45de8b22efa61226625d4c39f"
+            "346b844c5ebec5355866c00b726cc1640cb237c34a20a7c603d251f46e6b3b0f"
+            "a71b3276835e3e9da5b9485e789614af49f1e9504db2528631fbe1cd7dbee851"
+            "64e4c099a27a4583e9247d078f8830b46874c1b010bf3cd90eb0774961f239ba"),
+    },
+    EncapsulateVector {
+        ek: &hex!("e11bbe959b55238976ea4a257c3c4e528071460439572b37692592f91297f588"
+            "4f59438edf997dda56bcf0858f66475d8e52545f33211606598a694da3d047b2"
+            "e8bbc667b15225ce0c3b1f05ba46a7a2b60e608099c109c42382836c69504c53"
+            "d4b416391b33fa10c8d29aa58a2027a9d72b2f61149594cb924591ed6637edb6"
+            "c5c71b22ebaca49581876c59c92b79cb2fc08552331c584795f3a8c94e8c4ce7"
+            "487d03a46b8f22013b58215994c321ea1e3fca42655205ecd96060a4bde9158b"
+            "9ff2983036800a7b6ec751833553c2cf5215e78c481c396666104a72582a04bc"
+            "305b704c48e45dd97aa564a28dbbd51b53d69a65bca010e93a142a2043da80d3"
+            "fa36cff0747856c8c0f9bb301231325098416a094d434f2a967565209d86880b"
+            "7414bff60a1f96a6c994657316da681c3a922d4c3f308c6b1708abde9ccf9f01"
+            "ac02485d832bca97fa997db743d5faa9ff6541091ac972141169f1578381a374"
+            "a3beecb648cd9c363506d0e065320ec66c6a5633378748d9d3aaf0277b15187b"
+            "aed358190735c500ad6f0035c5d405482c4a5fe17f2b17112c136841e42e6c49"
+            "9f944430fc00c7f700101547c706d82eb5075ddf108bc3e70adb2454ef840fa4"
+            "918f29dc7a67e9b983453f01353f44660f1d539dab089778ecbfbe4866315c3c"
+            "bc9544e69808fe2857a3e6867c605c17da866974c5f8386ffaba704e51621851"
+            "b686fc0e437657e6b824e30961cca39a87db5ac8c97387d27d970390c0e4c455"
+            "f673dab920dfb084ebf1a0d022af4b76baf97983a928600336658fa2a82d020a"
+            "5e6621aa64bc375a25c93b5bd6e2876e609682d119a1d27fd4fb88cdd34f4dd9"
+            "170b1a6729f2571ea115c3c03a093298ea1587645aa5b7ca92b3b86c81f96a0b"
+            "8c83e14b5ede8b844df409777991a3d2cd65a77011000556457e4a176adecb85"
+            "a402009f276a7f829ba3cc4969a0096da80f1ef8050b9886b3fa912cccc8de95"
+            "a112475193275637b4a3968580fef4be559464eaf27daaa517cd7200059b0478"
+            "f78ea608a5ec56a90ef0cc9cc760dd923ee11ba20eb5acfcb157643671304296"
+            "00563ea1a341abf60e6f760609e2c0ca98c62a68ad19f67705d6b85143a4c8f0"
+            "9d5a8a095e1818ae94217c7256c4e420ec82479d27be3de28cfda098afa460b5"
+            "572e4ef694eed58e7e45c8265627b4580a97c22209c180f9c6552f58c2f5a78d"
+            "c00b09c8fb6155e3a483d4c225139f24ec0b8f9216b2341c0b2088efdb65ec49"
+            "94b9eb2cc50bbe37a30561a16b3481137d0643c6a69391101c44320798503aaf"
+            "125b26fc18e80386ef573b98ec7d2eb89b3ee48ce0186690680153d16791a216"
+            "ed4c4e4be07bb30baf33f05bd208681ea355cd38093b12a96ad52fd13859c218"
+            "257d2640fb485852582f8aab0d5f6c258a862843148bc8961135726bf337bb98"
+            "d41fa576a49ce78748545f609375d5050b3146002f4a2208d24652882f2cc94f"
+            "e9a776efc5732c681fd54007dbca311c2338f4f18d7882131603177a2c7eb774"
+            "99f82b0e7a4aa60b36907b4783a894474ff940aea928eeb77a01b3557a00a21a"
+            "437566f473c3b466e708679c5041012ace27b9acc96b53378cc8ec9bb42f382b"
+            "536f3f1427ff4366a441cd9d5349abb1072c9fcc7b096d0135e5c339f201c2bf"),
+        m: hex!("c2a7558bb9ddfaddaec603fae4411098cb97973f1592a3c51cb366838b171a4d"),
+        k: hex!("23bb4ffacdd05b9b96b7509c9505bd49311bca7c1896969ce1dbeb1ab5a8d6a7"),
+        c: &hex!("e361155447e59b8b6f7e28de0a09b2e9716fcda2e91ce75141841835be9787c9"
+            "2194e4fa82dadb5375cf3cafe3550805affc53a05b8a5aa131a9e86e957f4ffd"
+            "1daab13254f4a9345213fc50238a243194887011335eeac7743f9c0ae495be5b"
+            "4caec27600156fb819843ad5fd90ad98ea374446a879497260fe76e5a8f4ff4a"
+            "ec29eda04182fb8931b3f41039d3d09f336842063ce62b7788f025b4218a6dde"
+            "d43be0e8dd9fb451b448fe6adc8c892bd309550698793e537acb8305feee6cd2"
+            "63336bd1dfe167ecddf9dea65c61b20b257856679f1178453b3dbfb0f239070c"
+            "8b892d875d65f12d4b1b7ef683f83b6b774e3fc82022f4352e5e267d611f0ae3"
+            "15396d9ab8378c115f42102909a5047b0ff3ad346c0070302a54a7e7994169e0"
+            "dedcb51f442c29d67fb9c0d6ec0bd017bbc6e4643473de607c161175a9138ff9"
+            "7b98a5855ce191e68c3adba64fcc681380476bb35bb300a1625443bf2c935a15"
+            "0e9aa7111f05e014ebb2884dfefd72e93f159d968aac33a76b02b1b6628ba9d3"
+            "c80004a7286a9ca2b0b1f2ec6b3bb5d65d5a8d0d88ed9c869caaa2b0719a4f4c"
+            "c35186662ddd819c693ea70502b22da96c80dac6af68b42a6bf4f8ee4100ae5b"
+            "c824556dcc5bb609bd6355a3d1b721aef783533a4dec367ca23a79b257ae1eae"
+            "8b922c732045651426105fd6af29b2df41103759b41d6ab56acddb6267742e9a"
+            "defcd074ab911190d070c87f39cfd1584c023f43a5a15875324037e909713b50"
+            "74f69162ffdb0d7c6252e150ab845945c399ced9933d5dd9d22bd6c10834ab9c"
+            "4e65d910a9514bdd36bfbbb69d07401caf26de2f741831d3943e90b8f60f83be"
+            "e47157d11946a854bfb3333202cb06a08ba7e0ba553ad9184b8041e30e0eb4e6"
+            "ea13525effdc2a6dba296784eb82d48d74101379bfe1b6e1a8eddffb91491428"
+            "2ecec8c03ff6f6ed70a29e4dd70a47272c16cdb24f64512ff462f2e2b1a37930"
+            "2db8ee54d2d1a506d0e3445ebdad78865b4bf88d0362d3eb5e2feb6b3109f218"
+            "b503dc64c12ab8ac55ffce5cd5cb33594c4faaa30a5bb6df74f537d9a4916544"
+            "9ed0642329791cffa7770a7a384add5330d3f825c4535c67a891210ac4b6a41a"
+            "fdbd65a9ffd1cf2b7633edc871a5f83a135cfeb3c2a77882bc252f9c10adb56a"
+            "2e055f5772e2edf43b4a3e3cc8afcaf75f94eb2c68b99ffeaa430628f4b9059f"
+            "ec39e4e92a50121655895e053bbf5900b5ad1bf7010f42f713927f41f81889fe"
+            "96e7d809bd85359a462f5e0b69a2f4cf2cbcad0d2f06fe57e54086868b37cb46"
+            "20089cdd66d398d737d82e56138a54354070792d272bf6c366735820b3526284"
+            "bd8ad38f826eb20a2a329dc2fe140acdb5e30baeec490b7c10dfb2a72f36e03e"
+            "a89ec4dccac97aa5ead824a25972013fdf44438285558c40ba00e78ed83c9ca3"
+            "de63dd80ebb407d75d33fca89f70ef80ef9aded1321276dc30d3ce938f77e614"
+            "cf1e14de5dbdd0fdd83cad9712d7102ccecee68c636f74a7d74766e6431ef78f"),
+    },
+    EncapsulateVector {
+        ek: &hex!("fe975438ba88c317215d1bb7a793c6e7a6848abb57d43655656155c9168dcc10"
+            "8ea2207c943a7723c32eeec38ec8b0290645a2dce47ba08c385743be74ba7dd7"
+            "538e86b178ab72bf44213c84e112cef26aee337087eb0975eb261641b215e888"
+            "e3925cc47807a642cfdb4275f1e197b75b94984499bdababd0222f5fda05e672"
+            "c9c1a50a08cb6b9e900f82f5568783571904cb8732c4791a95ee55c5c28a549c"
+            "85cc203198635a94a262c1598a383a8c87f70c91b8fa2eaac0a216fbc6ee9b69"
+            "6c56a4a0a14dc7a1032fb7bd77b636eed32214f15500c29b01cbbe734aa8bcac"
+            "633a4ba08bf441c0145f9738b584123321611e6b74ba1aa05b671323345868f6"
+            "68aaf11b13338876a8c00d9fb431e599078ccb27761c4b4463a306882e71e6cf"
+            "0c2117a64b375702a58d90354b7bb67336bf0be73b82f883717aa95d2943451b"
+            "38bb55b24cb4543be45bdd5758ce3c8632baa19c5b38674b98661b3b5fa2b09d"
+            "77377de580ae0376474c0f6da7cffde2aabb96c3e58aafebb75f3e37cfb7cb8e"
+            "fb5c1cabe977c506cb8d0b8897a71d9db488a73180d53c3165aca1eadcbf1352"
+            "0ff9a072de6ac4c4e69f28f609087b197f954834c4317f6523110b136ba0a65b"
+            "062c3db769f2617ac0c5536b307872fa87bfb1a6a6b46c7734b36fdc8d16319e"
+            "863283bd9bc05a68680b3592798144273ca4cbc36d7c5850fc3b18830bbdbac0"
+            "6a9e51817bb697ddd22fb3271269c637a8e07f5ca6cdaa15ab1847baf9f7533c"
+            "7acc8ea583dc4142c1c54eebfc6fa9e973bb672c1886823489cd6e2666b5830b"
+            "0b1c0b458bac07453bc6933f70e41a6f354f8d9743ce574d5fe16b1445c88f10"
+            "8a28c4867eab4011b161f3f2c40929a5b360094c111630b22505c9a296837de9"
+            "c0c23cb9ac01dc93f3d833cdfb43fd02afdad9310490373d8186a2acb1d3243d"
+            "d5271a20c6403dd14764206cc60410b67965a0010f6ab9429b7149fba32c65c4"
+            "304d107f4022c74ed90ee6c05137099c9e781127e284d8b8c17026699a212694"
+            "73b9e9741acd70942d620441231dd8f11f2fca1ed7c01549141cffea8a90fa58"
+            "46b77ec89b7ca030cfa6f145448acc07e46b2546719e201294e9a464d8bc07dc"
+            "21a01c00b655bfa6d342afa139d79c711c924d83a050b0b95a52d8a2b8d19928"
+            "7a24f99067ccc86d68536673eb0fcd634a21ca2f5d42aa8b5495b3ca9e1e1542"
+            "68d52d8686c9e663cef7926abe9676ba571ae3252eada85094e69084483ebaba"
+            "2d6db313f8d298822aa5cb16beb08c093ddaa05b96ccb1ac667656c4c1e18abc"
+            "85958fe336515a3d41e993ba9cbba05a77aa5037b169a99c2c3ced457120fabc"
+            "d12470e3272099e631324b51b90c74e650c0ea8800a37210fed12c0c9b109e09"
+            "372a2a5847b28e721bc39dc3ad0e6646b94338421c699d7c893e2378bd5000a9"
+            "39838ccca69362918808a68699689e6248d4521510fa77ede98af856b11c680c"
+            "be9c7d7537ad78aab98a952159e1a6dc2c52a43b64d2ecc2b9a49fb539256c96"
+            "9b2ea86d9fc40a2414b57c851c86583eea38a17597af150a4d06a88b8ecb3cde"
+            "0562a1db7b382b7f294896bc6783511bb52f39ababf6b2922652c20b4e8ee7a8"
+            "226bcb1b040b5b92591f5b5b6ba0589846629c92a8d7639573f383cd28984749"
+            "c9c3f8a479756b602aafc84869bfdc569ac866b31343c9e69ee5608a81214715"
+            "1176e94a2f2676309d743436987ceab72c07a3a3e79a67fa682bf557695ce566"
+            "23f11a19b671df67c9cdbc16fb5b8023a1185ed714651800c83c031ec1563b81"
+            "afe995175f675b7fc1c5b5e84edce429d71595fd82575cf75ee6b7bb97b55d30"
+            "15879727281527b45d8607f8f1c246a44b1516bbcb55791e840fff3bbbf8c70e"
+            "97c1bbc1e3948736aae8985896312d24fb20ddf73a99f7157f884bbac1c27ee5"
+            "1cea240a431396417254cee819abbc8da430c24878830ab08178e590f8463aa1"
+            "7139d91368b878ac71229fcecb3339823ab14c6739c2b7bb17749a02b1ec60c9"
+            "d2b5903ca2a81dc74226c707b39067dcd52709591c6babc8843aa7a1241e477b"
+            "ae1b7387b141621c504328b521c738380fa7074ef51987402ad7d22aa29b2785"
+            "7b6b8a610503315670823ea0259d67cc7a4b0ab65f89a712cbaa71e57c529184"
+            "e1dc198817490b45b97b0346327e5165e6c1c687bf47361cd4537631c6ad7809"),
+        m: hex!("f20743aabb5c2b76536c16fe2a35dced8404e90d04410c87a1703dace479f1b4"),
+        k: hex!("f1760ee975ae4cf655636c8eba09cc451acfd340cc7b987f185854f9e715fc08"),
+        c: &hex!("5086dc25f58b4646244f809eee6e4b53b9383f5d1541d35d26f268396c7ba6fb"
+            "772152bfb6852e7d1bbd38d649cbd960cb39e1f87ebc47fec0129f4501bb7c30"
+            "e0aed5ecd8929eff011329687488ff00083554aebe041183b1cc7098691ffcac"
+            "f0a940ce4484524a156a698cb4d7c00945fcb55992d62cf5db1311df8cfc2eb5"
+            "afaafed0b63ccd8ac000b9e6080b33311020bdfd5ad3d79aaeb8e182c9a0e354"
+            "19a7a82b552e48b2ade6416050d589e2d8422df16c00503ae28f3e607bf58a92"
+            "df200178e00920b49e5f2b26900c83b5040115dcc358cb2ab508e0445303d655"
+            "93746ff010b1a49d4e0690df8ca6f9dcdd7ff4b7a7362f43ec471b3ff55fdaf9"
+            "2120de7fb06b69390f5dd54e4fc0105ee32bdc4b4d7b719a1703352509f87dcd"
+            "11186143caa03ca48a8ed7fce8b443fd55d09f322028971ba7deb0667065da68"
+            "92c0c8671d5b63ed854f5e8c258665c475386736b177cac7f89632c2378707b3"
+            "40b2f0d68bbc347e6c6ae3c336a8599466f720fb399d0d3c03d557d65d0e2e59"
+            "423c8f279a3669b160c0ccf248eb7747659514c2ace0cbbd21889c36ac0935b3"
+            "c48f4b2999ace9e1bf25e47d5de336a95b8bf86d3f507e0d585e7bd8d76a8edf"
+            "5ad237c5c44af2710ce05d3ee483cb13e1981f6d81998032ee0efe4af7c43545"
+            "c4402c17f8e3a8fe690ec85838874bc3d7c7acd259eb4039ef32681b66b1f507"
+            "5fcd729d1e06c4d9bb9291d6d9ea58f3b2548c0eae1b71a69c8df94635dfcb61"
+            "c06349ce0cbe403c1a2382105aa6fecee1828c534610a5bb5058123d54eed287"
+            "3b39bc8036491bc9c4c316a99832cee678b58d2cc1697fdcc3bc21c174b4bd6f"
+            "013ba9def073d16418867127d25090cd2fa410310173867156b8869026a85b85"
+            "97b4f8b42c8e6c8a7cac851efa0c6d6e4bc3cfdd5f7355468aba4a989c409142"
+            "329f861af69f54876a8506964ab3aa7174360bad1c99ddcd08072edf5b8eb868"
+            "44cf4664b3269087e3e13da8fafe0bcab93890e792d4bc337b1d2ba415a96014"
+            "486e9f84d690fb8542c8097101ebca95db2a8152f68686b73801157bf85e5769"
+            "aa64ffba877e7240e5a5e7a189e1857084da7686c88477ac35b438b270205de4"
+            "2711d949914f29c0fc00d03596340915cfc97e7ffc89a364a60e9dc791aaa9fd"
+            "11c5df2e1f15e2cf93b5f5eaedd1ff7409d4d692502411c63b663f33ba51c7f5"
+            "e99e470022997f1cdc205e40083d87c212dc8b8510167ea10a4dd0adca2c2ea1"
+            "ad1dcf06e0acf0ecd92743e781dfdfa8959a98e0c51608b34e124c7c779844d6"
+            "0cedcfe1c3a731da0ac1aec6750ec14ceef00b3f0d5f52faaf17a0cffc681da6"
+            "56635f211f7a3442d5a6bc082a7a0b0d15afb594c5a4a3bd4e8be16981e86aa3"
+            "7a564fefc1599add828e547d5e1edf4608c504e8e499f6b87b0a6cb2a8003187"
+            "87e2b6ffe55a9c5477992b7beb734624ab5d2e550f2cc7f5efb7f5e5709f8e50"
+            "1b688edd5e9f080d06c4c09fc27ee0799c34512869c3b6394864c33b052333be"
+            "40360e311e6eb0e488a08e65da9992fd427882e67431114714ba5c3feb011a6d"
+            "36f1b9beb75ea6e83fe869eef54bc35bb104f33709b7e650db565f228eedc3c9"
+            "d54a9b0305e02fe5991447e3afa63ba3834b66bb5699a8b162bdf946b2316aa4"
+            "cec8f0d981d008d8d7673b06c1ebd9b6d0b7b01597207f483e54bf09e7636c0f"
+            "e7fadcdc73fcc883cb1c5dc09a0b2af91b3752253291ca10e02e8424d9b9ffc2"
+            "edd17c4e1a1ed089050ad3aa8641d0ced6ea786523c3a432052565c559c25a39"
+            "e2548b260d597815b95345825858aa5b84c19283af62dfa23c9f71df5a4e5db6"
+            "6f51e667d8d4851c2a19024e4dc675c528bd7894655579fd1c9575b7d37094a0"
+            "20b2a80150917b591aab61c869aee131fcde43c4387b4e7ddcea2cb6629cd9b6"
+            "c1d0b0ccd1c393a1d3911e6c0cc04b42934ac4e24e98bfb509c67ae1580ead68"
+            "ac8f1ed465a62e438380fa5b19a48bf57b5e3204b2f6579a737fc605f5092bda"
+            "0d27a8bbea6ea768a80a807d128aedc876ff2601a1891dea5814874c839ba674"
+            "d016f7c9380458b1c7ceae6f89c06da2bf40b3a3a296e2ded9645c97a5ae23fe"
+            "45f6aadb8a3e3f9e654702a97151eefb28813b34460b89e57e96ceb46d42cddf"
+            "2bc72809a89051b1b12a35e751d6c0661130e761e8c920e28bffd1d47d91e950"),
+    },
+];
+
+const DEC_VECTORS: [DecapsulateVector; 3] = [
+    DecapsulateVector {
+        dk: &hex!("739b8b1f6a5766310b061904021438bbd61a14f085fde029b53386ec3761aae7"
+            "7828fb19dedc50ddc1c32d3a444a154bf833a82571315a5655fd3b06651c20f7"
+            "b0378d782472680cfbb91d07d23110d8b158e3a8f3295d9297646de5814ce82a"
+            "e874a6454c61f65b0bed7977ce01c76999ce86756da26a2f5a093c05707316d9"
+            "2ac2b25e72c2160e604c90c339d8d03fa8454d40c707813305e60a48892c6d48"
+            "fb1819f8a092186249e5021d838bace819a1c7c72c5b45cf8398c52a4bedd3a5"
+            "9ea45e48f76ec3e0786871431372b36fa7b56397cb95e9200a990b71bb4e10ba"
+            "3e7c08c4a62828b549cce4f5087fd2a4eb7864fddc5f92a868d5bb70ab51510c"
+            "b043c145b74eb05f72e5964bc70500b0c893e01ea1ab77c4737f236aa8dd1c61"
+            "f2d7ae5c9310242812aa034aaad3bcdf224d42f34709c05f1238b218ec33dd80"
+            "4cb33132f3647cbd48a6f5f54a6866b24b5183eb6691d0a0846b346f877987f2"
+            "6b76beb305507b34f2e1cc95cb8185bb5e3560676f302752c3824e90bd84081b"
+            "28891fcdda313f063765594e41e0852a65551ff250b3188a2a61bc5f76503b39"
+            "16f06461ca011fe1d19153e26429b0373a4539df32474776becbeca608e9706f"
+            "65ad92422bbb28b6b9c96ca02b613d5c06eee31f9f32223300b222e3955cb05a"
+            "1babac602ab31e1874154a83d4bc54dcdc94d6d05adfbc6f46613d858145edeb"
+            "63f92044551bb9e8bb6307101bef5bc2685bcb04353c94843ec99428698999f9"
+            "9580336a903eb30808e301a18879b09b8b205968e6247f3a14b900935d808a7c"
+            "f8218515f3b8c13b540729aa741b42460b4117e0555619460a5a484c52150f3b"
+            "1b600095d2259f45567a3e034e1439af5ed3bc41619352775305292426184913"
+            "c253d95446fc4533fe1279f55a049bcc70ad5086ad72c740d53d585b4b932042"
+            "a2843b695c219fb13ca3c0cf6256ca82e58a98fb79880691271c1ac53038953c"
+            "0ec6079206c50c5d24cdfe80234391ba1762197df12ccd01047b743b9cab2dc8"
+            "abb861190ffc92c6a26b8650f6a00df4404db25424686a3455c64590511a343a"
+            "8c734c558b4274b439771213ed5bc628585f750b67d3c04296dc4bda5644ecd5"
+            "84ee870b46a704e5d19fa1376e66880973ab56a5160d8b99c2b4ba4e177c25b2"
+            "4a9bdf80ba1b48c27c88bd2cabbd988034f35ca792549e4d579b20a507454743"
+            "1197a3627b5d7c7573fa930652fa999a128915cc01737a0870a302a15138b385"
+            "48b59a65d397b6a0fcb66123ad9347c89cf5be90a9accf4a616f42369a8399cf"
+            "c46e9cc6a101fc353ff227c6f262b795a21570497a9bad2d7cbf2e877ade82c8"
+            "37ea68bc42438df79ec596318b57981c35968715a759f453835cbc27d7594cb8"
+            "65dd51152172b0a136be2a4a95ed0a8027e15fca373513e98a2a32c8e22462ff"
+            "b44643541e8c45835e6781d1998e97a94ef498cf41a08020b09f9cac36ac4228"
+            "894558f329610d5288208a9b44f614d826440a87b920e07c5470c101245ad778"
+            "5b30a4c54cd3a0ec088cf6b27d43104c63f05675682638fbaf16a8aab8910458"
+            "b5c1e1db60b3a206b7f27f50440bc22609584538b353700901988b4456dad60c"
+            "98fa54c2baabd2bb3879b72fbcd503153a68a5283e1760896e19cfdbbcb43e86"
+            "45889a2ae11c0f9c74aa38b5a813120a3ff508f753c828f64665f11ea61c54c2"
+            "a8b0aab8c3d83534af0938dc98598df015c837c69fb5530d7001f53b429c0abe"
+            "42306087e538dc27c8d6348096d7cf19412e3e32b3d253c823381983e088c1e0"
+            "a19f11510733aa07f61602da8dbd206a62b6a9e190ad8dd9c191526d26d727fd"
+            "1595465471d2f055b14174e7021c840a621722ac0b76c3f0e41e249c8aa1069c"
+            "5255388dbbc8a9d6bdcb3b8a2c1908f540745451706d08bec0902dbeca2ee989"
+            "0c2a241dde47c3626616b43c7d39f63f989c6277c30f70f97bad0989cb64acbe"
+            "b4a403d2a4b65706338986c9a079b18b7d43f119a514aa59066cdc3911cc3cc4"
+            "8bec5645927b2563a65137b50256b0f3da25d5e41092009d0c7bc5398bb7cf15"
+            "3ccb854eafb2144093739b7a7ccd03c8cba8b2c2892e57f99cbd03234f099752"
+            "d7b105ec45c1a153bb300db7753b3eb30b765956e837bbaa8c51abd8be54eb64"
+            "ab5229611b4b8ceed4d77ae3b39335a4a47a68aa38504250829874d0ecc6dff4"
+            "986d1ae58cfe537afd2e02b2c44ba088e7f3dbd21f9d1c02371785746e667263"
+            "b50d48c4855ae249ab4c80c9b493d22bf959cd6e85fd28c0cd9faeced70fa790"),
+        c: &hex!("af4a0068c37344d7e106a9cd39779cf8c767d5b81cb3443440dade14c2c48327"
+            "ba342845dc8c588bd25d9b55e27f331342e246bdd56d183ac1e0788420573f37"
+            "e229d4490bf417b3feda4220d776a529d96f7cddfa1ece8481515693548bb76f"
+            "5fb7daa65dfb13bf84dd1ca4e0ef7e49e0d1e8a3918e3ce9a784b37dc2a4d2d2"
+            "d311f706e505a1d93e552369e510a0d2ca347aecf88462e2fde0a6c9feb49506"
+            "a4fcebdf9877abd18ca81fea64115fb179e335e006905b722a5888f73d70ed77"
+            "26e0722f55240ee2c205d3e2b2c537a52df02bb693f4d721011735ce11b80743"
+            "7616ff213d71c3a732484c5bc3daedeaf4fbe02fc5e1b9b0cc82f6a6e51c0bca"
+            "4f6d66fe1982cfdc4804fbc8a767bc442e8534f63af3b0d057bc6c950b5e0a07"
+            "ae9f0385510ae78c11a2a11ebb849c13e177d982db7cd47d55f8086c14e5c1c3"
+            "e6c8204941bca37916fe2015c2990d00bd98641ce615045386812939d0bcb742"
+            "77fcb71834ed297eda87db1df91497854895f6cd8d94cfcb41edc1be151df914"
+            "73e37eba546e15627a6dbd583d9ba0ed34ee511a0831eba135682975a239f495"
+            "e309842babeef6f40e7bb4d6cd45095e3f91f9b61b86359cdd05d79bb72f5eaa"
+            "2eb9854e21a0194c468d9fe7e89f3c0e74f570f88b5b5015d4bb4c8bcb9ea643"
+            "edce57ba72114cf586cd6fb8a4ea5b050447e06bb713891e1346cd2276f2b863"
+            "96e4cf4241276fecceec79e738dcb4745e2dcd7d6898862529f24c3879ebee42"
+            "d8796792db7a7c86d28ab18f26b99b6cfc82f39fe7c0e570736d33a1770be820"
+            "03666e4f69b1186b3d55838a851b87beb6e72201fe3f8a25f4867ec63ee8feab"
+            "05fff608390fa48d186069809ca642a0e7448f3ad0c2d911b33c175c66a2eadf"
+            "ba79e2bc140c03bf9660ec28b41d071c0ffbc73ce2585f3de5e198f025e7afd4"
+            "bd1267e1f54e063496fef3e966c5b71a565bf9bcfc7034216b5bf621a2c2179b"
+            "52e9c992871bd66187396dc69f2394ab20c417002c830e705a08ac205c613804"
+            "af15ac2c65553b69d5d012cd90e50a40fddb8edaa83313b0089b5d6997193f21"),
+        k: hex!("be41c07ad05967abf700b8b2e6975e3c1e87503ca0b583e1d73046230e265e5a"),
+    },
+    DecapsulateVector {
+        dk: &hex!("9d7986cb3a949816470da05e8c13481c57bce748a9d2114ef4e74db9457a5d33"
+            "9028c55c803332ba6546fa5b7e7291ccee363fd3d1cf805017b28bbf0eb19552"
+            "ac2dfca09819541cab624c2ec18e1a4081c4b62f214866b57575cfcb6d5a6011"
+            "56195dd3a75cd86b464cd2aa83f674bca0a9d0cb225ed14bb2d657ed316b5806"
+            "39c44c2a368b4786632097647da330b3b081810b4a90edf7c692a137b2e9a919"
+            "0a30e58877d5fa9380d759ddcb04e440659024803625a814408ba66873adab85"
+            "93e41e9469a299a910d4f873a1925e79247978b70835dac94901bbf05464f5f4"
+            "526de7748a471232e18372f77e7ab59c168b6498864a745a4d92faacc0e89dc3"
+            "76ac2fb1bd7fd66b94c1b48ccaa0547886795bb85ee8718eba39595279943547"
+            "a7c5b59bb0a19972ab4d8c880664125286bfc646293d88a772f53c6023238ee7"
+            "45257bc86227c852955b9304b603376bded2a59dba63c1ca3b07021de10778a2"
+            "91102830a3c45005d09bc1cf1776108535d8d100731593f83caa4d1cb0352200"
+            "f6456dab9182b33479bb0117df619e0103984fe2015ef9a92d014905bca20179"
+            "6ae7e48866320bdbb5027b7b27327bbf68d65255ccc898cb3c50ec2dd9953c2c"
+            "b1c3bfa995b22616e455c018d5800c3ba35f66aaf687b3530aca3abb4e38a537"
+            "2b25034fa07421348ed485a04e7636fbf35791c8a5d1d88a7f940a44235a65e4"
+            "8131fb98496b5b97e7216176c93e0480033b8ae792a5a24c576d08ca010c835b"
+            "b5243651c01398af2661bc56833d31d76526582a9da29763bbcfb51a12c91560"
+            "8042c2b8ba35f647a95307ae6efc9dbc46256631c72a13c7a48c88ed98a13fc2"
+            "3b9a664bd6d2756c2c48e3a3bf2c48b27436c9a5057b00e43591585b3d2c8785"
+            "388f8d373cee9a567f2a57d184bd1840ac9142bac7d9b033759f1e275cabb469"
+            "1f6c7327b3a6783256d9330b3efac84077cc5217ac5965a0f6b62a791ab0e004"
+            "6a7b794271e4b0ea7514b223b895570b302097f3b61aa252bc13e89b510c308b"
+            "4712a8e234c11cbe86253a1e1c59d442344ed60236b9a5ed38398ca337a9e623"
+            "7186700707b9e435270ed460b3bc3772e8433161c3290a85836802dda588035a"
+            "47a25374700b9f274383e402b21e18853519c401714fc51caef504375be084a5"
+            "7b0c7c0a3d3577aed576b004e455d3773e2760590fe9a4cf729b30166071e655"
+            "de22cfb2164fef8657b330360ed52f33a2a9505361be5522404146dda1b1d67a"
+            "a74596a99fa04ed441c3401432c5cb6d329178b3e7b9f134cf3c0520560bc4d4"
+            "95cca1a33223d8012dbc5a1f76c0bd7995a52a95cdc9a47691802b7670f9ac59"
+            "39724f272b07fa613ab4575af4b7cbdc3216f9b70189a33bfac8a6eb348602b0"
+            "54256c46a7fa313786b1a372734349cc3dc3c1cc672f0cb014e42241319a8bc5"
+            "fa6c8cbb8c838139ec81425f32381cb01407ea66cfb0a4c905110e19ad224134"
+            "ac65c7e297b44a1c94effa6077aa946b5341a484399f624e7b920dc5d12698f5"
+            "0e9d2960dd6b80ca4b5fa452082ceac7cbca174af67f3c3b12aad47586483bb2"
+            "4a926e744430361380c4286eb37cd451335542443c034c852994710a10a761c1"
+            "e11bbe959b55238976ea4a257c3c4e528071460439572b37692592f91297f588"
+            "4f59438edf997dda56bcf0858f66475d8e52545f33211606598a694da3d047b2"
+            "e8bbc667b15225ce0c3b1f05ba46a7a2b60e608099c109c42382836c69504c53"
+            "d4b416391b33fa10c8d29aa58a2027a9d72b2f61149594cb924591ed6637edb6"
+            "c5c71b22ebaca49581876c59c92b79cb2fc08552331c584795f3a8c94e8c4ce7"
+            "487d03a46b8f22013b58215994c321ea1e3fca42655205ecd96060a4bde9158b"
+            "9ff2983036800a7b6ec751833553c2cf5215e78c481c396666104a72582a04bc"
+            "305b704c48e45dd97aa564a28dbbd51b53d69a65bca010e93a142a2043da80d3"
+            "fa36cff0747856c8c0f9bb301231325098416a094d434f2a967565209d86880b"
+            "7414bff60a1f96a6c994657316da681c3a922d4c3f308c6b1708abde9ccf9f01"
+            "ac02485d832bca97fa997db743d5faa9ff6541091ac972141169f1578381a374"
+            "a3beecb648cd9c363506d0e065320ec66c6a5633378748d9d3aaf0277b15187b"
+            "aed358190735c500ad6f0035c5d405482c4a5fe17f2b17112c136841e42e6c49"
+            "9f944430fc00c7f700101547c706d82eb5075ddf108bc3e70adb2454ef840fa4"
+            "918f29dc7a67e9b983453f01353f44660f1d539dab089778ecbfbe4866315c3c"
+            "bc9544e69808fe2857a3e6867c605c17da866974c5f8386ffaba704e51621851"
+            "b686fc0e437657e6b824e30961cca39a87db5ac8c97387d27d970390c0e4c455"
+            "f673dab920dfb084ebf1a0d022af4b76baf97983a928600336658fa2a82d020a"
+            "5e6621aa64bc375a25c93b5bd6e2876e609682d119a1d27fd4fb88cdd34f4dd9"
+            "170b1a6729f2571ea115c3c03a093298ea1587645aa5b7ca92b3b86c81f96a0b"
+            "8c83e14b5ede8b844df409777991a3d2cd65a77011000556457e4a176adecb85"
+            "a402009f276a7f829ba3cc4969a0096da80f1ef8050b9886b3fa912cccc8de95"
+            "a112475193275637b4a3968580fef4be559464eaf27daaa517cd7200059b0478"
+            "f78ea608a5ec56a90ef0cc9cc760dd923ee11ba20eb5acfcb157643671304296"
+            "00563ea1a341abf60e6f760609e2c0ca98c62a68ad19f67705d6b85143a4c8f0"
+            "9d5a8a095e1818ae94217c7256c4e420ec82479d27be3de28cfda098afa460b5"
+            "572e4ef694eed58e7e45c8265627b4580a97c22209c180f9c6552f58c2f5a78d"
+            "c00b09c8fb6155e3a483d4c225139f24ec0b8f9216b2341c0b2088efdb65ec49"
+            "94b9eb2cc50bbe37a30561a16b3481137d0643c6a69391101c44320798503aaf"
+            "125b26fc18e80386ef573b98ec7d2eb89b3ee48ce0186690680153d16791a216"
+            "ed4c4e4be07bb30baf33f05bd208681ea355cd38093b12a96ad52fd13859c218"
+            "257d2640fb485852582f8aab0d5f6c258a862843148bc8961135726bf337bb98"
+            "d41fa576a49ce78748545f609375d5050b3146002f4a2208d24652882f2cc94f"
+            "e9a776efc5732c681fd54007dbca311c2338f4f18d7882131603177a2c7eb774"
+            "99f82b0e7a4aa60b36907b4783a894474ff940aea928eeb77a01b3557a00a21a"
+            "437566f473c3b466e708679c5041012ace27b9acc96b53378cc8ec9bb42f382b"
+            "536f3f1427ff4366a441cd9d5349abb1072c9fcc7b096d0135e5c339f201c2bf"
+            "784534dc554b11f5ed6b57b334984ff12f3a6b09806b78e62755598b2a471a32"
+            "e95e2ce56aaa837d86d9e74665c26774bca280f3c0069ab1273e1d46f19bcb33"),
+        c: &hex!("e361155447e59b8b6f7e28de0a09b2e9716fcda2e91ce75141841835be9787c9"
+            "2194e4fa82dadb5375cf3cafe3550805affc53a05b8a5aa131a9e86e957f4ffd"
+            "1daab13254f4a9345213fc50238a243194887011335eeac7743f9c0ae495be5b"
+            "4caec27600156fb819843ad5fd90ad98ea374446a879497260fe76e5a8f4ff4a"
+            "ec29eda04182fb8931b3f41039d3d09f336842063ce62b7788f025b4218a6dde"
+            "d43be0e8dd9fb451b448fe6adc8c892bd309550698793e537acb8305feee6cd2"
+            "63336bd1dfe167ecddf9dea65c61b20b257856679f1178453b3dbfb0f239070c"
+            "8b892d875d65f12d4b1b7ef683f83b6b774e3fc82022f4352e5e267d611f0ae3"
+            "15396d9ab8378c115f42102909a5047b0ff3ad346c0070302a54a7e7994169e0"
+            "dedcb51f442c29d67fb9c0d6ec0bd017bbc6e4643473de607c161175a9138ff9"
+            "7b98a5855ce191e68c3adba64fcc681380476bb35bb300a1625443bf2c935a15"
+            "0e9aa7111f05e014ebb2884dfefd72e93f159d968aac33a76b02b1b6628ba9d3"
+            "c80004a7286a9ca2b0b1f2ec6b3bb5d65d5a8d0d88ed9c869caaa2b0719a4f4c"
+            "c35186662ddd819c693ea70502b22da96c80dac6af68b42a6bf4f8ee4100ae5b"
+            "c824556dcc5bb609bd6355a3d1b721aef783533a4dec367ca23a79b257ae1eae"
+            "8b922c732045651426105fd6af29b2df41103759b41d6ab56acddb6267742e9a"
+            "defcd074ab911190d070c87f39cfd1584c023f43a5a15875324037e909713b50"
+            "74f69162ffdb0d7c6252e150ab845945c399ced9933d5dd9d22bd6c10834ab9c"
+            "4e65d910a9514bdd36bfbbb69d07401caf26de2f741831d3943e90b8f60f83be"
+            "e47157d11946a854bfb3333202cb06a08ba7e0ba553ad9184b8041e30e0eb4e6"
+            "ea13525effdc2a6dba296784eb82d48d74101379bfe1b6e1a8eddffb91491428"
+            "2ecec8c03ff6f6ed70a29e4dd70a47272c16cdb24f64512ff462f2e2b1a37930"
+            "2db8ee54d2d1a506d0e3445ebdad78865b4bf88d0362d3eb5e2feb6b3109f218"
+            "b503dc64c12ab8ac55ffce5cd5cb33594c4faaa30a5bb6df74f537d9a4916544"
+            "9ed0642329791cffa7770a7a384add5330d3f825c4535c67a891210ac4b6a41a"
+            "fdbd65a9ffd1cf2b7633edc871a5f83a135cfeb3c2a77882bc252f9c10adb56a"
+            "2e055f5772e2edf43b4a3e3cc8afcaf75f94eb2c68b99ffeaa430628f4b9059f"
+            "ec39e4e92a50121655895e053bbf5900b5ad1bf7010f42f713927f41f81889fe"
+            "96e7d809bd85359a462f5e0b69a2f4cf2cbcad0d2f06fe57e54086868b37cb46"
+            "20089cdd66d398d737d82e56138a54354070792d272bf6c366735820b3526284"
+            "bd8ad38f826eb20a2a329dc2fe140acdb5e30baeec490b7c10dfb2a72f36e03e"
+            "a89ec4dccac97aa5ead824a25972013fdf44438285558c40ba00e78ed83c9ca3"
+            "de63dd80ebb407d75d33fca89f70ef80ef9aded1321276dc30d3ce938f77e614"
+            "cf1e14de5dbdd0fdd83cad9712d7102ccecee68c636f74a7d74766e6431ef78f"),
+        k: hex!("23bb4ffacdd05b9b96b7509c9505bd49311bca7c1896969ce1dbeb1ab5a8d6a7"),
+    },
+    DecapsulateVector {
+        dk: &hex!("34ba40f4498dea7796e73b38dbe035b3dc671d0657520c47c8d026dcfcc9c636"
+            "2723aa429d1359fd319148e28d362898799253b0448be715b35024bd69574a1f"
+            "6b652580060b64b442a5063a6251946c8e388c76108418562c27c80a91e078ae"
+            "81d38bfa39bb569193042a053b436fac4b1877373bb1a20dd77681bba1b86cd9"
+            "07660439a433b4b00c2a78293c3fa70f2c8b88d53280cca75d0c23b7f6387284"
+            "3739e7939f55a57cf7911a1a1977220492555c4da5ebb80f13268c81ce2c49cd"
+            "5e6a5820694fc2802b07e0817158c167c1a7d935691393ab31c4602302c9e9f4"
+            "b520f76a06997c51a59540d73569dc4cf7259b19bab2ab2b4675a41ebbf92dbc"
+            "09004b473998c144fb9894493a14813705d07895ce983981397bb2dab7aaa664"
+            "2af11e3664c4a6fb09bd1b3159b759da71cb233247695b861e5056d3d0c780b5"
+            "1c2158940d48b86d3766844c70f3d9551c1b3d042a24a879117a78740702a019"
+            "f25b4c43a4e4a32150131913b2a9a8755b4387934f0bb57989c6d4d7a2b13b6b"
+            "4cac488090172868b44a0aad18bc72dc7174c66299c6f564f3b96167b05020c0"
+            "ab7447323098255403128a76469be272107b3c8be7c068552732ea3a847c0aa1"
+            "72c68e8b06003554da3b527099cc9200029a64132015429d74903e70c57295af"
+            "cc0449472101800aba1f115105269c3eb32dbda6163ca056ce3366d84608599b"
+            "3876d9b765799aa7c5b5cc6363eb672696f43b188724c9355dbbecb208fc4d62"
+            "55648c575c21e305ee315349c848e6942ff71b8fc9ca8a31c2207cf65b27f3b2"
+            "d3d253704730d397ac99abaf94a068733c75b2886b91b14999120f0532988bdb"
+            "1c99a9630519c045e03a88324c6c127756f0298b5721178a0bc4a036ed50564e"
+            "85336d681eedd4a4c1b8c17b039fa06753d320a7a0d239ce0b3e5ed424789633"
+            "3d0cb9dc2ba32a8285919a4464c77b7b8432e226339f3668f7c15fd3b4c1357a"
+            "644b5b52bd3c87fc83c4daca60af2c65d6c603f064a159c4b6774056fb90214a"
+            "30953802a68d9b97011794fcdbcd9f088651667ca5c3571a5c135fd0cbd51304"
+            "3cb80ae7748a6205a2c9f346cee0988de5540d54a6fb3ba7823b3286825469b6"
+            "68a9d9946ef485ae6578bdb6a42d0cb95001a12f74994e219aa0b78557c299ad"
+            "60b06f40c249a3cd530071a5c98b79371510c7306f296c6e506ed5690cd2fc29"
+            "cc2354d9ba039ab8119c839771a156ec666bb4866f8cc7c0baa98d2ef9c93481"
+            "5c679c5983d7257a2912a40609b62573104335560a449a28a9431b83ce0c43c1"
+            "03073c88a7ebdb05f1b7901c34cdd408128d3b8d85033447d831a6cb5fd8969c"
+            "d2f272dc13566cd37fdc0a8eebe3926d88ca72c154007d6d7e88651fcb29de95"
+            "1064d7c473793ae9f8b50e92c13197082e728adb088508b7adc22b67d6704c27"
+            "47058b624c879b52f45b657f47a360350af30bb8e56606eef9166b71a6689b1e"
+            "e26a64cbe20b3d35ba6485c9de2a4316925abb858277ea66d3e56c44b12c2257"
+            "08fd184b5458ab6db25f9a00b0a4623abc8b9fba78a2ff3b255fa33ddefa26f9"
+            "96c9b9fb34d64976b9316e59193ea4647230677876d4cdced7bc037204a8c266"
+            "52979e90420d3e12ac82e5771cb53b8d66c9bd564e8fe923d6a13e3cb6c73906"
+            "a1459c6c9fd48a2711aab223b518e955ad77055d86749ea11af6d8cd393c03e2"
+            "d86aa3737eb823731d9832387ba0af82afdb5a73cb28044df9a689b99d2c5164"
+            "28222540b280ce9a43dea98c3c637f69892720c83fd16c7c38958404abcc09b5"
+            "4929ea45340643f2248a408aceb1a8730b459e6c689f7434ab2ca15cc3212780"
+            "5b67b3c053666605e297689ad2c1f0f082b38327b7208d0a84a690c727965ab6"
+            "0c3204c2601712005068d80230b7a729e14a0d1195c61701106842fe423ca19a"
+            "8cc8768d4273781a596428203c0564c3986c391a2c2e764640773c09f26027bf"
+            "8b32b566ca5b4789029c547d9004d845ce91809e6fd29623baa6a8198519a50a"
+            "0c7c9d645b0e11d4ade1539f904a8f5f8822e230004e29b20d826b4f0aae2dec"
+            "b7c896ba9725208fa45d9607717cc632160163a3f86c2ad063422aa2c3ac5074"
+            "ca8db31ac25638bbb09128f0018e2e04a6c832adc05831a5e4024ad3035cf72c"
+            "fe975438ba88c317215d1bb7a793c6e7a6848abb57d43655656155c9168dcc10"
+            "8ea2207c943a7723c32eeec38ec8b0290645a2dce47ba08c385743be74ba7dd7"
+            "538e86b178ab72bf44213c84e112cef26aee337087eb0975eb261641b215e888"
+            "e3925cc47807a642cfdb4275f1e197b75b94984499bdababd0222f5fda05e672"
+            "c9c1a50a08cb6b9e900f82f5568783571904cb8732c4791a95ee55c5c28a549c"
+            "85cc203198635a94a262c1598a383a8c87f70c91b8fa2eaac0a216fbc6ee9b69"
+            "6c56a4a0a14dc7a1032fb7bd77b636eed32214f15500c29b01cbbe734aa8bcac"
+            "633a4ba08bf441c0145f9738b584123321611e6b74ba1aa05b671323345868f6"
+            "68aaf11b13338876a8c00d9fb431e599078ccb27761c4b4463a306882e71e6cf"
+            "0c2117a64b375702a58d90354b7bb67336bf0be73b82f883717aa95d2943451b"
+            "38bb55b24cb4543be45bdd5758ce3c8632baa19c5b38674b98661b3b5fa2b09d"
+            "77377de580ae0376474c0f6da7cffde2aabb96c3e58aafebb75f3e37cfb7cb8e"
+            "fb5c1cabe977c506cb8d0b8897a71d9db488a73180d53c3165aca1eadcbf1352"
+            "0ff9a072de6ac4c4e69f28f609087b197f954834c4317f6523110b136ba0a65b"
+            "062c3db769f2617ac0c5536b307872fa87bfb1a6a6b46c7734b36fdc8d16319e"
+            "863283bd9bc05a68680b3592798144273ca4cbc36d7c5850fc3b18830bbdbac0"
+            "6a9e51817bb697ddd22fb3271269c637a8e07f5ca6cdaa15ab1847baf9f7533c"
+            "7acc8ea583dc4142c1c54eebfc6fa9e973bb672c1886823489cd6e2666b5830b"
+            "0b1c0b458bac07453bc6933f70e41a6f354f8d9743ce574d5fe16b1445c88f10"
+            "8a28c4867eab4011b161f3f2c40929a5b360094c111630b22505c9a296837de9"
+            "c0c23cb9ac01dc93f3d833cdfb43fd02afdad9310490373d8186a2acb1d3243d"
+            "d5271a20c6403dd14764206cc60410b67965a0010f6ab9429b7149fba32c65c4"
+            "304d107f4022c74ed90ee6c05137099c9e781127e284d8b8c17026699a212694"
+            "73b9e9741acd70942d620441231dd8f11f2fca1ed7c01549141cffea8a90fa58"
+            "46b77ec89b7ca030cfa6f145448acc07e46b2546719e201294e9a464d8bc07dc"
+            "21a01c00b655bfa6d342afa139d79c711c924d83a050b0b95a52d8a2b8d19928"
+            "7a24f99067ccc86d68536673eb0fcd634a21ca2f5d42aa8b5495b3ca9e1e1542"
+            "68d52d8686c9e663cef7926abe9676ba571ae3252eada85094e69084483ebaba"
+            "2d6db313f8d298822aa5cb16beb08c093ddaa05b96ccb1ac667656c4c1e18abc"
+            "85958fe336515a3d41e993ba9cbba05a77aa5037b169a99c2c3ced457120fabc"
+            "d12470e3272099e631324b51b90c74e650c0ea8800a37210fed12c0c9b109e09"
+            "372a2a5847b28e721bc39dc3ad0e6646b94338421c699d7c893e2378bd5000a9"
+            "39838ccca69362918808a68699689e6248d4521510fa77ede98af856b11c680c"
+            "be9c7d7537ad78aab98a952159e1a6dc2c52a43b64d2ecc2b9a49fb539256c96"
+            "9b2ea86d9fc40a2414b57c851c86583eea38a17597af150a4d06a88b8ecb3cde"
+            "0562a1db7b382b7f294896bc6783511bb52f39ababf6b2922652c20b4e8ee7a8"
+            "226bcb1b040b5b92591f5b5b6ba0589846629c92a8d7639573f383cd28984749"
+            "c9c3f8a479756b602aafc84869bfdc569ac866b31343c9e69ee5608a81214715"
+            "1176e94a2f2676309d743436987ceab72c07a3a3e79a67fa682bf557695ce566"
+            "23f11a19b671df67c9cdbc16fb5b8023a1185ed714651800c83c031ec1563b81"
+            "afe995175f675b7fc1c5b5e84edce429d71595fd82575cf75ee6b7bb97b55d30"
+            "15879727281527b45d8607f8f1c246a44b1516bbcb55791e840fff3bbbf8c70e"
+            "97c1bbc1e3948736aae8985896312d24fb20ddf73a99f7157f884bbac1c27ee5"
+            "1cea240a431396417254cee819abbc8da430c24878830ab08178e590f8463aa1"
+            "7139d91368b878ac71229fcecb3339823ab14c6739c2b7bb17749a02b1ec60c9"
+            "d2b5903ca2a81dc74226c707b39067dcd52709591c6babc8843aa7a1241e477b"
+            "ae1b7387b141621c504328b521c738380fa7074ef51987402ad7d22aa29b2785"
+            "7b6b8a610503315670823ea0259d67cc7a4b0ab65f89a712cbaa71e57c529184"
+            "e1dc198817490b45b97b0346327e5165e6c1c687bf47361cd4537631c6ad7809"
+            "547798c5dbbce8f158f5e03cd727079cb9e4b771f662592c3fac28cdbcb7a43f"
+            "0476848d8c528305638774a11da3080cba145ae6aaec5f925c1be3c5028b3d3e"),
+        c: &hex!("5086dc25f58b4646244f809eee6e4b53b9383f5d1541d35d26f268396c7ba6fb"
+            "772152bfb6852e7d1bbd38d649cbd960cb39e1f87ebc47fec0129f4501bb7c30"
+            "e0aed5ecd8929eff011329687488ff00083554aebe041183b1cc7098691ffcac"
+            "f0a940ce4484524a156a698cb4d7c00945fcb55992d62cf5db1311df8cfc2eb5"
+            "afaafed0b63ccd8ac000b9e6080b33311020bdfd5ad3d79aaeb8e182c9a0e354"
+            "19a7a82b552e48b2ade6416050d589e2d8422df16c00503ae28f3e607bf58a92"
+            "df200178e00920b49e5f2b26900c83b5040115dcc358cb2ab508e0445303d655"
+            "93746ff010b1a49d4e0690df8ca6f9dcdd7ff4b7a7362f43ec471b3ff55fdaf9"
+            "2120de7fb06b69390f5dd54e4fc0105ee32bdc4b4d7b719a1703352509f87dcd"
+            "11186143caa03ca48a8ed7fce8b443fd55d09f322028971ba7deb0667065da68"
+            "92c0c8671d5b63ed854f5e8c258665c475386736b177cac7f89632c2378707b3"
+            "40b2f0d68bbc347e6c6ae3c336a8599466f720fb399d0d3c03d557d65d0e2e59"
+            "423c8f279a3669b160c0ccf248eb7747659514c2ace0cbbd21889c36ac0935b3"
+            "c48f4b2999ace9e1bf25e47d5de336a95b8bf86d3f507e0d585e7bd8d76a8edf"
+            "5ad237c5c44af2710ce05d3ee483cb13e1981f6d81998032ee0efe4af7c43545"
+            "c4402c17f8e3a8fe690ec85838874bc3d7c7acd259eb4039ef32681b66b1f507"
+            "5fcd729d1e06c4d9bb9291d6d9ea58f3b2548c0eae1b71a69c8df94635dfcb61"
+            "c06349ce0cbe403c1a2382105aa6fecee1828c534610a5bb5058123d54eed287"
+            "3b39bc8036491bc9c4c316a99832cee678b58d2cc1697fdcc3bc21c174b4bd6f"
+            "013ba9def073d16418867127d25090cd2fa410310173867156b8869026a85b85"
+            "97b4f8b42c8e6c8a7cac851efa0c6d6e4bc3cfdd5f7355468aba4a989c409142"
+            "329f861af69f54876a8506964ab3aa7174360bad1c99ddcd08072edf5b8eb868"
+            "44cf4664b3269087e3e13da8fafe0bcab93890e792d4bc337b1d2ba415a96014"
+            "486e9f84d690fb8542c8097101ebca95db2a8152f68686b73801157bf85e5769"
+            "aa64ffba877e7240e5a5e7a189e1857084da7686c88477ac35b438b270205de4"
+            "2711d949914f29c0fc00d03596340915cfc97e7ffc89a364a60e9dc791aaa9fd"
+            "11c5df2e1f15e2cf93b5f5eaedd1ff7409d4d692502411c63b663f33ba51c7f5"
+            "e99e470022997f1cdc205e40083d87c212dc8b8510167ea10a4dd0adca2c2ea1"
+            "ad1dcf06e0acf0ecd92743e781dfdfa8959a98e0c51608b34e124c7c779844d6"
+            "0cedcfe1c3a731da0ac1aec6750ec14ceef00b3f0d5f52faaf17a0cffc681da6"
+            "56635f211f7a3442d5a6bc082a7a0b0d15afb594c5a4a3bd4e8be16981e86aa3"
+            "7a564fefc1599add828e547d5e1edf4608c504e8e499f6b87b0a6cb2a8003187"
+            "87e2b6ffe55a9c5477992b7beb734624ab5d2e550f2cc7f5efb7f5e5709f8e50"
+            "1b688edd5e9f080d06c4c09fc27ee0799c34512869c3b6394864c33b052333be"
+            "40360e311e6eb0e488a08e65da9992fd427882e67431114714ba5c3feb011a6d"
+            "36f1b9beb75ea6e83fe869eef54bc35bb104f33709b7e650db565f228eedc3c9"
+            "d54a9b0305e02fe5991447e3afa63ba3834b66bb5699a8b162bdf946b2316aa4"
+            "cec8f0d981d008d8d7673b06c1ebd9b6d0b7b01597207f483e54bf09e7636c0f"
+            "e7fadcdc73fcc883cb1c5dc09a0b2af91b3752253291ca10e02e8424d9b9ffc2"
+            "edd17c4e1a1ed089050ad3aa8641d0ced6ea786523c3a432052565c559c25a39"
+            "e2548b260d597815b95345825858aa5b84c19283af62dfa23c9f71df5a4e5db6"
+            "6f51e667d8d4851c2a19024e4dc675c528bd7894655579fd1c9575b7d37094a0"
+            "20b2a80150917b591aab61c869aee131fcde43c4387b4e7ddcea2cb6629cd9b6"
+            "c1d0b0ccd1c393a1d3911e6c0cc04b42934ac4e24e98bfb509c67ae1580ead68"
+            "ac8f1ed465a62e438380fa5b19a48bf57b5e3204b2f6579a737fc605f5092bda"
+            "0d27a8bbea6ea768a80a807d128aedc876ff2601a1891dea5814874c839ba674"
+            "d016f7c9380458b1c7ceae6f89c06da2bf40b3a3a296e2ded9645c97a5ae23fe"
+            "45f6aadb8a3e3f9e654702a97151eefb28813b34460b89e57e96ceb46d42cddf"
+            "2bc72809a89051b1b12a35e751d6c0661130e761e8c920e28bffd1d47d91e950"),
+        k: hex!("f1760ee975ae4cf655636c8eba09cc451acfd340cc7b987f185854f9e715fc08"),
+    },
+];
+
+const REJ_VECTORS: [DecapsulateVector; 3] = [
+    DecapsulateVector {
+        dk: &hex!("739b8b1f6a5766310b061904021438bbd61a14f085fde029b53386ec3761aae7"
+            "7828fb19dedc50ddc1c32d3a444a154bf833a82571315a5655fd3b06651c20f7"
+            "b0378d782472680cfbb91d07d23110d8b158e3a8f3295d9297646de5814ce82a"
+            "e874a6454c61f65b0bed7977ce01c76999ce86756da26a2f5a093c05707316d9"
+            "2ac2b25e72c2160e604c90c339d8d03fa8454d40c707813305e60a48892c6d48"
+            "fb1819f8a092186249e5021d838bace819a1c7c72c5b45cf8398c52a4bedd3a5"
+            "9ea45e48f76ec3e0786871431372b36fa7b56397cb95e9200a990b71bb4e10ba"
+            "3e7c08c4a62828b549cce4f5087fd2a4eb7864fddc5f92a868d5bb70ab51510c"
+            "b043c145b74eb05f72e5964bc70500b0c893e01ea1ab77c4737f236aa8dd1c61"
+            "f2d7ae5c9310242812aa034aaad3bcdf224d42f34709c05f1238b218ec33dd80"
+            "4cb33132f3647cbd48a6f5f54a6866b24b5183eb6691d0a0846b346f877987f2"
+            "6b76beb305507b34f2e1cc95cb8185bb5e3560676f302752c3824e90bd84081b"
+            "28891fcdda313f063765594e41e0852a65551ff250b3188a2a61bc5f76503b39"
+            "16f06461ca011fe1d19153e26429b0373a4539df32474776becbeca608e9706f"
+            "65ad92422bbb28b6b9c96ca02b613d5c06eee31f9f32223300b222e3955cb05a"
+            "1babac602ab31e1874154a83d4bc54dcdc94d6d05adfbc6f46613d858145edeb"
+            "63f92044551bb9e8bb6307101bef5bc2685bcb04353c94843ec99428698999f9"
+            "9580336a903eb30808e301a18879b09b8b205968e6247f3a14b900935d808a7c"
+            "f8218515f3b8c13b540729aa741b42460b4117e0555619460a5a484c52150f3b"
+            "1b600095d2259f45567a3e034e1439af5ed3bc41619352775305292426184913"
+            "c253d95446fc4533fe1279f55a049bcc70ad5086ad72c740d53d585b4b932042"
+            "a2843b695c219fb13ca3c0cf6256ca82e58a98fb79880691271c1ac53038953c"
+            "0ec6079206c50c5d24cdfe80234391ba1762197df12ccd01047b743b9cab2dc8"
+            "abb861190ffc92c6a26b8650f6a00df4404db25424686a3455c64590511a343a"
+            "8c734c558b4274b439771213ed5bc628585f750b67d3c04296dc4bda5644ecd5"
+            "84ee870b46a704e5d19fa1376e66880973ab56a5160d8b99c2b4ba4e177c25b2"
+            "4a9bdf80ba1b48c27c88bd2cabbd988034f35ca792549e4d579b20a507454743"
+            "1197a3627b5d7c7573fa930652fa999a128915cc01737a0870a302a15138b385"
+            "48b59a65d397b6a0fcb66123ad9347c89cf5be90a9accf4a616f42369a8399cf"
+            "c46e9cc6a101fc353ff227c6f262b795a21570497a9bad2d7cbf2e877ade82c8"
+            "37ea68bc42438df79ec596318b57981c35968715a759f453835cbc27d7594cb8"
+            "65dd51152172b0a136be2a4a95ed0a8027e15fca373513e98a2a32c8e22462ff"
+            "b44643541e8c45835e6781d1998e97a94ef498cf41a08020b09f9cac36ac4228"
+            "894558f329610d5288208a9b44f614d826440a87b920e07c5470c101245ad778"
+            "5b30a4c54cd3a0ec088cf6b27d43104c63f05675682638fbaf16a8aab8910458"
+            "b5c1e1db60b3a206b7f27f50440bc22609584538b353700901988b4456dad60c"
+            "98fa54c2baabd2bb3879b72fbcd503153a68a5283e1760896e19cfdbbcb43e86"
+            "45889a2ae11c0f9c74aa38b5a813120a3ff508f753c828f64665f11ea61c54c2"
+            "a8b0aab8c3d83534af0938dc98598df015c837c69fb5530d7001f53b429c0abe"
+            "42306087e538dc27c8d6348096d7cf19412e3e32b3d253c823381983e088c1e0"
+            "a19f11510733aa07f61602da8dbd206a62b6a9e190ad8dd9c191526d26d727fd"
+            "1595465471d2f055b14174e7021c840a621722ac0b76c3f0e41e249c8aa1069c"
+            "5255388dbbc8a9d6bdcb3b8a2c1908f540745451706d08bec0902dbeca2ee989"
+            "0c2a241dde47c3626616b43c7d39f63f989c6277c30f70f97bad0989cb64acbe"
+            "b4a403d2a4b65706338986c9a079b18b7d43f119a514aa59066cdc3911cc3cc4"
+            "8bec5645927b2563a65137b50256b0f3da25d5e41092009d0c7bc5398bb7cf15"
+            "3ccb854eafb2144093739b7a7ccd03c8cba8b2c2892e57f99cbd03234f099752"
+            "d7b105ec45c1a153bb300db7753b3eb30b765956e837bbaa8c51abd8be54eb64"
+            "ab5229611b4b8ceed4d77ae3b39335a4a47a68aa38504250829874d0ecc6dff4"
+            "986d1ae58cfe537afd2e02b2c44ba088e7f3dbd21f9d1c02371785746e667263"
+            "b50d48c4855ae249ab4c80c9b493d22bf959cd6e85fd28c0cd9faeced70fa790"),
+        c: &hex!("181af9b9f163a3049d97d8194b5c26355fc9f9dcbf120576abe44d71a0d99106"
+            "d5575db4d11189c0ebbd3b309e2b7fb86bb251febd0cb51b34728952de93adf1"
+            "52b9b239277904939374cd6389c99ed26dc9b7d7a48282e91c8f5175cad4cb0c"
+            "49fb5b1d5b32fa8904d48449e10a18a3996025b14ec7137c1049e86fa0d125b2"
+            "b5e1a6d1e8fa8d6dc1db67253cba44b2a6b5f1a5e87f05291a03dbd27570702c"
+            "b70c46e3e2d260984065c9691884d8866232688b7743f27f4692c93e94e10567"
+            "f6226cefe82030fd0a83547a8c0a1ff7ca812608760568ec6928e0e630d7e937"
+            "a96699147e34be04bbb7ebb55507eb5161d0e9db13be2215b721e54741c753e5"
+            "0a50b69c15970cb12c92c405a508aea3ce17b3a84e253614fa678b06f30e197c"
+            "ff1f412438e8004670d888de0df2c1139cc293ee3ab92fa98edd4abe6be6ca7d"
+            "cd72e72730ad9cafc093594d5742ddf5154b63173429dc5ef03399c9d1395108"
+            "c894b24359d0b1ff449fb8deb0cb5845c71475c5c9a24c7b775e07992149e4e5"
+            "7c7917c01aed402978a0f0a7b590cacfbf21a6cf590a7d0d0ad7a455982be32f"
+            "94ecb5429c24162e130d9213048a6563390f63fe8ffb1aaf2d51dfee477606a3"
+            "85f9a16a0098067ff789102aacd599ee9879ef4db7ebc0f1cc1e7de5d25b6765"
+            "15994a009691f4e2af311ff73d2beacbdb2b2872d27ee02f7050e81198f47fe7"
+            "5e0aa04281f09e5d5d198b08bec561328ecb8d94324ea6e1ad9fad7a8fbf4f4b"
+            "1b91cc150b0f6c8640af2e112b6eebbe8ea0338120093f58bbe8aeae7df7288f"
+            "ef1de8ab325a92b42c0aa8ed3a62ed9a86e946ffdddc87af8015b4ee64df5ff4"
+            "8e9411545a0078cf16d149fbf31938aeb11092727a452ca84babb7038939a8db"
+            "3fa1044817ec03832f6c0a76e2b616bb40b2e76211b91ff969dce0ad069fb729"
+            "471e95bace358e1bbff2bdabd7429857dbab72214547af1abcdb08896ee77f13"
+            "f2d75b1717446fca7df32efe1c2d09dcfd5bfaffd19ede7e502b6364abe32e84"
+            "4999b4477c998a9fb3c9babbe83c6ec613740c2b0475ecb732de51643868ebb7"),
+        k: hex!("98ed600ffd9e019f350e0a15d4695ba096ce2b32c375244f79a574da06b4b1bd"),
+    },
+    DecapsulateVector {
+        dk: &hex!("9d7986cb3a949816470da05e8c13481c57bce748a9d2114ef4e74db9457a5d33"
+            "9028c55c803332ba6546fa5b7e7291ccee363fd3d1cf805017b28bbf0eb19552"
+            "ac2dfca09819541cab624c2ec18e1a4081c4b62f214866b57575cfcb6d5a6011"
+            "56195dd3a75cd86b464cd2aa83f674bca0a9d0cb225ed14bb2d657ed316b5806"
+            "39c44c2a368b4786632097647da330b3b081810b4a90edf7c692a137b2e9a919"
+            "0a30e58877d5fa9380d759ddcb04e440659024803625a814408ba66873adab85"
+            "93e41e9469a299a910d4f873a1925e79247978b70835dac94901bbf05464f5f4"
+            "526de7748a471232e18372f77e7ab59c168b6498864a745a4d92faacc0e89dc3"
+            "76ac2fb1bd7fd66b94c1b48ccaa0547886795bb85ee8718eba39595279943547"
+            "a7c5b59bb0a19972ab4d8c880664125286bfc646293d88a772f53c6023238ee7"
+            "45257bc86227c852955b9304b603376bded2a59dba63c1ca3b07021de10778a2"
+            "91102830a3c45005d09bc1cf1776108535d8d100731593f83caa4d1cb0352200"
+            "f6456dab9182b33479bb0117df619e0103984fe2015ef9a92d014905bca20179"
+            "6ae7e48866320bdbb5027b7b27327bbf68d65255ccc898cb3c50ec2dd9953c2c"
+            "b1c3bfa995b22616e455c018d5800c3ba35f66aaf687b3530aca3abb4e38a537"
+            "2b25034fa07421348ed485a04e7636fbf35791c8a5d1d88a7f940a44235a65e4"
+            "8131fb98496b5b97e7216176c93e0480033b8ae792a5a24c576d08ca010c835b"
+            "b5243651c01398af2661bc56833d31d76526582a9da29763bbcfb51a12c91560"
+            "8042c2b8ba35f647a95307ae6efc9dbc46256631c72a13c7a48c88ed98a13fc2"
+            "3b9a664bd6d2756c2c48e3a3bf2c48b27436c9a5057b00e43591585b3d2c8785"
+            "388f8d373cee9a567f2a57d184bd1840ac9142bac7d9b033759f1e275cabb469"
+            "1f6c7327b3a6783256d9330b3efac84077cc5217ac5965a0f6b62a791ab0e004"
+            "6a7b794271e4b0ea7514b223b895570b302097f3b61aa252bc13e89b510c308b"
+            "4712a8e234c11cbe86253a1e1c59d442344ed60236b9a5ed38398ca337a9e623"
+            "7186700707b9e435270ed460b3bc3772e8433161c3290a85836802dda588035a"
+            "47a25374700b9f274383e402b21e18853519c401714fc51caef504375be084a5"
+            "7b0c7c0a3d3577aed576b004e455d3773e2760590fe9a4cf729b30166071e655"
+            "de22cfb2164fef8657b330360ed52f33a2a9505361be5522404146dda1b1d67a"
+            "a74596a99fa04ed441c3401432c5cb6d329178b3e7b9f134cf3c0520560bc4d4"
+            "95cca1a33223d8012dbc5a1f76c0bd7995a52a95cdc9a47691802b7670f9ac59"
+            "39724f272b07fa613ab4575af4b7cbdc3216f9b70189a33bfac8a6eb348602b0"
+            "54256c46a7fa313786b1a372734349cc3dc3c1cc672f0cb014e42241319a8bc5"
+            "fa6c8cbb8c838139ec81425f32381cb01407ea66cfb0a4c905110e19ad224134"
+            "ac65c7e297b44a1c94effa6077aa946b5341a484399f624e7b920dc5d12698f5"
+            "0e9d2960dd6b80ca4b5fa452082ceac7cbca174af67f3c3b12aad47586483bb2"
+            "4a926e744430361380c4286eb37cd451335542443c034c852994710a10a761c1"
+            "e11bbe959b55238976ea4a257c3c4e528071460439572b37692592f91297f588"
+            "4f59438edf997dda56bcf0858f66475d8e52545f33211606598a694da3d047b2"
+            "e8bbc667b15225ce0c3b1f05ba46a7a2b60e608099c109c42382836c69504c53"
+            "d4b416391b33fa10c8d29aa58a2027a9d72b2f61149594cb924591ed6637edb6"
+            "c5c71b22ebaca49581876c59c92b79cb2fc08552331c584795f3a8c94e8c4ce7"
+            "487d03a46b8f22013b58215994c321ea1e3fca42655205ecd96060a4bde9158b"
+            "9ff2983036800a7b6ec751833553c2cf5215e78c481c396666104a72582a04bc"
+            "305b704c48e45dd97aa564a28dbbd51b53d69a65bca010e93a142a2043da80d3"
+            "fa36cff0747856c8c0f9bb301231325098416a094d434f2a967565209d86880b"
+            "7414bff60a1f96a6c994657316da681c3a922d4c3f308c6b1708abde9ccf9f01"
+            "ac02485d832bca97fa997db743d5faa9ff6541091ac972141169f1578381a374"
+            "a3beecb648cd9c363506d0e065320ec66c6a5633378748d9d3aaf0277b15187b"
+            "aed358190735c500ad6f0035c5d405482c4a5fe17f2b17112c136841e42e6c49"
+            "9f944430fc00c7f700101547c706d82eb5075ddf108bc3e70adb2454ef840fa4"
+            "918f29dc7a67e9b983453f01353f44660f1d539dab089778ecbfbe4866315c3c"
+            "bc9544e69808fe2857a3e6867c605c17da866974c5f8386ffaba704e51621851"
+            "b686fc0e437657e6b824e30961cca39a87db5ac8c97387d27d970390c0e4c455"
+            "f673dab920dfb084ebf1a0d022af4b76baf97983a928600336658fa2a82d020a"
+            "5e6621aa64bc375a25c93b5bd6e2876e609682d119a1d27fd4fb88cdd34f4dd9"
+            "170b1a6729f2571ea115c3c03a093298ea1587645aa5b7ca92b3b86c81f96a0b"
+            "8c83e14b5ede8b844df409777991a3d2cd65a77011000556457e4a176adecb85"
+            "a402009f276a7f829ba3cc4969a0096da80f1ef8050b9886b3fa912cccc8de95"
+            "a112475193275637b4a3968580fef4be559464eaf27daaa517cd7200059b0478"
+            "f78ea608a5ec56a90ef0cc9cc760dd923ee11ba20eb5acfcb157643671304296"
+            "00563ea1a341abf60e6f760609e2c0ca98c62a68ad19f67705d6b85143a4c8f0"
+            "9d5a8a095e1818ae94217c7256c4e420ec82479d27be3de28cfda098afa460b5"
+            "572e4ef694eed58e7e45c8265627b4580a97c22209c180f9c6552f58c2f5a78d"
+            "c00b09c8fb6155e3a483d4c225139f24ec0b8f9216b2341c0b2088efdb65ec49"
+            "94b9eb2cc50bbe37a30561a16b3481137d0643c6a69391101c44320798503aaf"
+            "125b26fc18e80386ef573b98ec7d2eb89b3ee48ce0186690680153d16791a216"
+            "ed4c4e4be07bb30baf33f05bd208681ea355cd38093b12a96ad52fd13859c218"
+            "257d2640fb485852582f8aab0d5f6c258a862843148bc8961135726bf337bb98"
+            "d41fa576a49ce78748545f609375d5050b3146002f4a2208d24652882f2cc94f"
+            "e9a776efc5732c681fd54007dbca311c2338f4f18d7882131603177a2c7eb774"
+            "99f82b0e7a4aa60b36907b4783a894474ff940aea928eeb77a01b3557a00a21a"
+            "437566f473c3b466e708679c5041012ace27b9acc96b53378cc8ec9bb42f382b"
+            "536f3f1427ff4366a441cd9d5349abb1072c9fcc7b096d0135e5c339f201c2bf"
+            "784534dc554b11f5ed6b57b334984ff12f3a6b09806b78e62755598b2a471a32"
+            "e95e2ce56aaa837d86d9e74665c26774bca280f3c0069ab1273e1d46f19bcb33"),
+        c: &hex!("e361155447e59b8b6f7e28de0a09b2e9716fcda2e91ce75141841835be9787c9"
+            "2194e4fa82dadb5375cf3cafe3550805affc53a05b8a5aa131a9e86e957f4ffd"
+            "1daab13254f4a9345213fc50238a243194887011335eeac7743f9c0ae495be5b"
+            "4caec27600156fb819843ad5fd90ad98ea374446a879497260fe76e5a8f4ff4a"
+            "ec29eda04182fb8931b3f41039d3d09f336842063ce62b7788f025b4218a6dde"
+            "d43be0e8dd9fb451b448fe6adc8c892bd309550698793e537acb8305feee6cd2"
+            "63336bd1dfe167ecddf9dea65c61b20b257856679f1178453b3dbfb0f239070c"
+            "8b892d875d65f12d4b1b7ef683f83b6b774e3fc82022f4352e5e267d611f0ae3"
+            "15396d9ab8378c115f42102909a5047b0ff3ad346c0070302a54a7e7994169e0"
+            "dedcb51f442c29d67fb9c0d6ec0bd017bbc6e4643473de607c161175a9138ff9"
+            "7b98a5855ce191e68c3adba64fcc681380476bb35bb300a1625443bf2c935a15"
+            "0e9aa7111f05e014ebb2884dfefd72e93f159d968aac33a76b02b1b6628ba9d3"
+            "c80004a7286a9ca2b0b1f2ec6b3bb5d65d5a8d0d88ed9c869caaa2b0719a4f4c"
+            "c35186662ddd819c693ea70502b22da96c80dac6af68b42a6bf4f8ee4100ae5b"
+            "c824556dcc5bb609bd6355a3d1b721aef783533a4dec367ca23a79b257ae1eae"
+            "8b922c732045651426105fd6af29b2df41103759b41d6ab56acddb6267742e9a"
+            "defcd074ab911190d070c87f39cfd1584c023f43a5a15875324037e909713b50"
+            "75f69162ffdb0d7c6252e150ab845945c399ced9933d5dd9d22bd6c10834ab9c"
+            "4e65d910a9514bdd36bfbbb69d07401caf26de2f741831d3943e90b8f60f83be"
+            "e47157d11946a854bfb3333202cb06a08ba7e0ba553ad9184b8041e30e0eb4e6"
+            "ea13525effdc2a6dba296784eb82d48d74101379bfe1b6e1a8eddffb91491428"
+            "2ecec8c03ff6f6ed70a29e4dd70a47272c16cdb24f64512ff462f2e2b1a37930"
+            "2db8ee54d2d1a506d0e3445ebdad78865b4bf88d0362d3eb5e2feb6b3109f218"
+            "b503dc64c12ab8ac55ffce5cd5cb33594c4faaa30a5bb6df74f537d9a4916544"
+            "9ed0642329791cffa7770a7a384add5330d3f825c4535c67a891210ac4b6a41a"
+            "fdbd65a9ffd1cf2b7633edc871a5f83a135cfeb3c2a77882bc252f9c10adb56a"
+            "2e055f5772e2edf43b4a3e3cc8afcaf75f94eb2c68b99ffeaa430628f4b9059f"
+            "ec39e4e92a50121655895e053bbf5900b5ad1bf7010f42f713927f41f81889fe"
+            "96e7d809bd85359a462f5e0b69a2f4cf2cbcad0d2f06fe57e54086868b37cb46"
+            "20089cdd66d398d737d82e56138a54354070792d272bf6c366735820b3526284"
+            "bd8ad38f826eb20a2a329dc2fe140acdb5e30baeec490b7c10dfb2a72f36e03e"
+            "a89ec4dccac97aa5ead824a25972013fdf44438285558c40ba00e78ed83c9ca3"
+            "de63dd80ebb407d75d33fca89f70ef80ef9aded1321276dc30d3ce938f77e614"
+            "cf1e14de5dbdd0fdd83cad9712d7102ccecee68c636f74a7d74766e6431ef78f"),
+        k: hex!("3901f29439946ca1f3f7f85675f08ae866f3d6bfb2e758492f9be0f9c84d9612"),
+    },
+    DecapsulateVector {
+        dk: &hex!("34ba40f4498dea7796e73b38dbe035b3dc671d0657520c47c8d026dcfcc9c636"
+            "2723aa429d1359fd319148e28d362898799253b0448be715b35024bd69574a1f"
+            "6b652580060b64b442a5063a6251946c8e388c76108418562c27c80a91e078ae"
+            "81d38bfa39bb569193042a053b436fac4b1877373bb1a20dd77681bba1b86cd9"
+            "07660439a433b4b00c2a78293c3fa70f2c8b88d53280cca75d0c23b7f6387284"
+            "3739e7939f55a57cf7911a1a1977220492555c4da5ebb80f13268c81ce2c49cd"
+            "5e6a5820694fc2802b07e0817158c167c1a7d935691393ab31c4602302c9e9f4"
+            "b520f76a06997c51a59540d73569dc4cf7259b19bab2ab2b4675a41ebbf92dbc"
+            "09004b473998c144fb9894493a14813705d07895ce983981397bb2dab7aaa664"
+            "2af11e3664c4a6fb09bd1b3159b759da71cb233247695b861e5056d3d0c780b5"
+            "1c2158940d48b86d3766844c70f3d9551c1b3d042a24a879117a78740702a019"
+            "f25b4c43a4e4a32150131913b2a9a8755b4387934f0bb57989c6d4d7a2b13b6b"
+            "4cac488090172868b44a0aad18bc72dc7174c66299c6f564f3b96167b05020c0"
+            "ab7447323098255403128a76469be272107b3c8be7c068552732ea3a847c0aa1"
+            "72c68e8b06003554da3b527099cc9200029a64132015429d74903e70c57295af"
+            "cc0449472101800aba1f115105269c3eb32dbda6163ca056ce3366d84608599b"
+            "3876d9b765799aa7c5b5cc6363eb672696f43b188724c9355dbbecb208fc4d62"
+            "55648c575c21e305ee315349c848e6942ff71b8fc9ca8a31c2207cf65b27f3b2"
+            "d3d253704730d397ac99abaf94a068733c75b2886b91b14999120f0532988bdb"
+            "1c99a9630519c045e03a88324c6c127756f0298b5721178a0bc4a036ed50564e"
+            "85336d681eedd4a4c1b8c17b039fa06753d320a7a0d239ce0b3e5ed424789633"
+            "3d0cb9dc2ba32a8285919a4464c77b7b8432e226339f3668f7c15fd3b4c1357a"
+            "644b5b52bd3c87fc83c4daca60af2c65d6c603f064a159c4b6774056fb90214a"
+            "30953802a68d9b97011794fcdbcd9f088651667ca5c3571a5c135fd0cbd51304"
+            "3cb80ae7748a6205a2c9f346cee0988de5540d54a6fb3ba7823b3286825469b6"
+            "68a9d9946ef485ae6578bdb6a42d0cb95001a12f74994e219aa0b78557c299ad"
+            "60b06f40c249a3cd530071a5c98b79371510c7306f296c6e506ed5690cd2fc29"
+            "cc2354d9ba039ab8119c839771a156ec666bb4866f8cc7c0baa98d2ef9c93481"
+            "5c679c5983d7257a2912a40609b62573104335560a449a28a9431b83ce0c43c1"
+            "03073c88a7ebdb05f1b7901c34cdd408128d3b8d85033447d831a6cb5fd8969c"
+            "d2f272dc13566cd37fdc0a8eebe3926d88ca72c154007d6d7e88651fcb29de95"
+            "1064d7c473793ae9f8b50e92c13197082e728adb088508b7adc22b67d6704c27"
+            "47058b624c879b52f45b657f47a360350af30bb8e56606eef9166b71a6689b1e"
+            "e26a64cbe20b3d35ba6485c9de2a4316925abb858277ea66d3e56c44b12c2257"
+            "08fd184b5458ab6db25f9a00b0a4623abc8b9fba78a2ff3b255fa33ddefa26f9"
+            "96c9b9fb34d64976b9316e59193ea4647230677876d4cdced7bc037204a8c266"
+            "52979e90420d3e12ac82e5771cb53b8d66c9bd564e8fe923d6a13e3cb6c73906"
+            "a1459c6c9fd48a2711aab223b518e955ad77055d86749ea11af6d8cd393c03e2"
+            "d86aa3737eb823731d9832387ba0af82afdb5a73cb28044df9a689b99d2c5164"
+            "28222540b280ce9a43dea98c3c637f69892720c83fd16c7c38958404abcc09b5"
+            "4929ea45340643f2248a408aceb1a8730b459e6c689f7434ab2ca15cc3212780"
+            "5b67b3c053666605e297689ad2c1f0f082b38327b7208d0a84a690c727965ab6"
+            "0c3204c2601712005068d80230b7a729e14a0d1195c61701106842fe423ca19a"
+            "8cc8768d4273781a596428203c0564c3986c391a2c2e764640773c09f26027bf"
+            "8b32b566ca5b4789029c547d9004d845ce91809e6fd29623baa6a8198519a50a"
+            "0c7c9d645b0e11d4ade1539f904a8f5f8822e230004e29b20d826b4f0aae2dec"
+            "b7c896ba9725208fa45d9607717cc632160163a3f86c2ad063422aa2c3ac5074"
+            "ca8db31ac25638bbb09128f0018e2e04a6c832adc05831a5e4024ad3035cf72c"
+            "fe975438ba88c317215d1bb7a793c6e7a6848abb57d43655656155c9168dcc10"
+            "8ea2207c943a7723c32eeec38ec8b0290645a2dce47ba08c385743be74ba7dd7"
+            "538e86b178ab72bf44213c84e112cef26aee337087eb0975eb261641b215e888"
+            "e3925cc47807a642cfdb4275f1e197b75b94984499bdababd0222f5fda05e672"
+            "c9c1a50a08cb6b9e900f82f5568783571904cb8732c4791a95ee55c5c28a549c"
+            "85cc203198635a94a262c1598a383a8c87f70c91b8fa2eaac0a216fbc6ee9b69"
+            "6c56a4a0a14dc7a1032fb7bd77b636eed32214f15500c29b01cbbe734aa8bcac"
+            "633a4ba08bf441c0145f9738b584123321611e6b74ba1aa05b671323345868f6"
+            "68aaf11b13338876a8c00d9fb431e599078ccb27761c4b4463a306882e71e6cf"
+            "0c2117a64b375702a58d90354b7bb67336bf0be73b82f883717aa95d2943451b"
+            "38bb55b24cb4543be45bdd5758ce3c8632baa19c5b38674b98661b3b5fa2b09d"
+            "77377de580ae0376474c0f6da7cffde2aabb96c3e58aafebb75f3e37cfb7cb8e"
+            "fb5c1cabe977c506cb8d0b8897a71d9db488a73180d53c3165aca1eadcbf1352"
+            "0ff9a072de6ac4c4e69f28f609087b197f954834c4317f6523110b136ba0a65b"
+            "062c3db769f2617ac0c5536b307872fa87bfb1a6a6b46c7734b36fdc8d16319e"
+            "863283bd9bc05a68680b3592798144273ca4cbc36d7c5850fc3b18830bbdbac0"
+            "6a9e51817bb697ddd22fb3271269c637a8e07f5ca6cdaa15ab1847baf9f7533c"
+            "7acc8ea583dc4142c1c54eebfc6fa9e973bb672c1886823489cd6e2666b5830b"
+            "0b1c0b458bac07453bc6933f70e41a6f354f8d9743ce574d5fe16b1445c88f10"
+            "8a28c4867eab4011b161f3f2c40929a5b360094c111630b22505c9a296837de9"
+            "c0c23cb9ac01dc93f3d833cdfb43fd02afdad9310490373d8186a2acb1d3243d"
+            "d5271a20c6403dd14764206cc60410b67965a0010f6ab9429b7149fba32c65c4"
+            "304d107f4022c74ed90ee6c05137099c9e781127e284d8b8c17026699a212694"
+            "73b9e9741acd70942d620441231dd8f11f2fca1ed7c01549141cffea8a90fa58"
+            "46b77ec89b7ca030cfa6f145448acc07e46b2546719e201294e9a464d8bc07dc"
+            "21a01c00b655bfa6d342afa139d79c711c924d83a050b0b95a52d8a2b8d19928"
+            "7a24f99067ccc86d68536673eb0fcd634a21ca2f5d42aa8b5495b3ca9e1e1542"
+            "68d52d8686c9e663cef7926abe9676ba571ae3252eada85094e69084483ebaba"
+            "2d6db313f8d298822aa5cb16beb08c093ddaa05b96ccb1ac667656c4c1e18abc"
+            "85958fe336515a3d41e993ba9cbba05a77aa5037b169a99c2c3ced457120fabc"
+            "d12470e3272099e631324b51b90c74e650c0ea8800a37210fed12c0c9b109e09"
+            "372a2a5847b28e721bc39dc3ad0e6646b94338421c699d7c893e2378bd5000a9"
+            "39838ccca69362918808a68699689e6248d4521510fa77ede98af856b11c680c"
+            "be9c7d7537ad78aab98a952159e1a6dc2c52a43b64d2ecc2b9a49fb539256c96"
+            "9b2ea86d9fc40a2414b57c851c86583eea38a17597af150a4d06a88b8ecb3cde"
+            "0562a1db7b382b7f294896bc6783511bb52f39ababf6b2922652c20b4e8ee7a8"
+            "226bcb1b040b5b92591f5b5b6ba0589846629c92a8d7639573f383cd28984749"
+            "c9c3f8a479756b602aafc84869bfdc569ac866b31343c9e69ee5608a81214715"
+            "1176e94a2f2676309d743436987ceab72c07a3a3e79a67fa682bf557695ce566"
+            "23f11a19b671df67c9cdbc16fb5b8023a1185ed714651800c83c031ec1563b81"
+            "afe995175f675b7fc1c5b5e84edce429d71595fd82575cf75ee6b7bb97b55d30"
+            "15879727281527b45d8607f8f1c246a44b1516bbcb55791e840fff3bbbf8c70e"
+            "97c1bbc1e3948736aae8985896312d24fb20ddf73a99f7157f884bbac1c27ee5"
+            "1cea240a431396417254cee819abbc8da430c24878830ab08178e590f8463aa1"
+            "7139d91368b878ac71229fcecb3339823ab14c6739c2b7bb17749a02b1ec60c9"
+            "d2b5903ca2a81dc74226c707b39067dcd52709591c6babc8843aa7a1241e477b"
+            "ae1b7387b141621c504328b521c738380fa7074ef51987402ad7d22aa29b2785"
+            "7b6b8a610503315670823ea0259d67cc7a4b0ab65f89a712cbaa71e57c529184"
+            "e1dc198817490b45b97b0346327e5165e6c1c687bf47361cd4537631c6ad7809"
+            "547798c5dbbce8f158f5e03cd727079cb9e4b771f662592c3fac28cdbcb7a43f"
+            "0476848d8c528305638774a11da3080cba145ae6aaec5f925c1be3c5028b3d3e"),
+        c: &hex!("5086dc25f58b4646244f809eee6e4b53b9383f5d1541d35d26f268396c7ba6fb"
+            "772152bfb6852e7d1bbd38d649cbd960cb39e1f87ebc47fec0129f4501bb7c30"
+            "e0aed5ecd8929eff011329687488ff00083554aebe041183b1cc7098691ffcac"
+            "f0a940ce4484524a156a698cb4d7c00945fcb55992d62cf5db1311df8cfc2eb5"
+            "afaafed0b63ccd8ac000b9e6080b33311020bdfd5ad3d79aaeb8e182c9a0e354"
+            "19a7a82b552e48b2ade6416050d589e2d8422df16c00503ae28f3e607bf58a92"
+            "df200178e00920b49e5f2b26900c83b5040115dcc358cb2ab508e0445303d655"
+            "93746ff010b1a49d4e0690df8ca6f9dcdd7ff4b7a7362f43ec471b3ff55fdaf9"
+            "2120de7fb06b69390f5dd54e4fc0105ee32bdc4b4d7b719a1703352509f87dcd"
+            "11186143caa03ca48a8ed7fce8b443fd55d09f322028971ba7deb0667065da68"
+            "92c0c8671d5b63ed854f5e8c258665c475386736b177cac7f89632c2378707b3"
+            "40b2f0d68bbc347e6c6ae3c336a8599466f720fb399d0d3c03d557d65d0e2e59"
+            "423c8f279a3669b160c0ccf248eb7747659514c2ace0cbbd21889c36ac0935b3"
+            "c48f4b2999ace9e1bf25e47d5de336a95b8bf86d3f507e0d585e7bd8d76a8edf"
+            "5ad237c5c44af2710ce05d3ee483cb13e1981f6d81998032ee0efe4af7c43545"
+            "c4402c17f8e3a8fe690ec85838874bc3d7c7acd259eb4039ef32681b66b1f507"
+            "5fcd729d1e06c4d9bb9291d6d9ea58f3b2548c0eae1b71a69c8df94635dfcb61"
+            "c06349ce0cbe403c1a2382105aa6fecee1828c534610a5bb5058123d54eed287"
+            "3b39bc8036491bc9c4c316a99832cee678b58d2cc1697fdcc3bc21c174b4bd6f"
+            "013ba9def073d16418867127d25090cd2fa410310173867156b8869026a85b85"
+            "97b4f8b42c8e6c8a7cac851efa0c6d6e4bc3cfdd5f7355468aba4a989c409142"
+            "329f861af69f54876a8506964ab3aa7174360bad1c99ddcd08072edf5b8eb868"
+            "44cf4664b3269087e3e13da8fafe0bcab93890e792d4bc337b1d2ba415a96014"
+            "486e9f84d690fb8542c8097101ebca95db2a8152f68686b73801157bf85e5769"
+            "aa64ffba877e7240e5a5e7a189e1857085da7686c88477ac35b438b270205de4"
+            "2711d949914f29c0fc00d03596340915cfc97e7ffc89a364a60e9dc791aaa9fd"
+            "11c5df2e1f15e2cf93b5f5eaedd1ff7409d4d692502411c63b663f33ba51c7f5"
+            "e99e470022997f1cdc205e40083d87c212dc8b8510167ea10a4dd0adca2c2ea1"
+            "ad1dcf06e0acf0ecd92743e781dfdfa8959a98e0c51608b34e124c7c779844d6"
+            "0cedcfe1c3a731da0ac1aec6750ec14ceef00b3f0d5f52faaf17a0cffc681da6"
+            "56635f211f7a3442d5a6bc082a7a0b0d15afb594c5a4a3bd4e8be16981e86aa3"
+            "7a564fefc1599add828e547d5e1edf4608c504e8e499f6b87b0a6cb2a8003187"
+            "87e2b6ffe55a9c5477992b7beb734624ab5d2e550f2cc7f5efb7f5e5709f8e50"
+            "1b688edd5e9f080d06c4c09fc27ee0799c34512869c3b6394864c33b052333be"
+            "40360e311e6eb0e488a08e65da9992fd427882e67431114714ba5c3feb011a6d"
+            "36f1b9beb75ea6e83fe869eef54bc35bb104f33709b7e650db565f228eedc3c9"
+            "d54a9b0305e02fe5991447e3afa63ba3834b66bb5699a8b162bdf946b2316aa4"
+            "cec8f0d981d008d8d7673b06c1ebd9b6d0b7b01597207f483e54bf09e7636c0f"
+            "e7fadcdc73fcc883cb1c5dc09a0b2af91b3752253291ca10e02e8424d9b9ffc2"
+            "edd17c4e1a1ed089050ad3aa8641d0ced6ea786523c3a432052565c559c25a39"
+            "e2548b260d597815b95345825858aa5b84c19283af62dfa23c9f71df5a4e5db6"
+            "6f51e667d8d4851c2a19024e4dc675c528bd7894655579fd1c9575b7d37094a0"
+            "20b2a80150917b591aab61c869aee131fcde43c4387b4e7ddcea2cb6629cd9b6"
+            "c1d0b0ccd1c393a1d3911e6c0cc04b42934ac4e24e98bfb509c67ae1580ead68"
+            "ac8f1ed465a62e438380fa5b19a48bf57b5e3204b2f6579a737fc605f5092bda"
+            "0d27a8bbea6ea768a80a807d128aedc876ff2601a1891dea5814874c839ba674"
+            "d016f7c9380458b1c7ceae6f89c06da2bf40b3a3a296e2ded9645c97a5ae23fe"
+            "45f6aadb8a3e3f9e654702a97151eefb28813b34460b89e57e96ceb46d42cddf"
+            "2bc72809a89051b1b12a35e751d6c0661130e761e8c920e28bffd1d47d91e950"),
+        k: hex!("f7242d10874f3163d37988132a51fc11bd8cd44682a9df7fb22e59f37ece9837"),
+    },
+];
@@ -0,0 +1,153 @@
+//! Known-answer tests for Kyber round 3.
+//!
+//! The `PQCkemKAT_*.rsp` files produced by the round 3 reference implementation are regenerated
+//! here from the same NIST AES-256 CTR_DRBG, and compared against the SHA3-256 digests of the
+//! reference files.
+
+use ::kem::{Decapsulate, Encapsulate};
+use aes::cipher::{BlockEncrypt, KeyInit};
+use aes::Aes256;
+use core::fmt::Write;
+use hex_literal::hex;
+use ml_kem::*;
+use rand_core::{CryptoRng, RngCore};
+use sha3::{Digest, Sha3_256};
+
+/// The deterministic random bit generator used by the NIST KAT generator: AES-256 CTR_DRBG
+/// without a derivation function or prediction resistance.
+struct CtrDrbg {
+    key: [u8; 32],
+    v: [u8; 16],
+}
+
+impl CtrDrbg {
+    fn new(entropy: &[u8; 48]) -> Self {
+        let mut drbg = Self {
+            key: [0; 32],
+            v: [0; 16],
+        };
+        drbg.update(Some(entropy));
+        drbg
+    }
+
+    fn next_block(&mut self) -> [u8; 16] {
+        for b in self.v.iter_mut().rev() {
+            *b = b.wrapping_add(1);
+            if *b != 0 {
+                break;
+            }
+        }
+
+        let mut block = self.v.into();
+        Aes256::new(&self.key.into()).encrypt_block(&mut block);
+        block.into()
+    }
+
+    fn update(&mut self, provided: Option<&[u8; 48]>) {
+        let mut temp = [0u8; 48];
+        for chunk in temp.chunks_mut(16) {
+            chunk.copy_from_slice(&self.next_block());
+        }
+
+        if let Some(provided) = provided {
+            temp.iter_mut().zip(provided).for_each(|(t, p)| *t ^= p);
+        }
+
+        self.key.copy_from_slice(&temp[..32]);
+        self.v.copy_from_slice(&temp[32..]);
+    }
+}
+
+impl RngCore for CtrDrbg {
+    fn next_u32(&mut self) -> u32 {
+        rand_core::impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(16) {
+            let block = self.next_block();
+            chunk.copy_from_slice(&block[..chunk.len()]);
+        }
+        self.update(None);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for CtrDrbg {}
+
+/// Regenerate the contents of the reference `.rsp` file
+fn kat<K: KemCore>(name: &str) -> String {
+    const COUNT: usize = 100;
+
+    let entropy: [u8; 48] = core::array::from_fn(|i| i.try_into().unwrap());
+    let mut rng = CtrDrbg::new(&entropy);
+    let seeds: Vec<[u8; 48]> = (0..COUNT)
+        .map(|_| {
+            let mut seed = [0u8; 48];
+            rng.fill_bytes(&mut seed);
+            seed
+        })
+        .collect();
+
+    let mut rsp = format!("# {name}\n\n");
+    for (count, seed) in seeds.iter().enumerate() {
+        let mut rng = CtrDrbg::new(seed);
+        let (dk, ek) = K::generate(&mut rng);
+        let (ct, ss) = ek.encapsulate(&mut rng).unwrap();
+        assert_eq!(dk.decapsulate(&ct).unwrap(), ss);
+
+        writeln!(rsp, "count = {count}").unwrap();
+        writeln!(rsp, "seed = {}", hex::encode_upper(seed)).unwrap();
+        writeln!(rsp, "pk = {}", hex::encode_upper(ek.as_bytes())).unwrap();
+        writeln!(rsp, "sk = {}", hex::encode_upper(dk.as_bytes())).unwrap();
+        writeln!(rsp, "ct = {}", hex::encode_upper(ct.as_bytes())).unwrap();
+        writeln!(rsp, "ss = {}", hex::encode_upper(ss)).unwrap();
+        writeln!(rsp).unwrap();
+    }
+
+    rsp
+}
+
+fn verify<K: KemCore>(name: &str, ss0: &[u8; 32], digest: &[u8; 32]) {
+    let rsp = kat::<K>(name);
+
+    // Check the first shared key directly, for a more useful message on failure
+    let expected = format!("ss = {}", hex::encode_upper(ss0));
+    assert_eq!(rsp.lines().nth(7), Some(expected.as_str()));
+    assert_eq!(Sha3_256::digest(rsp.as_bytes()).as_slice(), digest);
+}
+
+#[test]
+fn kyber512() {
+    verify::<Kyber512>(
+        "Kyber512",
+        &hex!("0A6925676F24B22C286F4C81A4224CEC506C9B257D480E02E3B49F44CAA3237F"),
+        &hex!("0387ab29c473f14fe7f72e282ed7d7deb25f1b8e4cbe03d2f50e43374e6d9258"),
+    );
+}
+
+#[test]
+fn kyber768() {
+    verify::<Kyber768>(
+        "Kyber768",
+        &hex!("914CB67FE5C38E73BF74181C0AC50428DEDF7750A98058F7D536708774535B29"),
+        &hex!("3d290e0a6743aaaa0eca27d2013d00c6cb97710ecb248a3d73893b618daabc75"),
+    );
+}
+
+#[test]
+fn kyber1024() {
+    verify::<Kyber1024>(
+        "Kyber1024",
+        &hex!("B10F7394926AD3B49C5D62D5AEB531D5757538BCC0DA9E550D438F1B61BD7419"),
+        &hex!("bc5111e1b0c36be84de730b616451a5f9c2b8f39a3210411c1e6ddcf7f71e57a"),
+    );
+}
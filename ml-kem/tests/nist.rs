@@ -28,9 +28,9 @@ pub struct Vectors {
 
 impl Vectors {
     pub fn verify(&self) {
-        self.mlkem512.verify::<MlKem512>();
-        self.mlkem768.verify::<MlKem768>();
-        self.mlkem1024.verify::<MlKem1024>();
+        self.mlkem512.verify::<MlKem512Ipd>();
+        self.mlkem768.verify::<MlKem768Ipd>();
+        self.mlkem1024.verify::<MlKem1024Ipd>();
     }
 }
 
@@ -57,7 +57,19 @@ fn nist() {
     vectors.verify();
 }
 
-// NIST test vectors, taken from:
+// Only key generation changed between the draft and the final standard, so the NIST encapsulation
+// and decapsulation values also hold for the final parameter sets
+#[test]
+fn nist_final() {
+    ENC_VECTORS[0].verify::<MlKem512>();
+    DEC_VECTORS[0].verify::<MlKem512>();
+    ENC_VECTORS[1].verify::<MlKem768>();
+    DEC_VECTORS[1].verify::<MlKem768>();
+    ENC_VECTORS[2].verify::<MlKem1024>();
+    DEC_VECTORS[2].verify::<MlKem1024>();
+}
+
+// NIST test vectors for the FIPS 203 initial public draft, taken from:
 //
 // https://csrc.nist.gov/Projects/post-quantum-cryptography/post-quantum-cryptography-standardization/example-files
 // * Linked file: PQC Intermediate Values -> PQC Intermediate Values.zip
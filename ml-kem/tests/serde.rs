@@ -0,0 +1,64 @@
+//! Serialization round trips for keys and ciphertexts
+
+#![cfg(feature = "serde")]
+
+use ::kem::Encapsulate;
+use ml_kem::*;
+
+fn round_trip_test<K>()
+where
+    K: KemCore,
+    K::DecapsulationKey: serde::Serialize + serde::de::DeserializeOwned,
+    K::EncapsulationKey: serde::Serialize + serde::de::DeserializeOwned,
+{
+    let mut rng = rand::thread_rng();
+    let (dk, ek) = K::generate(&mut rng);
+    let (ct, _) = ek.encapsulate(&mut rng).unwrap();
+
+    // Human-readable formats use hex
+    let json = serde_json::to_string(&ek).unwrap();
+    assert_eq!(json, format!("\"{}\"", hex::encode(ek.as_bytes())));
+    assert_eq!(ek, serde_json::from_str(&json).unwrap());
+
+    let json = serde_json::to_string(&dk).unwrap();
+    assert_eq!(dk, serde_json::from_str(&json).unwrap());
+
+    let json = serde_json::to_string(&ct).unwrap();
+    assert_eq!(json, format!("\"{}\"", hex::encode(ct.as_bytes())));
+    assert_eq!(ct, serde_json::from_str(&json).unwrap());
+
+    // Binary formats use raw bytes
+    let bin = bincode::serialize(&ek).unwrap();
+    assert_eq!(bin, ek.as_bytes().as_slice());
+    assert_eq!(ek, bincode::deserialize(&bin).unwrap());
+
+    let bin = bincode::serialize(&dk).unwrap();
+    assert_eq!(bin, dk.as_bytes().as_slice());
+    assert_eq!(dk, bincode::deserialize(&bin).unwrap());
+
+    let bin = bincode::serialize(&ct).unwrap();
+    assert_eq!(ct, bincode::deserialize(&bin).unwrap());
+
+    let mut cbor = Vec::new();
+    ciborium::into_writer(&dk, &mut cbor).unwrap();
+    assert_eq!(dk, ciborium::from_reader(cbor.as_slice()).unwrap());
+
+    // Deserialization applies the FIPS 203 modulus check
+    let mut bad = ek.as_bytes();
+    bad[0] = 0xff;
+    bad[1] |= 0x0f;
+    let json = format!("\"{}\"", hex::encode(&bad));
+    assert!(serde_json::from_str::<K::EncapsulationKey>(&json).is_err());
+    assert!(bincode::deserialize::<K::EncapsulationKey>(bad.as_slice()).is_err());
+
+    // ... and the length check
+    let json = format!("\"{}\"", hex::encode(&ek.as_bytes()[1..]));
+    assert!(serde_json::from_str::<K::EncapsulationKey>(&json).is_err());
+}
+
+#[test]
+fn round_trip() {
+    round_trip_test::<MlKem512>();
+    round_trip_test::<MlKem768>();
+    round_trip_test::<MlKem1024>();
+}
@@ -0,0 +1,148 @@
+//! Peak stack usage of key generation, encapsulation and decapsulation with the `low-memory`
+//! feature.
+//!
+//! A stack overflow aborts the whole process, so each measurement runs in a child process: the
+//! test binary re-runs itself with the operation and a thread stack size in `ML_KEM_STACK_CHILD`,
+//! and the child runs the operation on a thread with exactly that stack.  The peak is the smallest
+//! stack, found by bisection to the KiB, on which the child succeeds.  It includes the guard page
+//! and the frames of the thread itself.
+//!
+//! The peaks are printed, and each must fit in the budget for its parameter set.  Unoptimized
+//! builds use several times as much stack, so the budgets only apply to optimized builds:
+//!
+//! ```text
+//! cargo test --release --features low-memory --test stack
+//! ```
+//!
+//! The budgets were measured on x86_64, so the test only runs there.
+
+#[cfg(all(feature = "low-memory", not(debug_assertions), target_arch = "x86_64"))]
+mod stack {
+    use ::kem::{Decapsulate, Encapsulate};
+    use core::hint::black_box;
+    use ml_kem::*;
+    use rand::{rngs::StdRng, SeedableRng};
+    use std::{env, process, thread};
+
+    /// The environment variable with which the parent process requests a measurement run
+    pub const CHILD_VAR: &str = "ML_KEM_STACK_CHILD";
+
+    const OPERATIONS: [&str; 4] = ["generate", "from_seed", "encapsulate", "decapsulate"];
+
+    /// The most stack, in KiB, that any operation may need for each parameter set
+    //
+    // Key generation needs the most.  Measured with stable Rust on x86_64, it peaks at 36, 46 and
+    // 56 KiB with `low-memory`, against 38, 53 and 71 KiB without it, when the whole matrix is kept
+    // on the stack.  With `pct` and `zeroize` as well, the peaks are 39, 51 and 62 KiB, against 41,
+    // 58 and 81 KiB.  Each budget lies between the two, so the test fails if the matrix is sampled
+    // in full again.
+    const BUDGETS: [(&str, usize); 3] = if cfg!(any(feature = "pct", feature = "zeroize")) {
+        [("ML-KEM-512", 40), ("ML-KEM-768", 54), ("ML-KEM-1024", 70)]
+    } else {
+        [("ML-KEM-512", 37), ("ML-KEM-768", 49), ("ML-KEM-1024", 62)]
+    };
+
+    /// Run `operation` with the KEM `K` on a thread with a stack of `kib` KiB
+    fn run<K: KemCore>(operation: &str, kib: usize)
+    where
+        K::DecapsulationKey: Send + Sync,
+        K::EncapsulationKey: Send + Sync,
+    {
+        // The keys and the RNG are set up outside the measured thread
+        let mut rng = StdRng::seed_from_u64(0);
+        let (dk, ek) = K::generate(&mut rng);
+        let (ct, _) = ek.encapsulate(&mut rng).unwrap();
+
+        thread::scope(|scope| {
+            thread::Builder::new()
+                .stack_size(kib * 1024)
+                .spawn_scoped(scope, || match operation {
+                    "generate" => {
+                        black_box(K::generate(&mut rng));
+                    }
+                    "from_seed" => {
+                        black_box(K::from_seed(black_box(&Seed::default())));
+                    }
+                    "encapsulate" => {
+                        black_box(ek.encapsulate(&mut rng).unwrap());
+                    }
+                    "decapsulate" => {
+                        black_box(dk.decapsulate(black_box(&ct)).unwrap());
+                    }
+                    _ => unreachable!("unknown operation {operation}"),
+                })
+                .unwrap()
+                .join()
+                .unwrap();
+        });
+    }
+
+    /// Run an operation as requested by the parent process
+    pub fn child(request: &str) {
+        let mut parts = request.split(' ');
+        let (Some(kem), Some(operation), Some(kib)) = (parts.next(), parts.next(), parts.next())
+        else {
+            unreachable!("malformed request {request}")
+        };
+        let kib = kib.parse().unwrap();
+        match kem {
+            "ML-KEM-512" => run::<MlKem512>(operation, kib),
+            "ML-KEM-768" => run::<MlKem768>(operation, kib),
+            "ML-KEM-1024" => run::<MlKem1024>(operation, kib),
+            _ => unreachable!("unknown KEM {kem}"),
+        }
+    }
+
+    /// Whether `operation` succeeds on a stack of `kib` KiB, in a child process
+    fn fits(kem: &str, operation: &str, kib: usize) -> bool {
+        process::Command::new(env::current_exe().unwrap())
+            .env(CHILD_VAR, format!("{kem} {operation} {kib}"))
+            .stderr(process::Stdio::null())
+            .status()
+            .unwrap()
+            .success()
+    }
+
+    /// The smallest stack, in KiB, on which `operation` succeeds
+    fn peak(kem: &str, operation: &str) -> usize {
+        // Threads get at least 16 KiB of stack, and no operation comes close to 256 KiB
+        let (mut lo, mut hi) = (16, 256);
+        assert!(
+            fits(kem, operation, hi),
+            "{kem} {operation} needs over {hi} KiB"
+        );
+        if fits(kem, operation, lo) {
+            return lo;
+        }
+        while hi - lo > 1 {
+            let mid = (lo + hi) / 2;
+            if fits(kem, operation, mid) {
+                hi = mid;
+            } else {
+                lo = mid;
+            }
+        }
+        hi
+    }
+
+    /// Measure the peak of every operation, and check it against the budget
+    pub fn measure() {
+        let mut over = false;
+        for (kem, budget) in BUDGETS {
+            for operation in OPERATIONS {
+                let peak = peak(kem, operation);
+                println!("{kem} {operation}: {peak} KiB (budget {budget} KiB)");
+                over |= peak > budget;
+            }
+        }
+        assert!(!over, "stack budget exceeded");
+    }
+}
+
+fn main() {
+    #[cfg(all(feature = "low-memory", not(debug_assertions), target_arch = "x86_64"))]
+    match std::env::var(stack::CHILD_VAR) {
+        Ok(request) => stack::child(&request),
+        Err(_) => stack::measure(),
+    }
+}
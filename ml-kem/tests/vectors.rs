@@ -19,11 +19,18 @@ impl GenerateVector {
         assert_eq!(dk.as_bytes().as_slice(), self.dk);
         assert_eq!(ek.as_bytes().as_slice(), self.ek);
 
+        let seed = d.concat(*z);
+        let (dk_seed, ek_seed) = K::from_seed(&seed);
+        assert_eq!(dk, dk_seed);
+        assert_eq!(ek, ek_seed);
+
         let dk_bytes = Encoded::<K::DecapsulationKey>::from_slice(self.dk);
         assert_eq!(dk, K::DecapsulationKey::from_bytes(dk_bytes));
+        assert_eq!(dk, K::DecapsulationKey::try_from_bytes(dk_bytes).unwrap());
 
         let ek_bytes = Encoded::<K::EncapsulationKey>::from_slice(self.ek);
         assert_eq!(ek, K::EncapsulationKey::from_bytes(ek_bytes));
+        assert_eq!(ek, K::EncapsulationKey::try_from_bytes(ek_bytes).unwrap());
     }
 }
 
@@ -41,7 +48,7 @@ impl EncapsulateVector {
         let ek = K::EncapsulationKey::from_bytes(ek_bytes);
         let (c, k) = ek.encapsulate_deterministic(m).unwrap();
         assert_eq!(k.as_slice(), &self.k);
-        assert_eq!(c.as_slice(), self.c);
+        assert_eq!(c.as_bytes().as_slice(), self.c);
     }
 }
 
@@ -56,8 +63,9 @@ impl DecapsulateVector {
         let dk_bytes = Encoded::<K::DecapsulationKey>::from_slice(self.dk);
         let dk = K::DecapsulationKey::from_bytes(dk_bytes);
 
-        let c_bytes = Ciphertext::<K>::from_slice(self.c);
-        let k = dk.decapsulate(c_bytes).unwrap();
+        let c_bytes = Encoded::<Ciphertext<K>>::from_slice(self.c);
+        let c = Ciphertext::<K>::from_bytes(c_bytes);
+        let k = dk.decapsulate(&c).unwrap();
         assert_eq!(k.as_slice(), &self.k);
     }
 }
@@ -0,0 +1,759 @@
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![doc = include_str!("../README.md")]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![warn(clippy::pedantic)] // Be pedantic by default
+#![deny(missing_docs)] // Require all public interfaces to be documented
+
+//! # Usage
+//!
+//! The client generates a key share for each group it offers and sends its bytes in the
+//! `key_share` extension of its `ClientHello`.  The server answers with a key share for the
+//! selected group, and both sides derive the same shared secret, which is the input to the TLS
+//! 1.3 key schedule.
+//!
+//! ```
+//! use tls_key_share::{server_key_share, ClientKeyShare, NamedGroup};
+//! let mut rng = rand::thread_rng();
+//!
+//! // Client
+//! let group = NamedGroup::from_code_point(0x11EC).unwrap();
+//! let client = ClientKeyShare::try_generate(group, &mut rng)?;
+//! let mut client_share = [0u8; 1216];
+//! client.write_key_share(&mut client_share)?;
+//!
+//! // Server
+//! let mut server_share = [0u8; 1120];
+//! let server_secret = server_key_share(group, &mut rng, &client_share, &mut server_share)?;
+//!
+//! // Client
+//! let client_secret = client.finish(&server_share)?;
+//! assert_eq!(client_secret.as_bytes(), server_secret.as_bytes());
+//! # Ok::<(), tls_key_share::Error>(())
+//! ```
+
+use core::fmt;
+use elliptic_curve::{
+    ecdh,
+    sec1::{FromEncodedPoint, ModulusSize, ToEncodedPoint},
+    AffinePoint, CurveArithmetic, FieldBytes, FieldBytesSize, PublicKey, SecretKey,
+};
+use ml_kem::any::{DecapsulationKey, EncapsulationKey, MlKemAny};
+use ml_kem::ParameterId;
+use p256::NistP256;
+use p384::NistP384;
+use rand_core::CryptoRngCore;
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// The size of the longest shared secret, that of `SecP384r1MLKEM1024`
+const MAX_SHARED_SECRET_SIZE: usize = 48 + 32;
+
+/// A TLS 1.3 named group based on ML-KEM, identified by its `NamedGroup` code point
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NamedGroup {
+    /// `X25519MLKEM768` (0x11EC), which lists ML-KEM-768 before X25519
+    X25519MlKem768,
+
+    /// `SecP256r1MLKEM768` (0x11EB)
+    SecP256r1MlKem768,
+
+    /// `SecP384r1MLKEM1024` (0x11ED)
+    SecP384r1MlKem1024,
+
+    /// `MLKEM768` (0x0201), ML-KEM-768 on its own
+    MlKem768,
+
+    /// `MLKEM1024` (0x0202), ML-KEM-1024 on its own
+    MlKem1024,
+}
+
+/// The elliptic-curve component of a hybrid group
+#[derive(Clone, Copy, PartialEq)]
+enum Curve {
+    X25519,
+    P256,
+    P384,
+}
+
+impl Curve {
+    /// The size of a key share, which is an uncompressed point on the NIST curves
+    fn share_size(self) -> usize {
+        match self {
+            Self::X25519 => 32,
+            Self::P256 => 65,
+            Self::P384 => 97,
+        }
+    }
+
+    /// The size of the shared secret, which is the x-coordinate on the NIST curves
+    fn secret_size(self) -> usize {
+        match self {
+            Self::X25519 | Self::P256 => 32,
+            Self::P384 => 48,
+        }
+    }
+}
+
+impl NamedGroup {
+    /// All of the supported groups
+    pub const ALL: [Self; 5] = [
+        Self::X25519MlKem768,
+        Self::SecP256r1MlKem768,
+        Self::SecP384r1MlKem1024,
+        Self::MlKem768,
+        Self::MlKem1024,
+    ];
+
+    /// The `NamedGroup` code point of this group
+    #[must_use]
+    pub const fn code_point(self) -> u16 {
+        match self {
+            Self::X25519MlKem768 => 0x11EC,
+            Self::SecP256r1MlKem768 => 0x11EB,
+            Self::SecP384r1MlKem1024 => 0x11ED,
+            Self::MlKem768 => 0x0201,
+            Self::MlKem1024 => 0x0202,
+        }
+    }
+
+    /// The group with the `NamedGroup` code point `code_point`, if it is supported
+    #[must_use]
+    pub fn from_code_point(code_point: u16) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|group| group.code_point() == code_point)
+    }
+
+    /// The name of this group in the TLS `NamedGroup` registry, e.g., `"X25519MLKEM768"`
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::X25519MlKem768 => "X25519MLKEM768",
+            Self::SecP256r1MlKem768 => "SecP256r1MLKEM768",
+            Self::SecP384r1MlKem1024 => "SecP384r1MLKEM1024",
+            Self::MlKem768 => "MLKEM768",
+            Self::MlKem1024 => "MLKEM1024",
+        }
+    }
+
+    /// The size of the key share sent by the client, in bytes
+    #[must_use]
+    pub fn client_share_size(self) -> usize {
+        self.param().encapsulation_key_size() + self.curve().map_or(0, Curve::share_size)
+    }
+
+    /// The size of the key share sent by the server, in bytes
+    #[must_use]
+    pub fn server_share_size(self) -> usize {
+        self.param().ciphertext_size() + self.curve().map_or(0, Curve::share_size)
+    }
+
+    /// The size of the shared secret, in bytes
+    #[must_use]
+    pub fn shared_secret_size(self) -> usize {
+        32 + self.curve().map_or(0, Curve::secret_size)
+    }
+
+    fn param(self) -> ParameterId {
+        match self {
+            Self::X25519MlKem768 | Self::SecP256r1MlKem768 | Self::MlKem768 => {
+                ParameterId::MlKem768
+            }
+            Self::SecP384r1MlKem1024 | Self::MlKem1024 => ParameterId::MlKem1024,
+        }
+    }
+
+    fn curve(self) -> Option<Curve> {
+        match self {
+            Self::X25519MlKem768 => Some(Curve::X25519),
+            Self::SecP256r1MlKem768 => Some(Curve::P256),
+            Self::SecP384r1MlKem1024 => Some(Curve::P384),
+            Self::MlKem768 | Self::MlKem1024 => None,
+        }
+    }
+
+    /// Split a key share, or the shared secret, into its (ML-KEM, ECDH) parts.  Every hybrid
+    /// group but `X25519MLKEM768` puts the ECDH part first.
+    fn split<T>(self, bytes: T, ecdh_size: usize) -> (T, T)
+    where
+        T: Split,
+    {
+        if self == Self::X25519MlKem768 {
+            let mlkem_size = bytes.len() - ecdh_size;
+            bytes.split(mlkem_size)
+        } else {
+            let (ecdh, mlkem) = bytes.split(ecdh_size);
+            (mlkem, ecdh)
+        }
+    }
+}
+
+impl fmt::Display for NamedGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Shared and mutable byte slices, which [`NamedGroup::split`] splits in the same way
+trait Split: Sized {
+    fn len(&self) -> usize;
+    fn split(self, mid: usize) -> (Self, Self);
+}
+
+impl Split for &[u8] {
+    fn len(&self) -> usize {
+        <[u8]>::len(self)
+    }
+
+    fn split(self, mid: usize) -> (Self, Self) {
+        self.split_at(mid)
+    }
+}
+
+impl Split for &mut [u8] {
+    fn len(&self) -> usize {
+        <[u8]>::len(self)
+    }
+
+    fn split(self, mid: usize) -> (Self, Self) {
+        self.split_at_mut(mid)
+    }
+}
+
+/// Errors that can result from processing a key share
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// A key share, or the buffer for one, had the wrong length for its group.
+    InvalidLength {
+        /// The length required by the group
+        expected: usize,
+
+        /// The length that was provided
+        actual: usize,
+    },
+
+    /// The ML-KEM part of a key share was invalid, e.g., an encapsulation key failed the FIPS
+    /// 203 modulus check.
+    MlKem(ml_kem::Error),
+
+    /// The ECDH part of a key share was not an uncompressed point on the curve, or the ECDH
+    /// shared secret was zero.
+    InvalidEcdhShare,
+
+    /// The RNG failed to provide randomness.
+    Rng,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength { expected, actual } => {
+                write!(
+                    f,
+                    "invalid key share length: expected {expected} bytes, got {actual}"
+                )
+            }
+            Self::MlKem(e) => write!(f, "invalid ML-KEM key share: {e}"),
+            Self::InvalidEcdhShare => f.write_str("invalid ECDH key share"),
+            Self::Rng => f.write_str("RNG failure"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+impl From<ml_kem::Error> for Error {
+    fn from(e: ml_kem::Error) -> Self {
+        match e {
+            ml_kem::Error::Rng => Self::Rng,
+            e => Self::MlKem(e),
+        }
+    }
+}
+
+fn check_len(bytes: &[u8], expected: usize) -> Result<(), Error> {
+    if bytes.len() == expected {
+        Ok(())
+    } else {
+        Err(Error::InvalidLength {
+            expected,
+            actual: bytes.len(),
+        })
+    }
+}
+
+/// The shared secret established by a key exchange, which is the concatenation of the ML-KEM and
+/// ECDH shared secrets in the order of the group
+#[derive(Clone)]
+pub struct SharedSecret {
+    bytes: [u8; MAX_SHARED_SECRET_SIZE],
+    len: usize,
+}
+
+impl SharedSecret {
+    fn new(group: NamedGroup) -> Self {
+        Self {
+            bytes: [0; MAX_SHARED_SECRET_SIZE],
+            len: group.shared_secret_size(),
+        }
+    }
+
+    /// The bytes of the shared secret
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    /// The (ML-KEM, ECDH) parts of the shared secret, to be filled in
+    fn parts_mut(&mut self, group: NamedGroup) -> (&mut [u8], &mut [u8]) {
+        let ecdh_size = group.curve().map_or(0, Curve::secret_size);
+        group.split(&mut self.bytes[..self.len], ecdh_size)
+    }
+}
+
+impl AsRef<[u8]> for SharedSecret {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for SharedSecret {
+    fn drop(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for SharedSecret {}
+
+/// The ephemeral ECDH secret of a key share.  It is used for a single exchange, but is held as a
+/// static secret so that it can be drawn with [`RngCore::try_fill_bytes`](rand_core::RngCore).
+enum EcdhSecret {
+    X25519(x25519_dalek::StaticSecret),
+    P256(SecretKey<NistP256>),
+    P384(SecretKey<NistP384>),
+}
+
+impl EcdhSecret {
+    fn generate(curve: Curve, rng: &mut impl CryptoRngCore) -> Result<Self, Error> {
+        Ok(match curve {
+            Curve::X25519 => {
+                let mut bytes = [0u8; 32];
+                rng.try_fill_bytes(&mut bytes).map_err(|_| Error::Rng)?;
+                let secret = x25519_dalek::StaticSecret::from(bytes);
+
+                #[cfg(feature = "zeroize")]
+                bytes.zeroize();
+
+                Self::X25519(secret)
+            }
+            Curve::P256 => Self::P256(random_secret_key(rng)?),
+            Curve::P384 => Self::P384(random_secret_key(rng)?),
+        })
+    }
+
+    /// Write the public key share of this secret to `share`
+    fn write_share(&self, share: &mut [u8]) {
+        match self {
+            Self::X25519(secret) => {
+                share.copy_from_slice(x25519_dalek::PublicKey::from(secret).as_bytes());
+            }
+            Self::P256(secret) => write_point(secret, share),
+            Self::P384(secret) => write_point(secret, share),
+        }
+    }
+
+    /// Write the shared secret with the peer's key share `share` to `secret`
+    fn diffie_hellman(self, share: &[u8], secret: &mut [u8]) -> Result<(), Error> {
+        match self {
+            Self::X25519(sk) => {
+                let mut pk = [0u8; 32];
+                pk.copy_from_slice(share);
+                let ss = sk.diffie_hellman(&x25519_dalek::PublicKey::from(pk));
+
+                // RFC 8446, Section 7.4.2 requires rejecting the all-zero value
+                if !ss.was_contributory() {
+                    return Err(Error::InvalidEcdhShare);
+                }
+                secret.copy_from_slice(ss.as_bytes());
+                Ok(())
+            }
+            Self::P256(sk) => diffie_hellman(&sk, share, secret),
+            Self::P384(sk) => diffie_hellman(&sk, share, secret),
+        }
+    }
+}
+
+/// Draw a secret scalar for the curve `C` by rejection sampling, which rarely needs more than one
+/// draw
+fn random_secret_key<C>(rng: &mut impl CryptoRngCore) -> Result<SecretKey<C>, Error>
+where
+    C: CurveArithmetic,
+{
+    let mut bytes = FieldBytes::<C>::default();
+    loop {
+        rng.try_fill_bytes(&mut bytes).map_err(|_| Error::Rng)?;
+        if let Ok(secret) = SecretKey::from_bytes(&bytes) {
+            #[cfg(feature = "zeroize")]
+            bytes.zeroize();
+
+            return Ok(secret);
+        }
+    }
+}
+
+/// Write the public key of `secret` to `share` as an uncompressed point
+fn write_point<C>(secret: &SecretKey<C>, share: &mut [u8])
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    share.copy_from_slice(secret.public_key().to_encoded_point(false).as_bytes());
+}
+
+/// Write the x-coordinate of the ECDH shared point with the uncompressed point `share` to
+/// `secret`
+fn diffie_hellman<C>(sk: &SecretKey<C>, share: &[u8], secret: &mut [u8]) -> Result<(), Error>
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    // TLS only allows the uncompressed form, which `from_sec1_bytes` would not enforce
+    if share.first() != Some(&0x04) {
+        return Err(Error::InvalidEcdhShare);
+    }
+
+    let pk = PublicKey::<C>::from_sec1_bytes(share).map_err(|_| Error::InvalidEcdhShare)?;
+    let shared = ecdh::diffie_hellman(sk.to_nonzero_scalar(), pk.as_affine());
+    secret.copy_from_slice(shared.raw_secret_bytes());
+    Ok(())
+}
+
+/// The client's side of a key exchange: the secrets behind the key share it sent for one group
+pub struct ClientKeyShare {
+    group: NamedGroup,
+    dk: DecapsulationKey,
+    ek: EncapsulationKey,
+    ecdh: Option<EcdhSecret>,
+}
+
+impl ClientKeyShare {
+    /// Generate a key share for `group`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`ClientKeyShare::try_generate`] fails, e.g., if the RNG fails.
+    pub fn generate(group: NamedGroup, rng: &mut impl CryptoRngCore) -> Self {
+        Self::try_generate(group, rng).expect("key share generation failed")
+    }
+
+    /// Generate a key share for `group`, reporting a failure instead of panicking.  The ECDH
+    /// secret is drawn from `rng` before the ML-KEM key pair.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Rng`] if the RNG fails to provide randomness, or [`Error::MlKem`] if
+    /// ML-KEM key generation fails otherwise.
+    pub fn try_generate(group: NamedGroup, rng: &mut impl CryptoRngCore) -> Result<Self, Error> {
+        let ecdh = group
+            .curve()
+            .map(|curve| EcdhSecret::generate(curve, rng))
+            .transpose()?;
+        let (dk, ek) = MlKemAny::try_generate(group.param(), rng)?;
+        Ok(Self {
+            group,
+            dk,
+            ek,
+            ecdh,
+        })
+    }
+
+    /// The group of this key share
+    #[must_use]
+    pub fn group(&self) -> NamedGroup {
+        self.group
+    }
+
+    /// Write the `key_exchange` field of the client's `KeyShareEntry` to `share`, which must be
+    /// [`NamedGroup::client_share_size`] bytes long.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidLength`] if `share` has the wrong length.
+    pub fn write_key_share(&self, share: &mut [u8]) -> Result<(), Error> {
+        check_len(share, self.group.client_share_size())?;
+        match &self.ecdh {
+            Some(ecdh) => {
+                let ecdh_size = self.group.curve().map_or(0, Curve::share_size);
+                let (ek, ecdh_share) = self.group.split(share, ecdh_size);
+                self.ek.write_to_slice(ek)?;
+                ecdh.write_share(ecdh_share);
+            }
+            None => self.ek.write_to_slice(share)?,
+        }
+        Ok(())
+    }
+
+    /// Derive the shared secret from the `key_exchange` field of the server's `KeyShareEntry`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidLength`] if `server_share` has the wrong length, or
+    /// [`Error::InvalidEcdhShare`] if its ECDH part is invalid.
+    pub fn finish(self, server_share: &[u8]) -> Result<SharedSecret, Error> {
+        let group = self.group;
+        check_len(server_share, group.server_share_size())?;
+
+        let mut secret = SharedSecret::new(group);
+        let (mlkem_secret, ecdh_secret) = secret.parts_mut(group);
+        match self.ecdh {
+            Some(ecdh) => {
+                let ecdh_size = group.curve().map_or(0, Curve::share_size);
+                let (ct, ecdh_share) = group.split(server_share, ecdh_size);
+                self.dk.decapsulate_into_slice(ct, mlkem_secret)?;
+                ecdh.diffie_hellman(ecdh_share, ecdh_secret)?;
+            }
+            None => self.dk.decapsulate_into_slice(server_share, mlkem_secret)?,
+        }
+        Ok(secret)
+    }
+}
+
+/// Respond to the `key_exchange` field `client_share` of a client's `KeyShareEntry` for `group`,
+/// writing the `key_exchange` field of the server's `KeyShareEntry` to `server_share`, which
+/// must be [`NamedGroup::server_share_size`] bytes long.  The ECDH secret is drawn from `rng`
+/// before the ML-KEM randomness.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidLength`] if either key share has the wrong length, [`Error::MlKem`]
+/// if the ML-KEM encapsulation key is invalid, [`Error::InvalidEcdhShare`] if the ECDH part of
+/// `client_share` is invalid, or [`Error::Rng`] if the RNG fails to provide randomness.
+pub fn server_key_share(
+    group: NamedGroup,
+    rng: &mut impl CryptoRngCore,
+    client_share: &[u8],
+    server_share: &mut [u8],
+) -> Result<SharedSecret, Error> {
+    check_len(client_share, group.client_share_size())?;
+    check_len(server_share, group.server_share_size())?;
+
+    let mut secret = SharedSecret::new(group);
+    let (mlkem_secret, ecdh_secret) = secret.parts_mut(group);
+    if let Some(curve) = group.curve() {
+        let (ek, client_ecdh) = group.split(client_share, curve.share_size());
+        let (ct, server_ecdh) = group.split(&mut *server_share, curve.share_size());
+
+        let ek = EncapsulationKey::from_slice(group.param(), ek)?;
+        let ecdh = EcdhSecret::generate(curve, rng)?;
+        ek.encapsulate_into_slice(rng, ct, mlkem_secret)?;
+
+        ecdh.write_share(server_ecdh);
+        ecdh.diffie_hellman(client_ecdh, ecdh_secret)?;
+    } else {
+        let ek = EncapsulationKey::from_slice(group.param(), client_share)?;
+        ek.encapsulate_into_slice(rng, server_share, mlkem_secret)?;
+    }
+    Ok(secret)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ::kem::Decapsulate;
+
+    const MAX_SHARE_SIZE: usize = 97 + 1568;
+
+    fn exchange(group: NamedGroup) -> (ClientKeyShare, [u8; MAX_SHARE_SIZE], usize) {
+        let client = ClientKeyShare::generate(group, &mut rand::thread_rng());
+        let mut share = [0u8; MAX_SHARE_SIZE];
+        let len = group.client_share_size();
+        client.write_key_share(&mut share[..len]).unwrap();
+        (client, share, len)
+    }
+
+    #[test]
+    fn sizes() {
+        let sizes = NamedGroup::ALL.map(|group| {
+            (
+                group.client_share_size(),
+                group.server_share_size(),
+                group.shared_secret_size(),
+            )
+        });
+        assert_eq!(
+            sizes,
+            [
+                (1216, 1120, 64),
+                (1249, 1153, 64),
+                (1665, 1665, 80),
+                (1184, 1088, 32),
+                (1568, 1568, 32),
+            ]
+        );
+    }
+
+    #[test]
+    fn code_points() {
+        for group in NamedGroup::ALL {
+            assert_eq!(NamedGroup::from_code_point(group.code_point()), Some(group));
+        }
+        assert_eq!(NamedGroup::from_code_point(0x001D), None);
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut rng = rand::thread_rng();
+        for group in NamedGroup::ALL {
+            let (client, client_share, len) = exchange(group);
+            let mut server_share = [0u8; MAX_SHARE_SIZE];
+            let server_share = &mut server_share[..group.server_share_size()];
+            let server_secret =
+                server_key_share(group, &mut rng, &client_share[..len], server_share).unwrap();
+
+            let client_secret = client.finish(server_share).unwrap();
+            assert_eq!(client_secret.as_bytes(), server_secret.as_bytes());
+            assert_eq!(client_secret.as_bytes().len(), group.shared_secret_size());
+        }
+    }
+
+    #[test]
+    fn layout() {
+        // The ML-KEM parts of the key shares and the shared secret come first for X25519MLKEM768
+        // and last for the other hybrid groups
+        let mut rng = rand::thread_rng();
+        for group in NamedGroup::ALL {
+            let (client, client_share, len) = exchange(group);
+            let client_share = &client_share[..len];
+            let ek_size = group.param().encapsulation_key_size();
+            let ek = if group == NamedGroup::SecP256r1MlKem768
+                || group == NamedGroup::SecP384r1MlKem1024
+            {
+                &client_share[len - ek_size..]
+            } else {
+                &client_share[..ek_size]
+            };
+            let mut ek_bytes = [0u8; 1568];
+            client.ek.write_to_slice(&mut ek_bytes[..ek_size]).unwrap();
+            assert_eq!(ek, &ek_bytes[..ek_size]);
+
+            let mut server_share = [0u8; MAX_SHARE_SIZE];
+            let server_share = &mut server_share[..group.server_share_size()];
+            let secret = server_key_share(group, &mut rng, client_share, server_share).unwrap();
+
+            let ct_size = group.param().ciphertext_size();
+            let (ct, mlkem_secret) = if group == NamedGroup::SecP256r1MlKem768
+                || group == NamedGroup::SecP384r1MlKem1024
+            {
+                let ct = &server_share[server_share.len() - ct_size..];
+                (ct, &secret.as_bytes()[secret.as_bytes().len() - 32..])
+            } else {
+                (&server_share[..ct_size], &secret.as_bytes()[..32])
+            };
+            let ct = ml_kem::any::Ciphertext::from_slice(group.param(), ct).unwrap();
+            assert_eq!(client.dk.decapsulate(&ct).unwrap().as_slice(), mlkem_secret);
+        }
+    }
+
+    #[test]
+    fn invalid_length() {
+        let mut rng = rand::thread_rng();
+        let group = NamedGroup::X25519MlKem768;
+        let (client, client_share, _) = exchange(group);
+        let mut server_share = [0u8; 1120];
+        assert_eq!(
+            server_key_share(group, &mut rng, &client_share[..1215], &mut server_share).err(),
+            Some(Error::InvalidLength {
+                expected: 1216,
+                actual: 1215
+            })
+        );
+        assert_eq!(
+            client.finish(&server_share[..1119]).err(),
+            Some(Error::InvalidLength {
+                expected: 1120,
+                actual: 1119
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_ecdh_share() {
+        let mut rng = rand::thread_rng();
+        let mut server_share = [0u8; MAX_SHARE_SIZE];
+
+        // An all-zero X25519 public key, which yields the all-zero shared secret
+        let group = NamedGroup::X25519MlKem768;
+        let (_, mut client_share, len) = exchange(group);
+        client_share[len - 32..len].fill(0);
+        let server_share_size = group.server_share_size();
+        assert_eq!(
+            server_key_share(
+                group,
+                &mut rng,
+                &client_share[..len],
+                &mut server_share[..server_share_size]
+            )
+            .err(),
+            Some(Error::InvalidEcdhShare)
+        );
+
+        // A compressed P-256 point
+        let group = NamedGroup::SecP256r1MlKem768;
+        let (_, mut client_share, len) = exchange(group);
+        client_share[0] = 0x02;
+        let server_share_size = group.server_share_size();
+        assert_eq!(
+            server_key_share(
+                group,
+                &mut rng,
+                &client_share[..len],
+                &mut server_share[..server_share_size]
+            )
+            .err(),
+            Some(Error::InvalidEcdhShare)
+        );
+
+        // A P-384 point that is not on the curve
+        let group = NamedGroup::SecP384r1MlKem1024;
+        let (_, mut client_share, len) = exchange(group);
+        client_share[96] ^= 1;
+        let server_share_size = group.server_share_size();
+        assert_eq!(
+            server_key_share(
+                group,
+                &mut rng,
+                &client_share[..len],
+                &mut server_share[..server_share_size]
+            )
+            .err(),
+            Some(Error::InvalidEcdhShare)
+        );
+    }
+
+    #[test]
+    fn invalid_encapsulation_key() {
+        let mut rng = rand::thread_rng();
+        let group = NamedGroup::MlKem768;
+        let (_, mut client_share, len) = exchange(group);
+
+        // The first coefficient is set to q
+        client_share[0] = 0x01;
+        client_share[1] = (client_share[1] & 0xf0) | 0x0d;
+        let mut server_share = [0u8; 1088];
+        assert_eq!(
+            server_key_share(group, &mut rng, &client_share[..len], &mut server_share).err(),
+            Some(Error::MlKem(ml_kem::Error::InvalidEncapsulationKey))
+        );
+    }
+}
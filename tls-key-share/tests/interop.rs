@@ -0,0 +1,791 @@
+//! Fixed key exchanges for each group, checked against OpenSSL 3.5.
+
+use hex_literal::hex;
+use rand::{CryptoRng, Error, RngCore};
+use tls_key_share::{server_key_share, ClientKeyShare, NamedGroup};
+
+// The vectors were generated with the ML-KEM and hybrid TLS group implementations of OpenSSL 3.5,
+// and Python's `cryptography` package for the ECDH parts:
+//
+// * `client_random` is the client's ECDH secret followed by its ML-KEM seed `(d || z)`, and
+//   `client_share` is the key share derived from them, which OpenSSL accepts and re-encodes
+//   unchanged.
+// * `server_random` is the server's ECDH secret followed by the ML-KEM encapsulation randomness,
+//   and `server_share` and `shared_secret` are the resulting key share and secret.  OpenSSL's
+//   deterministic encapsulation (`ikme`) computes the ML-KEM part.  For X25519MLKEM768, OpenSSL
+//   also decapsulates `server_share` to `shared_secret`.  It could not import the NIST curve
+//   private keys of the other hybrid groups, so their layout is checked by the next pair instead.
+// * `openssl_server_share` and `openssl_shared_secret` come from a randomized encapsulation by
+//   OpenSSL to `client_share`.
+
+struct Vector {
+    group: u16,
+    client_random: &'static [u8],
+    client_share: &'static [u8],
+    server_random: &'static [u8],
+    server_share: &'static [u8],
+    shared_secret: &'static [u8],
+    openssl_server_share: &'static [u8],
+    openssl_shared_secret: &'static [u8],
+}
+
+/// An RNG that returns fixed bytes, and zeros once they run out.  Only the pairwise consistency
+/// test of ML-KEM, with its `pct` feature, draws more, and its randomness does not affect the
+/// result.
+struct FixedRng(&'static [u8]);
+
+impl RngCore for FixedRng {
+    fn next_u32(&mut self) -> u32 {
+        unreachable!("only try_fill_bytes is used")
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        unreachable!("only try_fill_bytes is used")
+    }
+
+    fn fill_bytes(&mut self, _dest: &mut [u8]) {
+        unreachable!("only try_fill_bytes is used")
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        let n = dest.len().min(self.0.len());
+        let (bytes, rest) = self.0.split_at(n);
+        dest[..n].copy_from_slice(bytes);
+        dest[n..].fill(0);
+        self.0 = rest;
+        Ok(())
+    }
+}
+
+impl CryptoRng for FixedRng {}
+
+#[test]
+fn interop() {
+    for v in &VECTORS {
+        let group = NamedGroup::from_code_point(v.group).unwrap();
+
+        let client = ClientKeyShare::try_generate(group, &mut FixedRng(v.client_random)).unwrap();
+        let mut client_share = vec![0u8; group.client_share_size()];
+        client.write_key_share(&mut client_share).unwrap();
+        assert_eq!(client_share, v.client_share, "{group}");
+
+        let mut server_share = vec![0u8; group.server_share_size()];
+        let server_secret = server_key_share(
+            group,
+            &mut FixedRng(v.server_random),
+            v.client_share,
+            &mut server_share,
+        )
+        .unwrap();
+        assert_eq!(server_share, v.server_share, "{group}");
+        assert_eq!(server_secret.as_bytes(), v.shared_secret, "{group}");
+
+        let client_secret = client.finish(v.server_share).unwrap();
+        assert_eq!(client_secret.as_bytes(), v.shared_secret, "{group}");
+
+        let client = ClientKeyShare::try_generate(group, &mut FixedRng(v.client_random)).unwrap();
+        let client_secret = client.finish(v.openssl_server_share).unwrap();
+        assert_eq!(client_secret.as_bytes(), v.openssl_shared_secret, "{group}");
+    }
+}
+
+const VECTORS: [Vector; 5] = [
+    // X25519MLKEM768
+    Vector {
+        group: 0x11EC,
+        client_random: &hex!("9e0ead8f32997ce26826c5a4487594cb5b7acd1a40415e03db15202ac2ea5be5"
+            "0b60d1b7510f34334c1a2b40249b86619077423349ed5d0eb35a5b1c3886ece1"
+            "503fad1e19afce555f4f0af1d36f4bb5665797b29017d4993d19f680f77e4d0b"),
+        client_share: &hex!("a563653b21191166ae4b15a4cbab98569489580271081ca5d69b272c95535f7a"
+            "c49f821d0fb51202b21fcc6632f07aaecd305b38dc3cb41c89d578a9bf2c2c5a"
+            "727ad31a17b20acfb5d9703982bf7ec19024567c1ee039a7757c27f880f3046b"
+            "5a8b471abb9a1dec0d86b500a3c722aa019155ab65784a738f473dec36096ad1"
+            "892803906707001524b0adf86f37c74c23366e426a429fabbc29a33b0d7b37cf"
+            "c3419f574ae94178df47b55086041299b398911e04777560399d306b917e6810"
+            "09aa7ec4fc134e1057327129dc5b3c2c77457c36834a704c027424c0e87c4539"
+            "258e6b85aefb5400175da54458cb204989a81758205b56298e7c64c24425415e"
+            "c614f174788824bc06a2a8b1b174b242ad2edc0627d142f355662c2348065307"
+            "c8d2b194c1890413b57c3121ed69b63be02af88c0d15133e3047c8a2b14c1546"
+            "764bd62b0b01a4535751199a3a08ba77f8e20128f9bebff174bd05cf7dc4009e"
+            "068544332aa937b0534a7a13a32e7ac3a5cb763257650b3cf88c78a2672e0421"
+            "841006b925839be7c7e85127659c354247caa93a0bd1c65e2031936d7504e83c"
+            "346ed536b661c053fa6a6af37c166588d9380e363c5acebb9d6c81b4510311d5"
+            "1287b576389f94c1b1460bcab084b182491a296e9d622c97c86d7e36276baccf"
+            "6945172cd4c9da35afe4c54bdaf71699d86892612c6b67207a27cf45bc38703c"
+            "5e65281ee79023a29c226660aee0f13b3ad35438f9637c20a1f3f22ff444bad1"
+            "4297b9622977fabd5c04345009cbe4760359f201f641b9624c250b5a05da36b0"
+            "060bb1f12b4ad253761f70af777c9965c545620398c1d185f0549fe83b0607f6"
+            "bc1a17a65ec20bf762cdd004327d2141dd763db4dc726b6850c41b2dd12b7555"
+            "068443bc9a880ba0e7a63a11324392c245b844ae2b8678079b6db7cc279c8ab2"
+            "3d493a16c4b89845a6afa508798a97c283385f78c9699420b147c7cab0c310c7"
+            "41df91239f207e6255c68c900a617a4fced676b1332347ac8f732a446643bad2"
+            "db25c003ac29b521740a3443c40a12e8567ec17cb9b4cc9aa10cb6b20b2ef098"
+            "bc77917453cc68325eff9a7cee26367c26c325940ccaac74592c82a0c0818e86"
+            "81cdfb5941e625a605195352351fd52c21b948ad16383c0a6fb9d63ce9f148fd"
+            "5253eb922981dba46b035a921119f33801aef53513fc84d4e58495023fd39c68"
+            "7d0836f390b97bb34b04691c966c320b6312c23a75e3a01fee503c5b857f51e0"
+            "a0b91a40aea15fc98a157bd16cf3297fe93b24b06b16c18928cbb1865f707859"
+            "4468c089512a7cab1cb51e36104f60447a62729787b35b43014a5ee1caf01673"
+            "ade9453d2cb574b84aac6c3b9fea8181010314967a08da25f49593f468adb715"
+            "a36b11b19e2b5be3735cd248afb8c86800658186686e8043460740942f7b10fe"
+            "d80c2fb15d68d6b40652a5a503a7490030a1e6b6a7b7ca4047c14b8c7158d533"
+            "b6a33f642a911aec6ea8a620118a05a8922ffa837f32c11f9cd66b2e5816e597"
+            "238ab847aa7945b13b1d5bc5307b82c3cec975c04b55b5572d60f92c70892ae0"
+            "cbbc6a57bc955c59a67a129e7bc92bb2294c16a6a17c8fccf83c9a51c08ed54e"
+            "a69afd637e8043c0d83854430d824a4821e8f3bc771f421657132f8dc660a73b"
+            "98f312d4e2bcdf7152a84c714af2ce30eb13570a1fb937c3b4231122f8a4ee35"),
+        server_random: &hex!("356604a685fc735bd207d6463594208cf8fb78833d37448bb2fd511f5f05db56"
+            "8bd279231c0eb6a721e078c53505442c5baa77ebbf5aa8faf7863a4e7c274cb3"),
+        server_share: &hex!("1796f74f3a75782f928481e702046e42e0d6cf159879efc60622e38156be850c"
+            "9e8a0e5f77f5deb87c916dab259ddebd63dab7ce1053feeacf811aeca2434b42"
+            "0af4beaaea5574f14eeb8b64c1cf88eb3b870d29aebd1324c3c32f79306a091f"
+            "20e7039a6b6b9eb176bfe2bde9a99a431f521cf579a183d1590a7cfa4e4cd8f2"
+            "e8086269855e4cfa63afb02ff213867da72eed7cb41dbaddddd6c5c242ed3c01"
+            "71e8eb1cffadea666e280a705885f2f5a7129d1a500cdbcaa5e4558c0ed7baeb"
+            "de45cd9af2180664508d6beb0a29a6d841ff870a5769d14d4d0b5f7d3393c666"
+            "f538c1f212a546ea7e56d6efe976d6f31e425beeb7f4a93bdb79a818a6c50dac"
+            "a0cdf4e0cbe2ce9c9c3bb2bcd6da7d05dfba1f0cddad0d5890f1440af2e9858b"
+            "2a42c6e617dc1892dc78bf4bd0aa1db0c31466785643668554a74d38d8fdec75"
+            "2b784cc34604d6fe2c4bb6495b46dd4d4381678008808208956579386f32c3b1"
+            "0ec02c0d950682244ccba6c2515f2550135d3ecc210555f2987d0d897de8875d"
+            "973de508d4fd93de77ddcf6250e8ac86745fc683cfc024edc999e45b9b61cfa8"
+            "55207d0db40fbb00735696e5953b9b46b2254115b52d20390835b90e68cf73b0"
+            "86454b50120f3e778598018e5540a84f826988a0b62f040fb79c4ec9ba386d90"
+            "410957c0c75385cb5b5589ab237b2cca199a11068232d4ea2a087b4643fb341e"
+            "5390df7a8197b32275861e4a55b5133be2984944d5c312967c55bbf1f79c8e8f"
+            "4bce185425e6de18aed0c30d34b0dc1cafa4a7c49235988213c95d8e3db4e335"
+            "ad6621be5b9610c189c716f9462954d9746df347fffd2ed51f6b8c2657c724fb"
+            "0024c657a19b0ccd4d4e6b5a298d6f35361d091285dd480a82cb24d5a505374e"
+            "c4e1bcefc11afa17b9e6fcad4cf42f406decfcd1f8827d64d6ce1edbb1deabe1"
+            "6bf79404cb08860c18e933e6f701229f684f0afaaf89bffcf825a0036626746e"
+            "ff5cd28f4aa6889379e2011d79d73d50a37865f2101170ad13b660838798b2b9"
+            "9feb00692d04f8bfbb6622e6b66479351aa2560998f9d14a3aabbfb30d43b6e0"
+            "704f081d803565eb005819784db78ee1ab40257e7615ead27a5eea8c8810b776"
+            "a135b8dc2e3a0abf24b522b0f6560820739b9a51b062404569c24e82e55a0adc"
+            "f7cdbdd8e32c0d93aaf32a68cff5dffe0dc91d439c7733f7e67cea14197434a9"
+            "044d004d44fed5a4fc9394d6420d1841de68bf64e1c089cfd4fba64e15de7e33"
+            "8474e6e4d44cac91be4371f34a72c775712cb61bc65b2d27fe0e9bbed0508032"
+            "323953a6d21c80a4e1393d8febda39a7a3e2ae74ebf2645aa5c8de34d5ad7427"
+            "534287ef11b69e428e459b8250e10c5fd0c590b79b4cfee1a846fbb690fcbb1a"
+            "b913615a1b1cee3948f312fd1b77b2f022bd3312f66e008ef206ddfc7857b84c"
+            "985b4eba340c776bf76eb2cdeafdb759dbbb201d19b4822b4234825abb8a04f6"
+            "ce125344678eada632cb63ef206a095d43bd4dd5058791d8fd2dc883ad5775cf"
+            "6e7fdf1157af04815697eb16cd23eb77af4cbd221d5c5c07bdd2c9c0e10aab4c"),
+        shared_secret: &hex!("dea68d7f148f8bad3d82bd61604695ff68230c6b0b6c2054bdd103dffa4f70df"
+            "6aea2dbd20f3e3d87293d4fdd610ce3bcf8216ba36d79b3de9226c3087987e56"),
+        openssl_server_share: &hex!("f314e2fbbb1c4e1bb28b113c2b2e9649eaf8bfe0a534fab4bd4f68afcd7187cf"
+            "35a92a3330e2e104b037c2b3068c4afefc41c54466f8178d50391fa52a1f9b31"
+            "415d7b8ea3895d25f45510675d32bf4694ec0133ba614bc40fbaed9765f866e6"
+            "f49ed9cc72a683d0d4125831ac7e4bea1393f4f9646befe27d112baea9c60930"
+            "fb3cce8094cba15f3ac59a10bb74e9414a51b52accbf4250c4ea01306f007541"
+            "03cc1606d50b1e7d2029189f317ec2d8ff1575dafe86872f6724f7f3f0fac069"
+            "c4f529abd6750098bc09e66368100d0029075102f93704ec75d938d0225ce7e0"
+            "335b1d2468c411c05faa19782abafa9f59a170c5870d69b38fefafadb5695ec9"
+            "167b62e5abbe459553ff81c8232bdb07c514763b55433d6d2350128e07eec7a4"
+            "a26dbaafabe9ef7d1c7a88a18552a7a6980021e9aa23c6137584ee315a2bcfc6"
+            "1db20c749812f1b20acaa73c2664f0c1d645d26f7f4d3ba7cbad674397482984"
+            "6423ec7a15ce66f6aa43e8bf5f88a7f465fcb091b9f5407401f87db21c3a45ab"
+            "14bff8a4ff88295c265d96c494ccf5cc7dad1097890c2a7a0b2e9bab0605e44e"
+            "61f5594be4e31e5b586d6c33ed873ee963714f6ce4c3659d2ae7776e2ee4a611"
+            "d6844eb9e4108cc3877e1facfc54146718d65398c8d0305d82e98577db3bef33"
+            "83c6b9d659c3ed3ddee1f3d20b4e835874427fa288f6dc8fca46c21a48fcb094"
+            "d2808ab0013315f2aa5dfb99fcdb1ef9c38ae67da98d66657ee8ff1d4e8f582a"
+            "b92636676480e68f8ccdaa92cedb92c266d0f9c13b7509e8607fbfc617795be2"
+            "8e796dc273d8900f3b89b4511820de0688e83a4b093af1c3e4c3ec84c4833a71"
+            "535b88a1fbb3209f665bd90a098c16ab692fdc8124c24882056ffd17631ed3d8"
+            "d9a82d64de09c4226b475a484be66e11d84a86868f4c00d73a30f63a2fde7f81"
+            "63c6a3fd3895cdc58dcfc7ca89b50c305123216d1300566249893265c72ed549"
+            "c7cc21747afce291ba704f9663ce8c2bf523f4b1e94a7f3d192858bee32412de"
+            "1bd0354ff6931495b72f993813dc009719c454196b53018bcc8226b7d5ec0944"
+            "13327c92a868f56ac73de7340cf168420a5f67539da5614db44b35f5fa0398bf"
+            "a000307d0de0910d556c22dc673313e803fa43bef10685b858fb45f3c712d47c"
+            "af05c5417259f2a74bc4afe0b4bbdfc71ac97c68e2a227b4214eaf4b484b4cdf"
+            "a31c3c17aa6f1c2f3233a4c2f85145e8a9e544f7a15eb8db88d2921a255b25e9"
+            "c1561b21cabba7f0c1aab9b93003a4dc7bfa15ac6344eb83dc7ec9ae521dc111"
+            "ecc62f4fec32a4884a664045f4d35e10434e3518f12fcde4f6b1c61e32fa7213"
+            "b15331607a23dd790d589efe94a3dbd6a51a18506156bf3bbbcfad1363dff218"
+            "fe451f3f71ebe1ae0487d0863193001591f3044647bd6e48ebf05d8d2a698b37"
+            "097175e00b78314c122df3b160eceb253c3e6ce14599653f859a20955cb9d579"
+            "4f98471a9a14d552c4c8f41af9302308a6cf8c73513ab2cc892245db6df0ea85"
+            "f1c879992c28a9695b1ed4d09c5b2b685d3fb35aad54e9e0e118480ae177330d"),
+        openssl_shared_secret: &hex!("1e5a5164a34620e5d28634ecaf9b6616092b6922fc2c32569185ceee9075b34f"
+            "cda6bcf838b758e33f3dda071a925b88daf6ff3c7581351f510e4a63e1b85d73"),
+    },
+    // SecP256r1MLKEM768
+    Vector {
+        group: 0x11EB,
+        client_random: &hex!("b922ddfdaf9a1accab179915e545e72bbacec83b3517ae1271cf5c8af7fdf540"
+            "1a5c0b8dcb8ae51bc1b813d055883d48cacdf74822b5aba18ca19764a6fb044f"
+            "dbc40e88a2b6bc062d7bb05181f7e7a934e5db70d53cbc789417e60b52acd5e2"),
+        client_share: &hex!("04c1750c83dba2db02e88e2483d665468463304b46d4e16c9a4d030a25198462"
+            "fa4e211d53c8243d4a8b37b6bac3fd0f5bb4ef08b3caf5791c92456796bc5631"
+            "503b873d41ecb96da4c735ac23636455b3720a1c1a9da3951475c64f6c279c4b"
+            "809899120f82926fc27b9530f43c35f62c68a63bd3cbc5d88a493f4c070d9813"
+            "e9f1777f8652dc57a4f9e31004b3384423a5f8b2415939221a267877fb6d78c6"
+            "4ad9239b096893052a90a320cabb441c03c2653f734ed81273f51a66488894d0"
+            "89aada04a149e1cebbd0bef98b0badb50807d5aa4f73a9f5811bc0421a70020b"
+            "9d597be3225f933baf23ca90296295f4862ab972abba3911e7c423c8622599a2"
+            "bb967b3d0998c2afb904d529b9a394957da5ca2d489a6034ae96f4b59c249355"
+            "785f94a93acfd61b05d33470d248714545732b2bbc7a1ded61b47c3306437907"
+            "51595803b06c586a1b502ab1201cbb81c7c06f20249fe2562917031c2456c2c0"
+            "9e79808aa61c637ea223a5427870d33c9807cc67e0380796414ba37a9cb3cbff"
+            "b0cba247138482553153404e76b9587a3399663c23b32cb8d1cea93882ed4b8e"
+            "322719d0149ad18725ff639b1a0b2b1b16701b90624cd662f9f25834650b2a22"
+            "2d4d02483ae190f6958c920a20acebcced6b69caf81d5c02b506e98cdb145836"
+            "66057c6374c3293a12364a69033ee32a78aff950f7c4b0d9270600a3511386b2"
+            "44a26c4aa57ec13831e56b87d8eb26eb629ae09c874974512d6588e44c44ea4b"
+            "994fa58f0e4c09647ac7b5ca312a4cc403b623d7619b2ea207f7167d2cf98074"
+            "804725965bdbc32e6dfa651a279c20597dfe627a9e076817435bc646005da283"
+            "8b379f9a9b44008b2b82892b71fc6bdd167869f977aa89268e5aa8383a2248d8"
+            "1aa8b42b28715575195a0a3b1d117a025b95191c840520f613a3f95ffef440b3"
+            "9b5075d5a2a0b4cd39275499fb0e1a133ad83b0af87ac701c56a4c5b741a6313"
+            "cfe93060626f2068c8c61c6683fb9cfbe64a2fb2794d30b8c4797773bb0d6306"
+            "7ceb2c45824206b8d19a45383f92ec7d04eb4f04736a006934ecd67ab7f85e88"
+            "32a6e609a9afd7b3f895106bd57f57cb3f7c462e7fc809f7393597763d88ec9a"
+            "7389963bb6316397a90ac7b6b5dc635436847f2353b9018838bb491549981702"
+            "95443b3cd11221a370151cc8921d593567296d23393f6ab92f9493b42b190b70"
+            "27252b959d3960a3d4a37992493c3f68870e7622cd565d7a1232ae5b65a1535a"
+            "eafa03924220382a7dc770594290abf42c0eeb9088752c317a9b7d92411e1af0"
+            "267f365476849b7fc47e1fc5506dab32acb5ad9bd5849e4861a7118883101da3"
+            "ab716adab3806b919e978aa8b051aefc18dfa3c0ed218706fcbb1b39c6a3f680"
+            "b0c2566732751b239a006c289e9100cfd58926880c8c7b67121b2a4d23a79aaa"
+            "2a757072d8f48a703a3501183620d1cd20e5c89d50a6b3d989c374a986b70141"
+            "56516007ab37c45783916bd45a031b82264a8b63a4ab10579906db8a50d4d94c"
+            "ad4852af522eb04337217485e87359e4d5020108ca1b39a85f023171698e3171"
+            "7a0d989b7ce98e4820ba56d48dd44447dbf77ad24c1182a4aa35987243bb8a73"
+            "58363fd7148764744b7ac913bbc61f5875f16c272cc4201ea9131fccb037743d"
+            "6fcc01a0587bcbf06b711994484610dec36d3f0b27a9f7238f65167f371ad670"
+            "4c23a636fac45796e3b45c89c059ff5901716414312da5f1c8fa461e9f7c7d7a"
+            "96"),
+        server_random: &hex!("cd3ba7d4bdfec6c51f6179903b69d38307df548689abde6efffa7ddb9d6f1300"
+            "c0c1f48b56a89b4aaad860a6fe6d3c3712a496973f217aa7d7c151e939a5e129"),
+        server_share: &hex!("04192dd4bac99da326bad581920f7dbee8d8e4ad8051d9765e83f93424cb6e73"
+            "c4d91d115c741da6656b8d9cba51781d033ff4f40d8e78597ae67c8820880893"
+            "5ac039305f0e35700be523cd30dc2239a5bab5b5fffa974ac924a84eaec52c91"
+            "8789d2eb6ce7aff851b639ceecb685680e76bff055e4d5029482b35dc20ca4c2"
+            "990ff76de48490a68e3b31ce7b546b54b2e1f80e72184087f9e369d355ab2f66"
+            "c332d9ea10b12c6a454ab86beb43453a76b9598c5c9c6b41e63cbaf886578e83"
+            "acf31d84d532941718f642b2e95380f4d0f6fc411be7a8811cb333faa1d38571"
+            "ee7af57321cfcc8e48377e9516883f667dca7ad2bbd6a3493688d635456b4009"
+            "45f27490abf6a3a1d8f4a9de88813c8665cba132d8c8208d524a841c03599a7e"
+            "49df413645d3b574fd0569317021a6047bf25f404c7a2d2da4da5f5dbf1d0118"
+            "0a7d80ad5bd517fda020302fa7596ba482e15a74e4f8274b25fb4198ac6e0d25"
+            "1185520d099df6ce7db872f559d7ce1e28e515fc08edcedc9ceeacaa89c4d218"
+            "546f425d1c846d22b73267289d420bcd79d0ac479e690628033a7f634c82e5bf"
+            "ebb459fd188a23bfc43e6bc48c2293fb35d80a747a570264f591d6045fcf74b1"
+            "c136801acd8ae462e4b6b6b9a4c5217876628024beb79a052d92a7be51b8b512"
+            "3eba6687f01bc1d523485ce90a63fd996f582a33355d39cd3edaa5a67fe015de"
+            "5945a8ff6d7a10c75473537d31df1611904cfdb4b94a07fc624ec56e8c2aee33"
+            "4670fe257c36232aa1dc5f56819c6cd9e3c3077edeb595f0eecdda48e66b84fd"
+            "b81b8edc6ad6a199de83bfe443587e4788a54c84351855316a01a84388f96550"
+            "d7acc23221f48a044dcb693086a0b4a303606e961571cad566d1c75f6f665184"
+            "2a7095edafb2b191d4ccec9cbd8c403964e70d8593719b21bf75fede8b8a7261"
+            "87db16d081053a61721d41dc8105c4d49af0ec0f78eb6cd5b58030d4f9586546"
+            "34deccd9e3f461b9aefab5e8392eb024d2ebde94593c9e8169b14558e929ea3a"
+            "9b14de54bc4860684cb9b289445cc03daff448be20dd5186c36143f2c890271b"
+            "4e8cea82b92d9929a2fe8427a4334ce724ed4921ff561200ef5d0466392e2e8a"
+            "cbe11aed4693b809a52e8c593e906805872c7a6b3448780db9535a108709aaab"
+            "473aa14fdef09f32d0013cf3ca84ad1713f4a1c494a0921a9e577d4ee7dc6273"
+            "ec5e296698c665666f1cabf2939c3d6727dc59c4cf190ec5a7fb7b7ed864210b"
+            "b3146776d90dcc9e288b6a0e6055c26e37d9137d9040fbfb1332164c74338a89"
+            "5c19032a096fb562d82c16d59d3d5ecaf997a26988ffc0880ec400e24db97544"
+            "315b510d99c211efc3cdbcdbd0c633c76a28ffb2b132297300a334134cce6a44"
+            "fac658b75ad3b66ead85ef05f4bd9ee2c7a536632fb9eda2ffefa4f8fc38d79c"
+            "d58088b0b6e60dcd185de45dbf8d470ad9c7407a5ad3ca080caf94180f0f9cc7"
+            "3431a3b4e86df81d174ddbe55beed98beb2b09d36aa84aa404e6aa9b31b5e67c"
+            "f045a6be13c4609f8c97f301b8f4c84423bca15c1c451052dee6031cbf41e328"
+            "01cc1ac2fada855b12aad86efb09a182433474a8e01ed9f5724d4fddb88a9976"
+            "d4"),
+        shared_secret: &hex!("7142f0473c1aadc5f49fd21ee7744f2bdff44730676a9591fb7bd7b1c8a71303"
+            "4d94c1986e23e272a066ed9b3c11b79a73cd9909ea1b1ab303e248d6b37cd396"),
+        openssl_server_share: &hex!("041d014e453ea4297cd8a5c41979f12ae5e83be4c608a8da0b4f19d63e687e0f"
+            "0a4b62be6f48b9fd39363f1bbdba9941557417e49b335740d6f5218a08f6b56a"
+            "bca40179782e1baa704f133c6d830a55071b53a94d81dfa84144492b2b5c4741"
+            "3e1212f80a1005274a68e6af74835eaed5d23def4a094cb2eaf5d2260f114bfa"
+            "69364a1de8d1ff88a15fd305e548ce37c80279c4026ac3e872164d3426001db6"
+            "233ca7cea97caed12da675d973cd67ff70804e15d39fe0bdcc2694e440e9c4dd"
+            "d9efa801a5c2e2efd844ff6ffea140935dcbe7ef0a9894d286d857afabaa87af"
+            "25b92f0df64f96683ff9c9b097f41d07a91571adf7c4efbde85746f3d6d3b16b"
+            "4c6b48571b7515723a127aab5456a614d45746baf57acd6822a9e2fb79378ed4"
+            "c0be0c16aeea7b513d85bc0335feaee054445429c5d045a318aa4885dbeb70fa"
+            "3a696b769b8e540be48253f9ba8ab1b5387208e69ce4f9bb0a1105eb16863b9f"
+            "18dd993b874c1eb21f346edc46514bb8dedb80fed726e9975229ed78beb2911d"
+            "b3c0d63e9aa627c36cc4742a2a98ac3c2c59e3250dfbf0c9c3ee3d0556fa7df5"
+            "208d4a2af4dc7bb84b3974afd12434062abaf9e11a8e254ad6976b2570188eca"
+            "414db45827e31d7b7d6f01004a4ac0d0c98465f40763b387809e01e1f356f29f"
+            "5ee2982970af55e6d40576b378a39e71c910ed128532085948c17ca4ff201ad4"
+            "c34931a5713b4ac5d27f608c14eec3f3ac073ddd0b76a3f380ef70674b8e336f"
+            "1cd32a5ac5c0035c43f564150657e8700ffc3f331880b0afdcda3a4fa3612256"
+            "52e7e44264944275acd0fa901488c3f3a47fed7f0bfb45fd309d9fe920d3af09"
+            "bd381b59d1377131c453da1948314b16850946bb94bcb77fbe5c95ea2cd7d26c"
+            "cb225614a14499c6b1ee868bd78e72878c8504f7310869008b6bdf54b4064925"
+            "a267e12de45a25aa07096f342391fceef8970fdc9f18652ddf0257a99ad97220"
+            "6f3b9fcf713ce0efab7c71e94921cc3c4fedffea6a21dc88baff4d977286fd0a"
+            "d8e27c468374f5b4546c4f2a5c283f5e24642627fbe29629bd2b9426c876d93e"
+            "6b01f9d75e151c5747a91a77403f6798ce4449d1461b10e05828244b08935923"
+            "46247a89f1fdb6dcbe32fd5d29bc7006af965ea4e2ccdc96b4cbc93a8057dd5f"
+            "123feff0ef35a656b9495136c271b54304c10022f5494ae83ae405f827a0b751"
+            "5fa57c809a0d8e47affcb4850e83a70e2e11d24b357b6b774f3f1b879720653f"
+            "2f6d032cd3e143b7f7857f18c555bcf4ea8e415caa6f1508e2bf8110574b8f51"
+            "1e45392fbded91dcf01e3f19156ae0dc34d6cb6f0843ab1e8b8639fd9f6040a2"
+            "58613cdbf974588acf4415d8ba35f23d18d50dd87dca2bfea9bd586e55b7909d"
+            "423273d850191e9c25bcbc52d5e20501552261e4a4d27cb0511456ed87139336"
+            "37a41d1b2a8bb9aca88665e4601caaee6d3fea4cd0038adbb5aa91aae2f9a937"
+            "aab898df85a6551514e61d485ab4eb749d3553ff1197ff674c163e53e7a74e86"
+            "9e42fd1839b700822d5e4393db4833b20bc7cb7abe0176f264fd95d50de5ea79"
+            "ad827be32db678ddac42b1a0ad4d369ba539349eb257406ec3cb2c9b45c73acd"
+            "4b"),
+        openssl_shared_secret: &hex!("73967bb51f45d39aaee9097ec2facbb604c7a22800934fd74f60f55b665b56f2"
+            "ca310dc56d33c340f014544341e045d67744b40ba0d7b69868f80a782f9676e2"),
+    },
+    // SecP384r1MLKEM1024
+    Vector {
+        group: 0x11ED,
+        client_random: &hex!("2c8417c3d7f0a67ee3f9d0d3f4332b1dbbc8a3d57a6770393cce612e9ac5d1a8"
+            "506d8a4763a56f7cb7db95d91f6d95f73db7528b40292bf852243f6663b29328"
+            "1410f99b30afb1a01d76353bbfae6fe23c9d140ed3837b934b3896673929994f"
+            "6504faafed6a2a6e10974edc1827c8e7"),
+        client_share: &hex!("046448a58581f058af690821204b6db67490c1a11f34913e5e97b47fca03d730"
+            "93fddef88bf4377bcd3234e10971632a4ece0870fbc96f02aacd4bf35c0990c3"
+            "b644e4c1b8d3ea21aab1b5b699860f2e45c5913fee980aa1c92b2863ac28e769"
+            "538c365825f674fe42c4e0649b01c71250813e04c77f905536c6687926568a7d"
+            "09aa26b272dc88201d411197c900fba20ceb941a8a07cb55ba9437974caf0819"
+            "f36c70b9a1023b369331ec83dc82839e17c511e44aedd42a7c5315a7d12e1809"
+            "c18b60a4325b9ea9f47e1957360c266931d943d26b204fb630918b53b9aa1573"
+            "95ceed8068492091ecb3a6310bc085264229fac6664c759564ceaca75527f31b"
+            "bde23e445a9e68d875fff4c6ea71cd0ec5720e776e87e36205b1acd82c93fc14"
+            "35b811ac5dd6b86880aa1fbc2e685c2f9e63aba59c50390872e86638c36a2155"
+            "eabe268cb630f56027e923dae0a42ea1955ac8818bfa8a8ff6c6762c7fcd27bc"
+            "aa4581d83bbdbae6ca5d554a448850d5c17905f1680681005e39146f68725490"
+            "38de477bbce23487972d68fb8434892b37445bb5fc43705491f2dc76882283a1"
+            "835b94c5785079b054a85295037d312678d526855a641df123cc5bfc89b25087"
+            "2c1298edf6305d9011168595ab450690f2547a028168cba74c76685131213039"
+            "979fc96f752a2f951396f86ab4403c73bba4cfe19c8d71bc074265c1324821f8"
+            "9a4f17c2510b7a0c8a729b4ffc7f9fb636dbe833f81a42b78b5713209689f65d"
+            "1e2a3b85114f9f93a6e9b43d9c822d784c11b6c4694e4bcee6e36d023811c7e9"
+            "b04d31b4efd26958752786ec43f781763b18128555287d395aadb07b82c3058a"
+            "796523128d48a60e9ffb46a880afebbbca4b3b7686abba4384b8830979be6145"
+            "86049cc9c64cb49ac986359a799613087a69e09ab85afa8f7f6a18d53506dbc3"
+            "5a04c27c965b40fdab421d3b0c307576a4b626f2aa7051382aecf424d3b23769"
+            "4010c33a1479559618264d041a2090d754d886bef4058bcc314a8677c2b3088b"
+            "907b981be62f1e931232b3b5c1a2cbb1c363d4978b88e154cd2b5ff47a5c9965"
+            "835fc36dab201bd66cc7f4b016f0409e2a7371ba6b2a2c6777a716b68ce5bb2e"
+            "8585a7121042db9e3171c51524bebb896d8e45af18950ac2a75ce40455ea2920"
+            "4b6a67e9b7117056c7bffcac5e48945a46c7e2a7bbf61700e17126eb7231f988"
+            "c742889f4037081e307070e04ab5f15d3e11c86e5093dbe817b9b5b28079a1a1"
+            "143f8b8bc3267372e7b25a7670556f01bc2161455619a6c74862fdf8af401236"
+            "d7b7666d8254dc610d9360871805446a01a3ea02a5c6685ba301c4e3051b49d7"
+            "3eba9783ff3637973b1e722778772c2860b15ad2e93181433af0a1a072888ac0"
+            "1c56ffe75153ac94ac9925d34947de767094d95136686cdca8b88a2c8fe04855"
+            "f6708341f89841f6b31c5a7850e01c89c87d9f57723b73bd01068ada1c8d13b9"
+            "ab64187a4523affea107d719600f4ac6520c4981391f07696a86a45be46343a7"
+            "9561c7b10853498005d4cc32c73519d66e6c21c17ccba2f9e8aed3dc1e359495"
+            "6efaa9709302432394ebd216af726168dc3e4ff183e802886531c39176b89c51"
+            "7022aca17b4b8115fb4cd1c14feca11a01d3ad6c34057bd520dff0c696984010"
+            "1bcdc344ce5c27a70e62cee851c7aa26299fd815e7851fc6c60e694b9aa09b2d"
+            "3c9a9331c01edc97c099413a4a89b33862983b39b6466ab062d69246854cba15"
+            "24f1029664b8337023a670808b97a5b99291b13be8b669cc942a0a480828b279"
+            "29c1aa05a5ee29702b8c10992167304497fbfb528034abf2e7766c233e0bb118"
+            "a7b3457af722f41c1bb2ac89a888c60ce454e7f079a8d66136dc3794fb80a853"
+            "951c61c4d1cbb9a116bed9d8995fa27319f16b4aabcdca6b439567616ba99411"
+            "c6cfb527b9f4840fad2c084331bfdac848d2061f24205c58fa8655a71077f0c0"
+            "df892e6fc937c8090ae9b2938268ac55ba8f52c047478ca427d8a9fd784d5831"
+            "418037b40480a74882367c484605ba5d702c3162c900fd06499df8782f8c6be2"
+            "74b2334397b58a31de209626e7c6ecfb29aac84eca5020e854756df53aaf1cbc"
+            "ed72503061b867468d82428de5e9aa1d21a2bd5a4ed65cc55bf191c4a6cc4541"
+            "5e022c5ba5c45df0d85bafd17bddf5892af43347c6cc8aa057450b0965d46d66"
+            "4b076a83193e630a2b4c53b55167f0ca4ea45b5683e19121e5ab94f6c38b13cb"
+            "72366fe64b10c70a0234d05498671a0416c271101deb4acaf2c0ae729bba5087"
+            "cb26e7be92048300b8bb1ce0c0b65148e27ac93e0fce8a58142d1c66056bdda9"
+            "d3"),
+        server_random: &hex!("2c0e900bf6e6563465b47cdf4ce5e1e0b4880d621e988248c4638427e1c57599"
+            "5cd03f8606f1725fb9091e344dc00deecbb367fa12b75e513cfd2ea735a0417f"
+            "0755827764f5ea4dc5fac3ae26f3a488"),
+        server_share: &hex!("04f7966da268bdfdad0d3db0c0514a9017e6300e2dcaca95ac8cff7a43a88ceb"
+            "be6a2dd388f3f7af82795a5be6218fc2e570c48fa0592c787455132d45adb468"
+            "78c29aed3977a7180d2803a6fe94e464b97e6018a0623774de3d5c5829202b06"
+            "7c54878e0e7e7bfd0c3c1e4cb2787543b0a9bdb07608e40a28e81444930d7f3b"
+            "5559a5f3908541da34d254107e1901f082040103beaeb2e8795f58bed25d40fc"
+            "e259663d94407fca9df3450f96eda1dab53815a01170a9a02bff1a870e220a4a"
+            "116ea881ae6d2b1678e620b82a810d9bd77c7aff125c79694b8f93fc3602bf0f"
+            "d9541e84d90fe8c81dc9abc3e1c9587cc640cefda65a53e87ee13c29701ddb1d"
+            "771fd35a107255a4d2e1336ebee5c95be656d491d8523bcbbc01ec4c3bac9aad"
+            "fb84b2396e0f5689d8a99803bb4b95ec04707b5e3557dbbaf7b1ac1395123229"
+            "7d34ff84048e27dbae84dd9eb9f8ad9d20ddb0a7adf45e0e96580b0539f0de2f"
+            "a27487c78e0aa7468bef26766c2f46852d7316cf4b8f82adf3a0f7355b30b9d6"
+            "66d7be7a81872113259fbba5c11772ab9373b4b06042a43966eb1a665b33aa3f"
+            "e0a0986c89d1ab5828ffdab2ad96cf1e3f678415b523efa803736c78fd996e15"
+            "866b4d7b5966bbb4d9fd35b3dbd0a17dbbec761f3509ab088b287c09f109f3a8"
+            "5ab77bf4870387fb2dd498a158fb6c1a8ae1b90dc950c9bf1d6a2892b1a55bd3"
+            "902a764eee98669e8e36d4c4b916a78e841dc3eda412bd4b42187c102d029e28"
+            "e935594853f6dc708749713a74ca8427fa22c50435a97e1bbd1caf4d43365c21"
+            "c2402830cd86e74575c4a412df3a81085e558c2a7893dfda1ab96c5b3d4ad52b"
+            "e860a2b82f279c667e441370873d4ea5260a933e5785b851b10eede2e35ed73b"
+            "f0b215dfeb8840fe53e869556c83128678b17e57471e730364d794a268b76134"
+            "19e3ec614e61223897baf408c317a0b61d5fd2f8592e1e2bfe00a95d53f63b56"
+            "083db43b3e2c13beae1bb1ad7552cb877645478580eba4b3f87c86d10dd417a8"
+            "37b7739d0236146b0930389cf4a18f9f38600f32268bbc7fc2fbf174ebac80b7"
+            "6a916b8a992f9e57f838e5cdd8cd7693db3c2dc398dc73d7a8a42e742984a940"
+            "78ebb1a3bef24e4c98f05e9d44c946ec6df3f015372ff1256ff5ff91cb1bfce1"
+            "c5f45ae82360a17c7069e5737ab80cf89e970ad8dc2a0fa2b9c8e0230ca8831c"
+            "1165a7f44bd455afdf2cfff430b7481e9fc29e756c7f7795044d1d27117e42b8"
+            "af4971ba0668cc544046661bb87e09ee1cd57c0fb1e883e95b318f604ad45fec"
+            "ff03011118afccabe6f2e78a1012160d5ad82e73c503ece5fe2b9d6a7f4523b0"
+            "a33b5549a50f13b71cc6b5c0931d68e90670d2515a8d208d00ec17a0a5b6a1f6"
+            "15ed01e65f2205b39554b52cc38533a84fcdf176f89514a93cf836b503f852c0"
+            "b212168179a92ebae6d597bf696f2b2f279059449444ee637c87e89788ea3ac4"
+            "895c0d05d7668e2fa55e4e463acb6280f738e707ed316e7e1507204056c75e20"
+            "41219009774fd11a2af71363aee5f7f68e3a9ed1630fe81716adf021d418b879"
+            "09e760ecdfcd25d39213f32b368bebe344f888f2afee9fa774311239278748c8"
+            "85818fcb0046e7c59e504407ef80c1a4cf4f5d28bd12b8d94df246785157bc74"
+            "45f40c6e480ef0a59eb1bc5791bf0491ea0756d7651384b3127162e8d7d63834"
+            "f9b194ccc8244b89a23ecfbcb4368ed897372fe23e72875a6d3d8df29d773c30"
+            "a918fd0651f19c2808503afcb86fcff455f87fa093c8abcd4cac5549ec64825c"
+            "2e0ae771210633f6c8809b10ee1aef0d8f5c217be159110d3a80ba36f73c4c9f"
+            "aa38074027ddd8bb904016d17330dd487ea63e4615bd3ec35aae79aed32ea9ee"
+            "556322e5156297fb4a7af42b04cb9ea0711af91031586264d67f6fbb0ac872cb"
+            "bc882814cc79b29565c3a2f580e4e7c4e69ac1b7a4ec58d71dfe441ca42d6191"
+            "80b070a9c520ed8785e878105c94c0da4f2dd10492ea52f6ae4e24044b3a9ff2"
+            "e30a4fdf1b7dd1535dcf8697032eb5e7e76725c482e9a134d42739e37c80a1b0"
+            "f8c62a83968d65ed147c64a711a515a3ead745c6634d081b7633c0a6fd6c1fe3"
+            "fe7c9fb3b99b7a6bce9078275d0aed3ebfd3a76975828dc1e84a250aeea87f2b"
+            "a8f8818768175b63b760d16bb7c9694aacd23505450c2a1946a5b138b3156335"
+            "555ee86dc52303b8de50f9e33af3f9d6fe01b0e76beb3dd62002e8102ed07222"
+            "40859e962a0697bf6b967a8010bf130c9b3ec96b403b8327fa479f50c86251d9"
+            "76b19e5b2dbbde2b1ce811624cb0729d7a909acbba988efebc9d748af7b2e232"
+            "df"),
+        shared_secret: &hex!("6dea14101dfeb6c69523f23c0280511a637968567a64ff819ac93a603f250143"
+            "e9674fc603327792353c68736fe3e426759393c282d18fb2186082b384a312a0"
+            "bb3266f6513b3f75b665e7f09603ab44"),
+        openssl_server_share: &hex!("04fb9edd4454e06c9fca744c3fc5c32e79136bad0e44ba69ea2b04fc5057eaf1"
+            "5318801ee33c5d42f47338e653c3fa560bedad17891093c5e225ab79ecca6c8c"
+            "9343df0d341a6e8deca758cd0deabcf3a574844c8d86ab57779d9d57b58450fe"
+            "e11e46b079e2c517dd97c98cb0b1066c664b245b4909c8478f8a3fd9862b2889"
+            "36df4d6709e9e5353a7535087ef094541931ed781eca90faa9a4609285d5d9ca"
+            "b6240c103a394c5fadfbca8340506df0b2fca3f80d33daaf76dc7dd1e3a208c5"
+            "c2ce7fa09407fee10341733629833b9bca052ff39ff1cec69b11d4328af2d18e"
+            "2cc30167cab8a36d1b9b641079634c918e20811101563ef056f1baa925bf976b"
+            "fc092faa4c3855f3e819906913dbb762bb66149b1d10b4f16ff561b4d49fae11"
+            "362bd62f6caafe4f09fec66dcfe19505a09f7f0358e0eee3ff235136a4757905"
+            "b3124ff431dd53e399b56cc3a8cedaab484b8f60c5dfd00697f75de47e58b95d"
+            "b1efa107b4b0a17e7436f722c7bdb6b44f49fea23fe9d863ba63c37b0f0532d8"
+            "6c5c56247a0025a4b65ef349cc41909741baca4a084543c8e6bb298b4cc027f3"
+            "87e6e8ff792d1294068926fe3e30ecb4a0fb2309bea064a1824377cc28a0adb6"
+            "3a76c01785a06296fe0cb0cedc373609ea510be60c158fb917bfed711a63b802"
+            "2c5dd80b5784c0d8aee7ee94177c05928be7e913d779d22906ede4f39d7d8357"
+            "b97b7a6518b6faba15f0a2c93a4596f5566de874955b6beee185bb71e89a0ab4"
+            "038a44f15c0aaa480cf867f2dbd5dd378a44a58631ec159e32b44575ca592d8e"
+            "3d75103a5750a13ae9a88262c283ef47d623ec028543f7afdd02ba5b8410e87a"
+            "c790b90d137664e199f420481a0ab1f6e3374a3287e7b4e2028bf6e82a931689"
+            "e34b7ab649de0f88283066d4517723f030e7339587ade3f37003402ef7c41e03"
+            "868c3e6c657b68ee53a38b92508274e871e92308861ff259a783791d04c30452"
+            "fd05c96558af8d0edfb6e0b8bbb5f274cd3020f02df3da1130c91237b50d5463"
+            "0625166fa16d9a4edce7d4342b74f47b9102aea6bd049ddc440d9c58cfc934e2"
+            "4d6aa09afea79b674561721de994e8eea109bde21705388063736c3c507e8376"
+            "c6d1fd1886cc0019d53cd172acb01326346381e81f5d1e98f9958b79ada5515a"
+            "17d09f53d19baca63ae699fc932aec276e1e40f9b993fce3f49437b888b6f343"
+            "1bc1bcc00dce77c8435dd2bdc905560464199b5f991ccb0189844881a3d275f0"
+            "ae28f03e6e3d79bd4a7f5a0d840445be066fe342356dc17edbdb28399f009cdc"
+            "d0761d894ef1a156db8c581e1b15f5353f92909dbb0888c99e89c566fd03c147"
+            "1c0704e5fe4b2a417372110105fbde207000e57f3a8c64f9223cc0b862c5eb23"
+            "2505a759125231c949f7b39f0b656cbdfafcf271c65677353805c5fe1bd3fcc0"
+            "eba06545fab43f0cc56820233cf869886cf4c358353650a82e110c14ae26b025"
+            "d3e11d1fce603c03c3706b34a9cbcb26f92963ffb1a52ee242fd6ed991c3df6d"
+            "2a050b34c0a768e85a4003c24f3b1de0e7b7dcfd21d408f18e2911e4cf4c2cbf"
+            "676f09ea9846a6903ee505c842cce38fa2e58f01c3581cbaee04027aa1fe23ce"
+            "74f90d06cc4053c70dcb0ceb9f2f61d12ededf877bdfdc1d6c4428c60eff8da5"
+            "57435ee5f8f5f558bd4c2256ed757c511159b76b0f28a82c974e97272b0869d2"
+            "ac7add0b2be7fcc41fcd77179582d1b3667255b747365158026df8c4a2a822c6"
+            "2c3844836184b3142864c0a5f47ab657a140f5526f874d36c873faf320878233"
+            "9043f232b3f073e239c85bd2842b900aa93f7e7455426fe3e6f646ff197344fe"
+            "13042f53957d1baaed2c60eb5c4ec6cf319cb31e1d472d06a92c9fe64b4ea545"
+            "c9d0b269162a7f4198368d5cad1b3bc83cf4203d0675d7eb8caf7735fd1a3650"
+            "f4babf511776b4660dee08c7b5ec5a7bacbc4820adcbd179c291ba3ad60eaf26"
+            "8b96afb1f1b226ac2cbc400f8da02988ba721a6cdee6a7727e23c4013f24659a"
+            "5f33421bd8c27f4db348dce921ce5269fa318f5cfc03b9715defa2793945b3a0"
+            "e8d45ad11063ec20be4827e9b602fad4629689e583fd01598fd3eb0154f7cb61"
+            "68bd6a07d63a74a5baf05686b76d06ad77a77d57f5d8fa9c30f439be30a4048c"
+            "66d30e4941071be234284884aa251c19b3d1e63293b7c5e3227615b9b30dfc05"
+            "4462b0e37464a8cedc2b281e379b3663a839a65ad017943e575961348e8205ba"
+            "1a2936c7c781da43acea2311ee608e91d87ace160569de5bf9f703439f26c14a"
+            "9a0bca5bfa869f560dd364b96529bf3c75981891e993f07e075c63f1df4a9eab"
+            "d6"),
+        openssl_shared_secret: &hex!("a18189510f03f093b4576a921f85b0b4de0d8ecc3e4ece714191089bcffd6ca0"
+            "b64005644ac3fa06ec46d8f3a4429393d15be31a33855a541cf862b51919cf65"
+            "51b5795fa13813035b7de6d560d500d3"),
+    },
+    // MLKEM768
+    Vector {
+        group: 0x0201,
+        client_random: &hex!("fce78b01a749ed9d3b296646fd6afc9d558d195c9d26e8a184866d1f15b1a67e"
+            "22edcafbf8bcd0cb575051bbb8d5c456cf6df2f18ac65678bc2a9a7f03a798c4"),
+        client_share: &hex!("e82977b6d092b0e12bfca580bb0089ca2509f09aa5af62a819f4947f975084c1"
+            "63687b2a3c666d94ec7a205c966d37a16006cb08616e4e0a1f5f77ca432a35db"
+            "73b23e98c77054a2e64312846ab4dffb7ba65aa94888a164b6a8cce33df0f9ba"
+            "8bda8f24e705e19371a0d735e8609074d6b0f48c541e9c2953c0b851fa396649"
+            "7387b49807e1bf4197001274cd2ecaafe1d3b39c374564752545a3455cac229c"
+            "c72bfde315deda5bbc0b2a6796a728f30812229dd0e0b64759ae171412717b6c"
+            "a2e66311406939da40c0b20d047ca995fc392e0911fe93c7d3177c4191b9b1f7"
+            "7a54359727ba50d0bc1989a9c02ec921403b96473a889958445f255f566716f1"
+            "59419f350c1bf571af7aaa656291b40b142ec96b25a818cbd7b0428088d22cb3"
+            "e28a139263534e20ccd60661c637b5be9a051cc33835163d52c6c5729a2429b3"
+            "8750f71205009177fbac9ab7cf92405d1881a683eb4f39fa133d56573db60690"
+            "c374c50799e82820ebf616966772c64bc4d43a36bfdb96cbc2a72f8603b9174d"
+            "b4723982451ec3978c7bc51c0a51940466bbaa13589a47ce6205941fb0ab76b8"
+            "6a4b555ca953b8492149699bc440d29cd52b54366a0e0ca0776a64a6eb448b01"
+            "61036745c36dfa08544c482d5371503703a591c19efc09bdfcb06697bf6cf2c0"
+            "435b24fff27a058275e0d86bf69369c60095d687733d18555f9015c8ab8712b1"
+            "056c608d136102b84c89a8136e00bc91e9421f9bc720ad9a3ba6d96fcaf9711e"
+            "570de9d73d8fab6a60535628713dab5013bb6677aa359f63aabc42db9337b04e"
+            "e159ced2cb9530d6916ba83a0213c84aa24be25a4f18b966246a1ff2da916c30"
+            "598a79a26b01b2285a2985b68b1d7c508cf4b5c0d183c539272fe27ad1d9ab4e"
+            "0347b210b28336c59f25c473bc2b04c89023b2cfa0eaa71e3a31df074dec0b65"
+            "a1059cfdea5ba6499126f2692f639f73c632f67a805f729608a2bba852562f87"
+            "6261f515f3d730b0312ac3059a33a971167cb86e723e90c0aaf6a63725960153"
+            "a38d02ea23c5190954d82a4d01314eac867bbb813a7635f7fc698149a69c99b1"
+            "7567937776b2f9b77f2753bd279a093da42819c2b0e7b8ca47193786479a6f38"
+            "0087498a101bc069c7cd29cc2e454ab86892adc5f2aac0c454376a7b63e0b5a9"
+            "e340482c5a062958e009c09459480e7aaaf41264ec631d1722a7dbe9ae685216"
+            "8f301392ec6d9af61aae0a5f9f40216746bf6f1782914b0b870c4c88b27bf072"
+            "9f03644521f062664597b5780bb1709adb046c46d58753693081b62b7179b213"
+            "fa33f2131b5ec18c732757fa65187ef70f5e4a0cbf1a63db82c6ed55705c8595"
+            "8b518939240764d36778e1841158bba777bf136976e696ca97e4cbdfb58da5fa"
+            "100d167bc7fcc0a575902d02792a13cce82792a1a73ec20a49a36ba84dd81f72"
+            "5412d454431fd06c0bb1ab6747944f350ac9362d6c0c64f854267d95838a5b05"
+            "5f4790afebc5b6a9739c52209db4431e9968b9156f39076f5e58c79ee7aefb60"
+            "89261ca21298b8a1444c5ae0790ae247efc48cf71c11bbba8390a97c89d579f5"
+            "832838c5bbc091825ff99c7ae78b1a6bc8f6cbc40bd155945a536bb37528db6f"
+            "65a34639e3e3ed264d0fda2b5fb48aa8ca20e996cf646f83d139683a874d2dee"),
+        server_random: &hex!("443bc92df12ce01d8e7f6048afeda74a9cc273fe45a18e1d780017de73e88eec"),
+        server_share: &hex!("9782ef98e7e3e208dcfe26f5a35b1f5105b5760ad80ccab4c7bb116f23a0be78"
+            "647886121eadbfc67fde5fade31fef37d5a3a9938793f37dfdc6fc7bc8ac6312"
+            "684ca60aff766f53d789235a34c90796e34c407fb0bc0bc62368061524eac2df"
+            "aac61b4b7e3979b1b48eeca7f4d8398a51ed109f3e0404ed4de6316dec77cf29"
+            "c9f0df267f5054ddcafb2fb13f464b8eeef4b08be3e99b3ed3788863f7e3baac"
+            "e9a507f87b9775179a6650d4053fb542a04718ec03ee73980224b1e93e22b0b5"
+            "075942db76f91b3ed13c3510ca14b849f7a64982580deb85557231f84f7cfc6d"
+            "914936225e65350f5810f42ec401c6c701645b4c740caa3ec8c857c51419ec27"
+            "5fd4c9cccb2905d3b4e9d71cb90a626838ffaa171751cc81669eae9cda64929f"
+            "6a50c740d31213df57cc658f84f915cef42b7e2213e409379ed7f1b8a9150597"
+            "7d0e0d60cf3834e6c5c15fc7f356b80275ce82cc1a10a8483117fc765c85a64d"
+            "61bb3da415e2b1f54a5a11382e46f8bc7f2d2b73105dab99c086014f53d3d0ff"
+            "cd183ba51c7f4df922c2b6c3870e61aad80b10275e729697e70110fbe9952650"
+            "d21790594291ddeef0c845a6c458ca26c1cd55590cf1e905f0bd5f91c91bc49c"
+            "68939c9899c4e444ad321c528b006bb8f1b82dad3611014941fa10da603679e5"
+            "635eadfcd9612179a978c9ff369f68a33270f94ab1f0092bd664171672403b24"
+            "889cfe328cafe87f9eeef6f0363e764e9badf254241511fe2f88358e0cd7fc34"
+            "6e3c31e35a2b271a0f67284f9e17c1ea07c27b0a8a4487cd6dd28a51eaf7006a"
+            "ca47d39363350ad68bedaceacbe640326b20967d1634243af5d2a0cacbc0b3b1"
+            "3026ebba7a3178a5655a36632eb16ad2dc4eb8333c5b6f930979855edd7455e8"
+            "b9137ab2d694659ab36b0286994b0782f474a45124eee66c7c83048eb7cdaf02"
+            "b3399907f907988f8ba4feb58fe46960415e578947e273527980e7c17d4000f3"
+            "a7609c61c5c90319ceba2d1468bec55dbd4fbae028c733c799fa4eafe2ffd7f1"
+            "d02ce19c0f94104a82cf8fdd9b97ec9e167329a20c21e4741047e8ad847f277d"
+            "852ecae30977eea0dd95ee183dfcb54576f922c46a8b6becd6c7e477abc78aba"
+            "154d041fab3a6c3a5bbcf7935a1375e7de19ea71d04e91e54e0d2502aa704c54"
+            "2a23ff1856d36e5308c8684601832da20cdbeefa3a2d5c5bd088a987eee08627"
+            "b9155a1a048648d6dc1c0834ac3c194ef8d2cf3ec89d56133f0ee071721a9385"
+            "e5bd160b55d6110ecd90d44457f5822b3f01eece2706e8d78e014f9dec1a5d1d"
+            "4e54c21d5d2778ddca19bf8ecc8835bc05a0d3827fe0bf1f2ad086ffd8b2a904"
+            "31d97881ec566d58c126efc48ada84538b8365931cfcc78a230d284ecb5a757b"
+            "f319878f1058247ac15d4fcfc6daf19e8d022556412826434657f410b23a96f7"
+            "d6c4378cfbfb087f9acda1765a82d84e3d631c993c0bc1ded3bf4eacf6737fbe"
+            "f1612d3f41b8a37928e59d4f6831ce9be88de8c5d86198a359e9ca3ed0627156"),
+        shared_secret: &hex!("1c266e82846428ee3266de6a3a00f2cfa10eb520846726ae28c5e1707dfcbcc7"),
+        openssl_server_share: &hex!("938b15ad0617bcefc25f817b7310418e9891a17bcf277937323418918aa9c2a8"
+            "c0dbcc014a9f2db95f20376846975e59b5b22fcfefa49b864c353269da68b5cd"
+            "22c95fe1880cc09b89713dd4d083e49dd6428022e61efba700cb6472038ef612"
+            "81e66aec0f7de61dd13ea6e4f4c33ea7ca6f6c155cfc7b7b7b04409c6a5764f9"
+            "76e4acf06e56c227b313e7dc5c241f37e294aca9ae95d60edf9e07f83e25552f"
+            "8df0e05b7469619fec7bb34731363a20f9271128002d9582a2bb7f3b8a7e9c41"
+            "2ce8e3c2dd549930f130567af57c151bc7308f2a235e12549f644f7ee3d303bb"
+            "3c3cdb68949ccd2e8bb5ffdf3650f777a54ff45e98e60676eb5a03d34ea666ca"
+            "db4a34feda4375bc8a1dcef2aa4ac75bcde7cffedf2d09ca0b6ea9d37679c860"
+            "0edfabc09877aacc023ae3540f2d77af59e97fb5819f473aee5f80f9320fa9c9"
+            "8e231868ae6559d2ca5e14703559ac5dfd43c9c69fa069c25486ab7f462ea51c"
+            "d82a1c515a201b3ec0294fa42d1636faf55eeacb6653b9a32b02dc4f45eb4e24"
+            "33037cff59d7e4d9071fb33d19a9f909b6f40f529d07126705368274b32ce89f"
+            "48a5049e6cda0b97ec5f0bd4c920506d2ceaa3a30f91898292b0fee564abaec0"
+            "fb8f51f2d8a3736eff57ba95e7de4e841a0a0243722a87dbcf0391ec05997925"
+            "41898902c5a4b03fff018f648a808a922489ecf84c3e031f7321cbf09ac45367"
+            "b296e62ea43d1e6e97f31c54ffbabb5e4ba197c5f2e4af8504f30b1398b252ae"
+            "a1b098743c9a656765e7bed29c45f5f9fc90173a5b301ee95c5333b53556dea7"
+            "e7defa7b5dcedefb45cf210738eb572e84af6bb56156a038ef4a7dad5832b8e8"
+            "08e7a6ebd96e80843e0c487b304b39c2a9fc2fee6acf10e259c4cfa43faedf4e"
+            "42175ab2c89d77a5f4b18b7018353e1fc95a8c1534fc356a8ff1f0fc8b743435"
+            "61038183ad056a811d09acde268475f2b5464e43ef80191d5f3183a3bfcd461c"
+            "60c51bf0fda5d47956aaa26a3c0007f8748aa53c8d11f7297afa01e5b61c2e85"
+            "e8ad8318b8c7e979ec3784a35445fffb13e6cee48dbfc25eab4d5ca508b094e7"
+            "8a7d6f66e39289d6237934e9f3c846086c890a0c827bf0d09690a42ad6b9e87d"
+            "0dce076e3104ceac7b6c803e6da31a36d656dc9d34ea412db8d8323c3c73bc20"
+            "5a0e1f6325a36d8e4bc6029b200d52320d24e6a3210e5c945ecd18f6d24f95fa"
+            "a2e753f8b958f1254b0a6bb93aee59b0f70c3cc46fee7985019159d80ef207ca"
+            "57bfe0a822224909376b9db32fa83ede4aa140ea6fc8ddce7cd4b77a22eab5e0"
+            "d52d90df8612d9f79d7101512b2355600e6ee193be5f60129dee8a7cefd8fc51"
+            "29a2f31ad220a02bd9c10f3352695ca465fffdb95fd5c8fc30c1b5dbb0fdecbc"
+            "3b6126e3af424d81d2e3ed78b03ae3ca69ade99783f4f8a8e27e0c573d217273"
+            "ee78d72181db5a0eb3f1db6722dd22ef972fbe8faaa34ce0b52f3fcd2b10fd96"
+            "6b38b42c73ed3bcecc48f9afdb51aa4e089579b2707493cedb9723c2d5679a89"),
+        openssl_shared_secret: &hex!(
+            "070c345a1516a34bdfb36dfe28c097f1b86c513514b4e08371e2e6fe68503656"
+        ),
+    },
+    // MLKEM1024
+    Vector {
+        group: 0x0202,
+        client_random: &hex!("5e995e412746aaed8b697d8618296a3b0920e543a93300d5b2e423008deb541c"
+            "8b853175d000a5c5f02f461ca7233ce767efad87821816253bf5dccd42f20f50"),
+        client_share: &hex!("18a61dcd306018d146863028bb569368012830392e70d553ead7a1d10b6e057b"
+            "a7480a230d3b04d761035206af332bc02d9b2885276abf4cc4987b8bdb09b91a"
+            "9a97ded937bc7b182ffbbe31b7481b654e1afc218efa622a7b805426b5052185"
+            "646b11818052491181dd7ca10d4811eb1255b7e98800565015e7449af54a98c1"
+            "c6a043008cfc3627421af1e05782d5c90f434c8b654a2bacaaf788bf0b0bcc08"
+            "4a90ea619942cbb5ac6a411666aa13f84a494a43af51c38f528b404a7a38545b"
+            "3a192a90199b7a76079e265d9d9a1a427c03c52409a93c875ee3181d132c4917"
+            "99b8659bef11b3b0992c8f3570cc0553c31bc648395a24ba34d997cda8753b05"
+            "764f34b112d5558030a958c7eb1b7b9481c9f22b7923b53465b2eea53741c994"
+            "34bb5bf644b51c9b47ea856950205f00f43bab48bee8882fca8b6b26d15a587b"
+            "8a0d2068eec488fffc62c5814f150371aa5212563c41819a6d6c397a925a7771"
+            "b65fa8c9c096584740e05078d92cf4703b595b8b26e488a47bbd9ea2373d112a"
+            "88e90e3b73bbbf5ca0c6ba7f08b49e79907cbc534466730bfc392830ec337e10"
+            "15147c6359cc8eb6e27c214a0988528c1f137aad97bc91a1a81bd3841d108f0a"
+            "01c0859a5f2a48cc4c9830f509c0ef1919a68171fd5aaef59936910cc43d1452"
+            "10929b0f824a38b65993d736eab7c7193852a64684c33bc464c376a274379cd6"
+            "36e2c18be344bfe3607e84126a2e80911fe42d77a02872e9a259630eafc23778"
+            "5cba481b6b94540511545700c33f97dc369cd10bdff57dc5817bb926876b8663"
+            "504b1ce3720d05622bbf849c18894b86bc90c2b827e0b68f097c62b841ca7532"
+            "8fe40c8f50631427a85c3186992730be2ae66aa28b1cb2b2111838a6f7f9100c"
+            "309de6630f110072008a6688d06f2fc30e089089ac045b5970b0d00102798490"
+            "3f75b851ac60e7d273f5f845e9192b0a59642267088d0b2230486502a450ac51"
+            "5a5908cce31a815d8610c8264602d928da81c717e90204d42b0a07cc70b0532f"
+            "e50207c9ba8c95b179b3b3384b15fcaa9400ec0098313813748041f75aaf8231"
+            "7b446a69043d7da4737bdac99eb58da1d89723ac20709c5347f73754ec3b1944"
+            "00ff857733a39196f7856a7c4b5e6b4ce804015296239c58c52c5b9aba186a00"
+            "f64d191b865cb825c7035e69ecafbc0409edba736e50461c768939bb46527a84"
+            "74aacf151802f1a9bc1f9b079c8c47b6137f0129baf9f87e5eb9298d8904f3c3"
+            "4009a85dbf8984e302a41c4a9bd7638b44f98cd1872e3d669239a2816ceac61a"
+            "9b59d9d57621b6028a63a44222ce200237f591cc60624862f9b23ea7a6bdc585"
+            "6f45410874b0bfd534c71663b54c8c6f321156cc97dddb970b58b1a50471acd9"
+            "8a2045ae26813eea14c9529a1cf45681117b1b525cb8fef79b6d7657ef6333b7"
+            "c03ab95ba41cec65e3721911058e704166bd5057f759b2c7ac46547462b8979b"
+            "0cb0bbdcc189041879c38a46860a4a789ab24dd25bcfc10010a16dec59a308f9"
+            "a193cb0dfb51760e1929029a53b187c497e60701d059102827f74a88f53928bd"
+            "f809e129a54100160207ae046a7711c6ac5a70761b2404a41b89c9852c6b250c"
+            "d5743ee0a74536b881e0d974c52c5d17ab8e10371d9b008d1565506f2c00c2a8"
+            "be93f4814be26263386a560427fca3255e0031f3619e6358c5245a94e1476c3e"
+            "0532e1926dfa7b5c95508f554a6844e13849d1839be1265bd51395054d18b04d"
+            "10180780fb6f4376ced1ec7051882fd5e6c566f1164348c1aaa61d7b28b2f6d6"
+            "8eb89c69f4294818a06648d9b0009c302cc60832911bf5356621881ca6fcb922"
+            "088190eabc00d74722e772e3bc708ad79c8a5c3fea485a9fab7120e13b0fe59a"
+            "608b97beb5158e426ee16b5b85e06a71ac476d764581e410c6403e755908ce88"
+            "a7321432a17c65aacc6ae5a709bd147633cc7759c445df53bab8543361d46a01"
+            "19996898622ce98a41394cd652ba48f43d9ab206cd43145974cf117ca7e6cc68"
+            "0e52b9faea23d94467a6531748469360bc5c7d7a438ea703aeaa374c8760d573"
+            "c425d4b5df33c1e8bb2ca78bab0da17ee3f6785786c636a73896093b0d7bc37b"
+            "f63206185fea28567d4603f11b0d3ab31685b429db337e4ff73d9a0493012a7d"
+            "614d5f6c9b1a07bd8371c2ebd4a878f77cbd76332833b3e6a253be3d89140f49"),
+        server_random: &hex!("2986e437c532c6665d7a7259320f6e5247d4c0fcc0d7629a0d3481dd1fd9bc8e"),
+        server_share: &hex!("b7e62b0a05885a4228a51fdd303a6bba0ab5b136f34ee7ee0017291e2e239b62"
+            "972c7a51dead5c99508ada602bde2484d11cdf9322137e72f01d3eecd13f6f5b"
+            "9463cfb7f0b022927f377d2a55f8eb1e794b31e63097764e8628f6fc14f6b213"
+            "1bd6e03bd4405e0d5dab005f26f6f0eb04c824f70a237370807f05d56fe4f2ec"
+            "63148aec0303e081aa84298831992237915c673620795c48b2ebd89411c674f6"
+            "cbe47b93b4dd93bc83a4e07844461fcd3b4d8994cce8859aa8ba6fcc27418a6d"
+            "9daf7c5d6de61a093a9cc068b4040b21b081b0c6cb65167ef6864a1716f725a2"
+            "7921921efdb11524df84bd8098c82ffb94fd9f26fb4b29ae6e840ff0386d0dab"
+            "15f0e7c4aaa40dfdcb740ef63a79aa8500d8ef9a98615c4a9910f41af056bcee"
+            "4e2984c098be6dda7b6fa0f794815ab395b748fbfa366cb691bb65e832886c28"
+            "cb457925c5e8d4e614167dcf8a29ef11964f86c53b4a6d20ebf13eba2ba9c302"
+            "204ac00fe4025287f0619f721a74f8598883975f880314e8e603a081e5b0b442"
+            "dd11c4f6101d1e2be137a399ad252e89493d55f48de0585b6a29b53980406691"
+            "5c1e21b1365d35b55cccad753c4e7a3509b305343f89a4a9e6ce652416f20793"
+            "6c0a997a7a8d0d1e4c4a6b965c67993c945c3ae0d72183206ee1d879329739d7"
+            "dc2cd8d2e1669e247037db6ea5b2e9ae1a60e95b59d6569ca22f47743ba3d516"
+            "1e52118914d4c46e57d58db68f885086c400a7b3c1a591d94299c61d93020f53"
+            "b9915f81b12d769d30f8d967b6b12692c2123da17c8c98e9a42a41a462323c10"
+            "8deb65e44bb93e82fd4e9adf676667d8fc480f4b360d309f6011752a64a1cb58"
+            "0fa11a24e3b74b8616c3476a0f75916912920925a3d5677c92a0af182e08ba0a"
+            "c6cf17beb90a0522c8b1b9d8b2b138b3c39a0ebcb853f0f4e80ef8817fab3e35"
+            "7cbadb1106f0b6913e1989dd07e816104c1a324557be7dfa496e2503fd05360e"
+            "3fccc1316460b6d0d28c362d72e32b4a099ef0dfdb3e27797a37295ecb320bef"
+            "d49f737873fc3d71f256bd575dc73aeaf134e2205f053a237b6ddfa92cb0d30e"
+            "968ba031b8e76bfaaf5fd47209678bc550f35e62313023b28ee4e5a640fb985d"
+            "06526f4c5c9b41dd9d84510ae6d49f86e2d1b3490e241442c1e87fb1ec64a1ef"
+            "44eb98b1b90bd81814abff9eaac7913c62383e1cc8f2a7e5b853229c74fed5c2"
+            "8d0f8bc7c41f9f1e15220033cbdc850308945b5ac50ce6366905816f195dc286"
+            "4a5f2ca7a304c707d6f20298f61c6a222ecfe22e130988b2f39c463fe68f9ecc"
+            "b8e03f0649392c18b96c6b3cdfd697e304fbc6449123dabad426886526fdff03"
+            "a105fb8dffc2282b32c3e06ecd3b5bf28ae763f0ab3439b62c4f0f93f75efcad"
+            "1fa395a59c12c5c3de7054c3183ff41436f7af634582d58b770d3b2243fe5731"
+            "31ec3290cca22be607c44be92416fd7bb0812376d556270a25083b3f11a2dd64"
+            "185c192b89137f9ac7a2fa53b627239c0a163224cae51aaf14b78fcecfb2b9cf"
+            "dbaccd3fa85dc4955b49915fb68d4cb3d15b0b3f22bd7aad4111852854c9699d"
+            "2cabc2d8e4dcd1cbee04f1ae8f8e7910ca8e59b182fcc72d6569b37537886303"
+            "9cdc7a7283a6b6f512833f0c7d1f6043e0c673fc0a95f48bd6884ce9cce3ce67"
+            "bbe209fb205e4214b2636b85dcc7a188cbed97956c84cbe8fac2b0b060a08fce"
+            "11a743098028f98eaa64a444ffad5c7e922361c51d5a082aeb8404f9db19296f"
+            "f4c3fb03a6a4fba046d1699af9fa51b220487f25e7f7dd6eec9368107fee876a"
+            "f07c4ba24734ab28be1001f9ddd02e5b3ca23f0888e3fe6fe2384bdde69a7914"
+            "9424e345385fdadf34d558e55c67f03f781ab93332ba90e49c44f7cf32f18639"
+            "1d5eb75766323faa8cce68dbba1e21fc20f5f00f56a65b0babe0c37b5339dbf1"
+            "508c5ddb19063f82ea50cdb982376a99ce78ae347a22d010ca8f5e247e33ab73"
+            "f1426a5cc526fffe230930c9bb277c291a34053f3ef2c3117fd5532ecaa2bf58"
+            "2d22b4cbbdd95b685fc220d89dd61d6aede3d4c19ae39f47347923c2425f3ef5"
+            "3291628fbfe3bd6b71bbe09882b4721feb6a9c06041c3d470185590caa5ed6de"
+            "ed49ff7d58a3153ce8cff3aea0715adcd5b8d3c4da2b4eb5e1d45514af879a83"
+            "6d952fba05e20d4df06eecd55fd6a62658f64cb50ad13074ddb3db8665d79826"),
+        shared_secret: &hex!("63a30f1f1ee9753766ca7ab53e5d846c72d5b6c2815612dde83def4a4fff7a52"),
+        openssl_server_share: &hex!("83e671b6ed38e054d2fc84ee39c5cdf6c5971e42d5d642e4ba1ddd9aeaff78bb"
+            "2475eb047b00fef96ea9876ecc7e586ec32f0bd896d900293623a5d9bc513100"
+            "cac88a5115a54881446323f9d7234a4a402d7079c622d8a0445dcefcd88f782f"
+            "0fd70a162c078e3dd2438099299d55a1f83cf1f49a8ea47b5a26cc845ec776fd"
+            "89ca69de0452562779e38bddec231dfef3dea96689be9b1a7357fcf523501691"
+            "1f8714a28a093f4028a5955a3ff6a570dffcd97d58dc7e427934ff3ef05469c2"
+            "57804e2e81d61feb419fc6edd7e15966aafb04d8808f8f2f35788e3347187557"
+            "be2c24bfdcdd3b15acceab76b4c2f21bd8011ab4a6cd6417f7c8ee1a95191b22"
+            "276a176552a6953f8b206e2cb0e6d5c9e65f784581196034664b14b4fb4821f4"
+            "5d7bd5a6351b0ef3e20ef153ecf8f631b725d976ee5ea94543cb79d7c9ff11d0"
+            "c01e4eee28fcd30f24c24ffabc7206961a11404c6854515b40b5d68de51dc7cc"
+            "519ad9c6e085c5bcdef38e4ebad3a51ceeda5dee59084fd98942c2722e388d60"
+            "7425107eab985f0e16402f01ae9f2f83894a268165daae8fc5b1df1eff874e4d"
+            "3622bf99abf7a7e9fda4e45a15b78254d5a2a41be3f5568a87979472b7a2db46"
+            "a574a38277e050d50317c76cd71dd6dce81de036ae0386a32ee4eed760edb93c"
+            "656bcef7f8f0b7053b8d4bfedf71548dac11420cf9a70864e2318f9825ef6708"
+            "a1f63900cc15a14413fb4266739fb88857a4036dd2b16add597162475aaff6f2"
+            "fc32c21c0eb4f9a8a8bcd289468f074ad99ef89551bf2ed84f53289522ac985e"
+            "bcafc6f24fdca295f441da7ff595ffa6f3bb5efa1ddd0b1902558bfbaff590cf"
+            "900fdfad7d87485e3823c9a22b2343044b07d2e74fd5a9e37ce99162713ea716"
+            "2067a9fd1a522af704a4ddedd3fbadafb917e8a1f3d96bb90bd2d53ea9cdafa5"
+            "5bd750d58541ed0b712b69f103978f4ef084cbe55fed46ed26f7bbff91c2ba6a"
+            "690bb7693d8b7a424207019f6548b897a00781e2a87a08c4fab4b096892d8827"
+            "dee82421dc00dc253c2c1b52aa3c49662fb97231704647ac438d1857e977fff0"
+            "c1ed571fd7868daf08cb67e44809f83252815e907b15c9a3e35c9ef7a75ba5cd"
+            "c5fce3eb43ba33bcbf49585e272bb367520a87257f372781c24a80f81e5f69c7"
+            "6ff7892ab2498849dd6d3925fa703a09e76cc3837a5a7ecd0fe2eaa2b71b20f9"
+            "133942280387a53ccc3a1b16538ff70ac3fc03bd5f0bbe0381b95b67b11a6a4c"
+            "0a7b3110572289a7e7e8b0b7ecd5c5839765ca73938b77b52e67350f9e04e580"
+            "bd5cead16966c3376174b1ec5e9bf5d8b15e247ff8bc90135732ef5f7cd4b6ab"
+            "4fdff4f29998fd6d7f11524f0e4197ab511e56cb1cf95948746cae386de1e97c"
+            "b91ee3aed49d332a90f6e5af1eb08beb823d64c2a8feef7e7fa0ade5bcfe2322"
+            "4a8391ef451293ce36b396cbfa1317dd758b93f6f6c706fc4d3e39b834a1363d"
+            "f48c27f5a586a1e52d49df2aa4c77d76ddb823d17f864d89fe4fe2021ca267d6"
+            "99e4ac178398ad5f3648816b768c54627d18469f93d36ad42f4b241313d4ea25"
+            "b67cc3331c169562e56b9e777c149154f6b3814a9eb3f3c9d53e19d03f627b09"
+            "36b05befa6232b36d538f2b7af3695f334046a0a4195c6d3db41e1aee539b27a"
+            "9efdbfeebdf1c4d8c157dec245125966e80e44fc0c87f7da0f2f715feef189ef"
+            "590a8b8130a6901611b706a518529f5cca7f0168c53fc629a106591d329e5eb6"
+            "120349d807af28ac098df9d901ef564e2613fc96c5934b0a6f5acba255b6dcc6"
+            "8bf781c050ddf6adb0a27a9892dc242780dd92e352e7802bb44398c2569d7358"
+            "015ffcc066303b7450d01db06d7ce0ed6a363dc81c6bd4ab6ad23b36695f43c6"
+            "e76d888688fc5339e93b4ed1b18cf7b0a0084246d937cd791edb6baec06a56c9"
+            "951be1bb1b5e8d09cd01c82da904d562c5a1139d136ff7ff2dc4f21dcb3d9b94"
+            "86e05d94378c184adfacf9c954e3687f034a4f3c532b34ddca602a8a7b12f938"
+            "6da7c416445ca3cfab91885c3472ebee8089c51bdbd99af11a4da9e316e80861"
+            "32c1337716aaa15c12c4fa5110dc31172c8a9a9fe3931b1176a4f98d50c68c3e"
+            "c1cdb484c84361430505d623a01c57192b00f0c565f7b44fbfee9b1160de4b2c"
+            "f4af38dc44c86263f4dfa86b0dbbbfb67a0096f3625c0751575515e7f4cdd570"),
+        openssl_shared_secret: &hex!(
+            "0dedd4acff1a29bfb19d450d6d1d26f9c46ae98a2822106f0eff6ced51eb2444"
+        ),
+    },
+];
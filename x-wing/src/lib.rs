@@ -0,0 +1,513 @@
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![doc = include_str!("../README.md")]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
+)]
+#![warn(clippy::pedantic)] // Be pedantic by default
+#![deny(missing_docs)] // Require all public interfaces to be documented
+
+//! # Usage
+//!
+//! X-Wing is a hybrid KEM: an attacker has to break both ML-KEM-768 and X25519 to recover the
+//! shared key.  Its decapsulation key is a 32-byte seed, from which the ML-KEM-768 and X25519
+//! key pairs are expanded.
+//!
+//! ```
+//! # use ::kem::{Decapsulate, Encapsulate};
+//! let mut rng = rand::thread_rng();
+//!
+//! // Generate a (decapsulation key, encapsulation key) pair
+//! let (dk, ek) = x_wing::generate_key_pair(&mut rng);
+//!
+//! // Encapsulate a shared key to the holder of the decapsulation key
+//! let (ct, k_send) = ek.encapsulate(&mut rng)?;
+//!
+//! // Decapsulate the shared key
+//! let k_recv = dk.decapsulate(&ct)?;
+//! assert_eq!(k_send, k_recv);
+//!
+//! // The decapsulation key can be stored as its seed
+//! let dk = x_wing::DecapsulationKey::from(*dk.as_bytes());
+//! assert_eq!(dk.decapsulate(&ct)?, k_send);
+//! # Ok::<(), x_wing::Error>(())
+//! ```
+
+use ::kem::{Decapsulate, Encapsulate};
+use core::fmt;
+use ml_kem::{kem, Encoded, EncodedSizeUser, KemCore, MlKem768, MlKem768Params, Seed};
+use rand_core::CryptoRngCore;
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+use sha3::{Digest, Sha3_256, Shake256};
+use subtle::{Choice, ConstantTimeEq};
+use x25519_dalek::{PublicKey, StaticSecret};
+
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+pub use ml_kem::Error;
+
+/// The size of an encoded decapsulation key, which is a seed, in bytes
+pub const DECAPSULATION_KEY_SIZE: usize = 32;
+
+/// The size of an encoded encapsulation key, in bytes
+pub const ENCAPSULATION_KEY_SIZE: usize = ML_KEM_EK_SIZE + X25519_SIZE;
+
+/// The size of a ciphertext, in bytes
+pub const CIPHERTEXT_SIZE: usize = ML_KEM_CT_SIZE + X25519_SIZE;
+
+/// The size of a shared key, in bytes
+pub const SHARED_KEY_SIZE: usize = 32;
+
+const ML_KEM_EK_SIZE: usize = 1184;
+const ML_KEM_CT_SIZE: usize = 1088;
+const X25519_SIZE: usize = 32;
+
+/// The domain separator appended to the input of the combiner, `\.//^\` in ASCII
+const X_WING_LABEL: &[u8; 6] = br"\.//^\";
+
+/// A shared key produced by X-Wing
+pub type SharedKey = [u8; SHARED_KEY_SIZE];
+
+type MlKemDecapsulationKey = kem::DecapsulationKey<MlKem768Params>;
+type MlKemEncapsulationKey = kem::EncapsulationKey<MlKem768Params>;
+type MlKemCiphertext = ml_kem::Ciphertext<MlKem768>;
+
+/// Generate a new (decapsulation, encapsulation) key pair.
+///
+/// # Panics
+///
+/// Panics if the RNG fails to provide randomness.
+pub fn generate_key_pair(rng: &mut impl CryptoRngCore) -> (DecapsulationKey, EncapsulationKey) {
+    let dk = DecapsulationKey::generate(rng);
+    let ek = dk.encapsulation_key();
+    (dk, ek)
+}
+
+/// An X-Wing decapsulation key, which is stored as the 32-byte seed it is expanded from
+#[derive(Clone)]
+pub struct DecapsulationKey {
+    seed: [u8; DECAPSULATION_KEY_SIZE],
+    dk_m: MlKemDecapsulationKey,
+    sk_x: StaticSecret,
+    ek: EncapsulationKey,
+}
+
+impl DecapsulationKey {
+    /// Generate a new decapsulation key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the RNG fails to provide randomness.
+    pub fn generate(rng: &mut impl CryptoRngCore) -> Self {
+        Self::try_generate(rng).expect("RNG failure")
+    }
+
+    /// Generate a new decapsulation key, reporting a failure of the RNG instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Rng`] if the RNG fails to provide randomness.
+    pub fn try_generate(rng: &mut impl CryptoRngCore) -> Result<Self, Error> {
+        let mut seed = [0u8; DECAPSULATION_KEY_SIZE];
+        rng.try_fill_bytes(&mut seed).map_err(|_| Error::Rng)?;
+        let dk = Self::from(seed);
+
+        #[cfg(feature = "zeroize")]
+        seed.zeroize();
+
+        Ok(dk)
+    }
+
+    /// The seed from which this key was expanded, which is its encoded form
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8; DECAPSULATION_KEY_SIZE] {
+        &self.seed
+    }
+
+    /// The encapsulation key corresponding to this key
+    #[must_use]
+    pub fn encapsulation_key(&self) -> EncapsulationKey {
+        self.ek.clone()
+    }
+}
+
+impl From<[u8; DECAPSULATION_KEY_SIZE]> for DecapsulationKey {
+    /// Expand a decapsulation key from its seed, as in `expandDecapsulationKey`
+    fn from(seed: [u8; DECAPSULATION_KEY_SIZE]) -> Self {
+        let mut expanded = [0u8; 96];
+        Shake256::default()
+            .chain(seed)
+            .finalize_xof()
+            .read(&mut expanded);
+
+        let mut seed_m = Seed::default();
+        seed_m.copy_from_slice(&expanded[..64]);
+        let (dk_m, ek_m) = MlKem768::from_seed(&seed_m);
+
+        let mut sk_x = [0u8; X25519_SIZE];
+        sk_x.copy_from_slice(&expanded[64..]);
+        let sk_x = StaticSecret::from(sk_x);
+        let pk_x = PublicKey::from(&sk_x);
+
+        #[cfg(feature = "zeroize")]
+        {
+            expanded.zeroize();
+            seed_m.zeroize();
+        }
+
+        Self {
+            seed,
+            dk_m,
+            sk_x,
+            ek: EncapsulationKey { ek_m, pk_x },
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for DecapsulationKey {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        let seed: [u8; DECAPSULATION_KEY_SIZE] =
+            bytes.try_into().map_err(|_| Error::InvalidLength {
+                expected: DECAPSULATION_KEY_SIZE,
+                actual: bytes.len(),
+            })?;
+        Ok(Self::from(seed))
+    }
+}
+
+impl Decapsulate<Ciphertext, SharedKey> for DecapsulationKey {
+    // Only fails if ML-KEM is built with its `self-test` feature and is in the error state
+    type Error = Error;
+
+    fn decapsulate(&self, encapsulated_key: &Ciphertext) -> Result<SharedKey, Self::Error> {
+        let ss_m = self.dk_m.decapsulate(&encapsulated_key.ct_m)?;
+        let ss_x = self.sk_x.diffie_hellman(&encapsulated_key.ct_x);
+        Ok(combiner(
+            &ss_m,
+            ss_x.as_bytes(),
+            &encapsulated_key.ct_x,
+            &self.ek.pk_x,
+        ))
+    }
+}
+
+impl ConstantTimeEq for DecapsulationKey {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        // Everything else is expanded from the seed
+        self.seed.ct_eq(&other.seed)
+    }
+}
+
+impl PartialEq for DecapsulationKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl fmt::Debug for DecapsulationKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DecapsulationKey")
+            .field("ek", &self.ek)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for DecapsulationKey {
+    fn drop(&mut self) {
+        // `dk_m` and `sk_x` wipe themselves
+        self.seed.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl ZeroizeOnDrop for DecapsulationKey {}
+
+/// An X-Wing encapsulation key, which is the concatenation of an ML-KEM-768 encapsulation key
+/// and an X25519 public key
+#[derive(Clone, Debug, PartialEq)]
+pub struct EncapsulationKey {
+    ek_m: MlKemEncapsulationKey,
+    pk_x: PublicKey,
+}
+
+impl EncapsulationKey {
+    /// Encode this key as `ek_M || pk_X`
+    #[must_use]
+    pub fn as_bytes(&self) -> [u8; ENCAPSULATION_KEY_SIZE] {
+        let mut bytes = [0u8; ENCAPSULATION_KEY_SIZE];
+        let (ek_m, pk_x) = bytes.split_at_mut(ML_KEM_EK_SIZE);
+        ek_m.copy_from_slice(&self.ek_m.as_bytes());
+        pk_x.copy_from_slice(self.pk_x.as_bytes());
+        bytes
+    }
+
+    /// Parse an encapsulation key, performing the FIPS 203 modulus check on its ML-KEM-768
+    /// component.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidEncapsulationKey`] if the ML-KEM-768 encapsulation key is not
+    /// canonically encoded.
+    pub fn from_bytes(bytes: &[u8; ENCAPSULATION_KEY_SIZE]) -> Result<Self, Error> {
+        let (ek_m, pk_x) = bytes.split_at(ML_KEM_EK_SIZE);
+        let ek_m = MlKemEncapsulationKey::try_from(ek_m)?;
+        let pk_x = PublicKey::from(x25519(pk_x));
+        Ok(Self { ek_m, pk_x })
+    }
+
+    /// Encapsulate a shared key using the 64 bytes of randomness `eseed`, as in
+    /// `EncapsulateDerand`.  Note that this interface is not safe: In order for the KEM to be
+    /// secure, `eseed` must be randomly generated.
+    ///
+    /// # Panics
+    ///
+    /// If ML-KEM is built with its `self-test` feature, panics if it is in the error state.
+    #[cfg(feature = "deterministic")]
+    #[must_use]
+    pub fn encapsulate_deterministic(&self, eseed: &[u8; 64]) -> (Ciphertext, SharedKey) {
+        use ml_kem::EncapsulateDeterministic;
+
+        let (m_bytes, ek_x) = eseed.split_at(32);
+        let mut m = ml_kem::B32::default();
+        m.copy_from_slice(m_bytes);
+        let (ct_m, ss_m) = self
+            .ek_m
+            .encapsulate_deterministic(&m)
+            .unwrap_or_else(|err| panic!("ML-KEM encapsulation failed: {err}"));
+        self.encapsulate_with(ct_m, &ss_m, &StaticSecret::from(x25519(ek_x)))
+    }
+
+    fn encapsulate_with(
+        &self,
+        ct_m: MlKemCiphertext,
+        ss_m: &[u8],
+        ek_x: &StaticSecret,
+    ) -> (Ciphertext, SharedKey) {
+        let ct_x = PublicKey::from(ek_x);
+        let ss_x = ek_x.diffie_hellman(&self.pk_x);
+        let ss = combiner(ss_m, ss_x.as_bytes(), &ct_x, &self.pk_x);
+        (Ciphertext { ct_m, ct_x }, ss)
+    }
+}
+
+impl TryFrom<&[u8]> for EncapsulationKey {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        let bytes = bytes.try_into().map_err(|_| Error::InvalidLength {
+            expected: ENCAPSULATION_KEY_SIZE,
+            actual: bytes.len(),
+        })?;
+        Self::from_bytes(bytes)
+    }
+}
+
+impl Encapsulate<Ciphertext, SharedKey> for EncapsulationKey {
+    type Error = Error;
+
+    fn encapsulate(&self, rng: &mut impl CryptoRngCore) -> Result<(Ciphertext, SharedKey), Error> {
+        let mut ek_x = [0u8; X25519_SIZE];
+        rng.try_fill_bytes(&mut ek_x).map_err(|_| Error::Rng)?;
+        let ek_x = StaticSecret::from(ek_x);
+
+        let (ct_m, ss_m) = self.ek_m.encapsulate(rng)?;
+        Ok(self.encapsulate_with(ct_m, &ss_m, &ek_x))
+    }
+}
+
+impl ConstantTimeEq for EncapsulationKey {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.ek_m.ct_eq(&other.ek_m) & self.pk_x.as_bytes().ct_eq(other.pk_x.as_bytes())
+    }
+}
+
+/// An X-Wing ciphertext, which is the concatenation of an ML-KEM-768 ciphertext and an
+/// ephemeral X25519 public key
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ciphertext {
+    ct_m: MlKemCiphertext,
+    ct_x: PublicKey,
+}
+
+impl Ciphertext {
+    /// Encode this ciphertext as `ct_M || ct_X`
+    #[must_use]
+    pub fn as_bytes(&self) -> [u8; CIPHERTEXT_SIZE] {
+        let mut bytes = [0u8; CIPHERTEXT_SIZE];
+        let (ct_m, ct_x) = bytes.split_at_mut(ML_KEM_CT_SIZE);
+        ct_m.copy_from_slice(self.ct_m.as_ref());
+        ct_x.copy_from_slice(self.ct_x.as_bytes());
+        bytes
+    }
+}
+
+impl From<&[u8; CIPHERTEXT_SIZE]> for Ciphertext {
+    fn from(bytes: &[u8; CIPHERTEXT_SIZE]) -> Self {
+        // Fill both parts in one pass, so that no length check can fail
+        let mut ct_m = Encoded::<MlKemCiphertext>::default();
+        let mut ct_x = [0u8; X25519_SIZE];
+        for (dst, src) in ct_m.iter_mut().chain(&mut ct_x).zip(bytes) {
+            *dst = *src;
+        }
+
+        Self {
+            ct_m: MlKemCiphertext::from_bytes(&ct_m),
+            ct_x: PublicKey::from(ct_x),
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for Ciphertext {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        let bytes: &[u8; CIPHERTEXT_SIZE] = bytes.try_into().map_err(|_| Error::InvalidLength {
+            expected: CIPHERTEXT_SIZE,
+            actual: bytes.len(),
+        })?;
+        Ok(Self::from(bytes))
+    }
+}
+
+impl ConstantTimeEq for Ciphertext {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.ct_m.ct_eq(&other.ct_m) & self.ct_x.as_bytes().ct_eq(other.ct_x.as_bytes())
+    }
+}
+
+/// Convert a 32-byte slice split from a larger array into an X25519 value
+fn x25519(bytes: &[u8]) -> [u8; X25519_SIZE] {
+    bytes.try_into().expect("32-byte slice")
+}
+
+/// Derive the shared key, as in `Combiner`:
+/// `SHA3-256(ss_M || ss_X || ct_X || pk_X || XWingLabel)`
+fn combiner(ss_m: &[u8], ss_x: &[u8; 32], ct_x: &PublicKey, pk_x: &PublicKey) -> SharedKey {
+    Sha3_256::new()
+        .chain_update(ss_m)
+        .chain_update(ss_x)
+        .chain_update(ct_x.as_bytes())
+        .chain_update(pk_x.as_bytes())
+        .chain_update(X_WING_LABEL)
+        .finalize()
+        .into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut rng = rand::thread_rng();
+        let (dk, ek) = generate_key_pair(&mut rng);
+        let (ct, k_send) = ek.encapsulate(&mut rng).unwrap();
+        let k_recv = dk.decapsulate(&ct).unwrap();
+        assert_eq!(k_send, k_recv);
+
+        // Another key pair derives a different shared key
+        let (dk_other, _) = generate_key_pair(&mut rng);
+        assert_ne!(dk_other.decapsulate(&ct).unwrap(), k_send);
+    }
+
+    #[test]
+    fn codec() {
+        let mut rng = rand::thread_rng();
+        let (dk, ek) = generate_key_pair(&mut rng);
+        let (ct, _) = ek.encapsulate(&mut rng).unwrap();
+
+        let dk_bytes = dk.as_bytes();
+        assert_eq!(DecapsulationKey::try_from(&dk_bytes[..]).unwrap(), dk);
+
+        let ek_bytes = ek.as_bytes();
+        assert_eq!(EncapsulationKey::from_bytes(&ek_bytes).unwrap(), ek);
+        assert!(bool::from(
+            EncapsulationKey::try_from(&ek_bytes[..])
+                .unwrap()
+                .ct_eq(&ek)
+        ));
+
+        let ct_bytes = ct.as_bytes();
+        assert_eq!(Ciphertext::from(&ct_bytes), ct);
+        assert_eq!(
+            Ciphertext::try_from(&ct_bytes[1..]),
+            Err(Error::InvalidLength {
+                expected: CIPHERTEXT_SIZE,
+                actual: CIPHERTEXT_SIZE - 1
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_encapsulation_key() {
+        let (_, ek) = generate_key_pair(&mut rand::thread_rng());
+        let mut bytes = ek.as_bytes();
+
+        // The first coefficient of the ML-KEM key is set to q
+        bytes[0] = 0x01;
+        bytes[1] = (bytes[1] & 0xf0) | 0x0d;
+        assert_eq!(
+            EncapsulationKey::from_bytes(&bytes),
+            Err(Error::InvalidEncapsulationKey)
+        );
+    }
+
+    #[test]
+    fn expand() {
+        // The encapsulation key is `ek_M || pk_X`, with both key pairs expanded from
+        // `SHAKE256(seed, 96)`
+        let seed = [0x42; DECAPSULATION_KEY_SIZE];
+        let mut expanded = [0u8; 96];
+        Shake256::default()
+            .chain(seed)
+            .finalize_xof()
+            .read(&mut expanded);
+
+        let (_, ek_m) = MlKem768::from_seed(&Seed::try_from(&expanded[..64]).unwrap());
+        let pk_x = x25519_dalek::x25519(
+            x25519(&expanded[64..]),
+            x25519_dalek::X25519_BASEPOINT_BYTES,
+        );
+
+        let ek = DecapsulationKey::from(seed).encapsulation_key().as_bytes();
+        assert_eq!(&ek[..ML_KEM_EK_SIZE], ek_m.as_bytes().as_slice());
+        assert_eq!(&ek[ML_KEM_EK_SIZE..], &pk_x);
+    }
+
+    #[cfg(feature = "deterministic")]
+    #[test]
+    fn encapsulate_deterministic() {
+        use ml_kem::EncapsulateDeterministic;
+
+        let dk = DecapsulationKey::from([0x42; DECAPSULATION_KEY_SIZE]);
+        let ek = dk.encapsulation_key();
+        let mut eseed = [0x11; 64];
+        eseed[32..].fill(0x22);
+        let (ct, ss) = ek.encapsulate_deterministic(&eseed);
+        assert_eq!(dk.decapsulate(&ct).unwrap(), ss);
+
+        // Recompute `EncapsulateDerand` from its components
+        let m = ml_kem::B32::try_from(&eseed[..32]).unwrap();
+        let (ct_m, ss_m) = ek.ek_m.encapsulate_deterministic(&m).unwrap();
+        let ek_x = x25519(&eseed[32..]);
+        let ct_x = x25519_dalek::x25519(ek_x, x25519_dalek::X25519_BASEPOINT_BYTES);
+        let ss_x = x25519_dalek::x25519(ek_x, *ek.pk_x.as_bytes());
+
+        let ct_bytes = ct.as_bytes();
+        assert_eq!(&ct_bytes[..ML_KEM_CT_SIZE], ct_m.as_ref());
+        assert_eq!(&ct_bytes[ML_KEM_CT_SIZE..], &ct_x);
+
+        let mut input = [0u8; 32 * 4 + 6];
+        input[..32].copy_from_slice(&ss_m);
+        input[32..64].copy_from_slice(&ss_x);
+        input[64..96].copy_from_slice(&ct_x);
+        input[96..128].copy_from_slice(ek.pk_x.as_bytes());
+        input[128..].copy_from_slice(b"\\.//^\\");
+        assert_eq!(ss, <[u8; 32]>::from(Sha3_256::digest(input)));
+    }
+}
@@ -0,0 +1,357 @@
+#![cfg(feature = "deterministic")]
+
+use ::kem::Decapsulate;
+use hex_literal::hex;
+use x_wing::*;
+
+// The first vector is the first test vector of draft-connolly-cfrg-xwing-kem, whose `seed` and
+// `eseed` are the start of the SHAKE128 output stream for the empty input.
+//
+// The others were computed independently of this crate.  The ML-KEM-768 key pair, ciphertext and
+// shared key come from OpenSSL 3.5 with its deterministic key generation (`hexseed`) and
+// encapsulation (`hexikme`) options.  The seed expansion, X25519 and the combiner are computed
+// with Python's `hashlib` and `cryptography` packages.
+
+struct Vector {
+    seed: [u8; 32],
+    eseed: [u8; 64],
+    pk: &'static [u8],
+    ct: &'static [u8],
+    ss: [u8; 32],
+}
+
+#[test]
+fn vectors() {
+    for v in &VECTORS {
+        let dk = DecapsulationKey::from(v.seed);
+        let ek = dk.encapsulation_key();
+        assert_eq!(ek.as_bytes().as_slice(), v.pk);
+        assert_eq!(EncapsulationKey::try_from(v.pk).unwrap(), ek);
+
+        let (ct, ss) = ek.encapsulate_deterministic(&v.eseed);
+        assert_eq!(ct.as_bytes().as_slice(), v.ct);
+        assert_eq!(ss, v.ss);
+
+        let ct = Ciphertext::try_from(v.ct).unwrap();
+        assert_eq!(dk.decapsulate(&ct).unwrap(), v.ss);
+    }
+}
+
+const VECTORS: [Vector; 4] = [
+    Vector {
+        seed: hex!("7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26"),
+        eseed: hex!("3cb1eea988004b93103cfb0aeefd2a686e01fa4a58e8a3639ca8a1e3f9ae57e2"
+            "35b8cc873c23dc62b8d260169afa2f75ab916a58d974918835d25e6a435085b2"),
+        pk: &hex!("e2236b35a8c24b39b10aa1323a96a919a2ced88400633a7b07131713fc14b2b5"
+            "b19cfc3da5fa1a92c49f25513e0fd30d6b1611c9ab9635d7086727a4b7d21d34"
+            "244e66969cf15b3b2a785329f61b096b277ea037383479a6b556de7231fe4b7f"
+            "a9c9ac24c0699a0018a5253401bacfa905ca816573e56a2d2e067e9b7287533b"
+            "a13a937dedb31fa44baced40769923610034ae31e619a170245199b3c5c39864"
+            "859fe1b4c9717a07c30495bdfb98a0a002ccf56c1286cef5041dede3c44cf16b"
+            "f562c7448518026b3d8b9940680abd38a1575fd27b58da063bfac32c39c30869"
+            "374c05c1aeb1898b6b303cc68be455346ee0af699636224a148ca2aea1046311"
+            "1c709f69b69c70ce8538746698c4c60a9aef0030c7924ceec42a5d36816f545e"
+            "ae13293460b3acb37ea0e13d70e4aa78686da398a8397c08eaf96882113fe4f7"
+            "bad4da40b0501e1c753efe73053c87014e8661c33099afe8bede414a5b1aa27d"
+            "8392b3e131e9a70c1055878240cad0f40d5fe3cdf85236ead97e2a97448363b2"
+            "808caafd516cd25052c5c362543c2517e4acd0e60ec07163009b6425fc32277a"
+            "cee71c24bab53ed9f29e74c66a0a3564955998d76b96a9a8b50d1635a4d7a67e"
+            "b42df5644d330457293a8042f53cc7a69288f17ed55827e82b28e82665a86a14"
+            "fbd96645eca8172c044f83bc0d8c0b4c8626985631ca87af829068f1358963cb"
+            "333664ca482763ba3b3bb208577f9ba6ac62c25f76592743b64be519317714cb"
+            "4102cb7b2f9a25b2b4f0615de31decd9ca55026d6da0b65111b16fe52feed8a4"
+            "87e144462a6dba93728f500b6ffc49e515569ef25fed17aff520507368253525"
+            "860f58be3be61c964604a6ac814e6935596402a520a4670b3d284318866593d1"
+            "5a4bb01c35e3e587ee0c67d2880d6f2407fb7a70712b838deb96c5d7bf2b44bc"
+            "f6038ccbe33fbcf51a54a584fe90083c91c7a6d43d4fb15f48c60c2fd66e0a8a"
+            "ad4ad64e5c42bb8877c0ebec2b5e387c8a988fdc23beb9e16c8757781e0a1499"
+            "c61e138c21f216c29d076979871caa6942bafc090544bee99b54b16cb9a9a364"
+            "d6246d9f42cce53c66b59c45c8f9ae9299a75d15180c3c952151a91b7a107724"
+            "29dc4cbae6fcc622fa8018c63439f890630b9928db6bb7f9438ae4065ed34d73"
+            "d486f3f52f90f0807dc88dfdd8c728e954f1ac35c06c000ce41a0582580e3bb5"
+            "7b672972890ac5e7988e7850657116f1b57d0809aaedec0bede1ae148148311c"
+            "6f7e317346e5189fb8cd635b986f8c0bdd27641c584b778b3a911a80be1c9692"
+            "ab8e1bbb12839573cce19df183b45835bbb55052f9fc66a1678ef2a36dea7841"
+            "1e6c8d60501b4e60592d13698a943b509185db912e2ea10be06171236b327c71"
+            "716094c964a68b03377f513a05bcd99c1f346583bb052977a10a12adfc758034"
+            "e5617da4c1276585e5774e1f3b9978b09d0e9c44d3bc86151c43aad185712717"
+            "340223ac381d21150a04294e97bb13bbda21b5a182b6da969e19a7fd072737fa"
+            "8e880a53c2428e3d049b7d2197405296ddb361912a7bcf4827ced611d0c7a7da"
+            "104dde4322095339f64a61d5bb108ff0bf4d780cae509fb22c256914193ff734"
+            "9042581237d522828824ee3bdfd07fb03f1f942d2ea179fe722f06cc03de5b69"
+            "859edb06eff389b27dce59844570216223593d4ba32d9abac8cd049040ef6534"),
+        ct: &hex!("b83aa828d4d62b9a83ceffe1d3d3bb1ef31264643c070c5798927e41fb07914a"
+            "273f8f96e7826cd5375a283d7da885304c5de0516a0f0654243dc5b97f8bfeb8"
+            "31f68251219aabdd723bc6512041acbaef8af44265524942b902e68ffd23221c"
+            "da70b1b55d776a92d1143ea3a0c475f63ee6890157c7116dae3f62bf72f60acd"
+            "2bb8cc31ce2ba0de364f52b8ed38c79d719715963a5dd3842d8e8b43ab704e47"
+            "59b5327bf027c63c8fa857c4908d5a8a7b88ac7f2be394d93c3706ddd4e698cc"
+            "6ce370101f4d0213254238b4a2e8821b6e414a1cf20f6c1244b699046f5a01ca"
+            "a0a1a55516300b40d2048c77cc73afba79afeea9d2c0118bdf2adb8870dc328c"
+            "5516cc45b1a2058141039e2c90a110a9e16b318dfb53bd49a126d6b73f215787"
+            "517b8917cc01cabd107d06859854ee8b4f9861c226d3764c87339ab16c3667d2"
+            "f49384e55456dd40414b70a6af841585f4c90c68725d57704ee8ee7ce6e2f9be"
+            "582dbee985e038ffc346ebfb4e22158b6c84374a9ab4a44e1f91de5aac5197f8"
+            "9bc5e5442f51f9a5937b102ba3beaebf6e1c58380a4a5fedce4a4e5026f88f52"
+            "8f59ffd2db41752b3a3d90efabe463899b7d40870c530c8841e8712b733668ed"
+            "033adbfafb2d49d37a44d4064e5863eb0af0a08d47b3cc888373bc05f7a33b84"
+            "1bc2587c57eb69554e8a3767b7506917b6b70498727f16eac1a36ec8d8cfaf75"
+            "1549f2277db277e8a55a9a5106b23a0206b4721fa9b3048552c5bd5b594d6e24"
+            "7f38c18c591aea7f56249c72ce7b117afcc3a8621582f9cf71787e183dee0936"
+            "7976e98409ad9217a497df888042384d7707a6b78f5f7fb8409e3b5351753734"
+            "61b776002d799cbad62860be70573ecbe13b246e0da7e93a52168e0fb6a9756b"
+            "895ef7f0147a0dc81bfa644b088a9228160c0f9acf1379a2941cd28c06ebc80e"
+            "44e17aa2f8177010afd78a97ce0868d1629ebb294c5151812c583daeb8868522"
+            "0f4da9118112e07041fcc24d5564a99fdbde28869fe0722387d7a9a4d16e1cc8"
+            "555917e09944aa5ebaaaec2cf62693afad42a3f518fce67d273cc6c9fb5472b3"
+            "80e8573ec7de06a3ba2fd5f931d725b493026cb0acbd3fe62d00e4c790d965d7"
+            "a03a3c0b4222ba8c2a9a16e2ac658f572ae0e746eafc4feba023576f08942278"
+            "a041fb82a70a595d5bacbf297ce2029898a71e5c3b0d1c6228b485b1ade509b3"
+            "5fbca7eca97b2132e7cb6bc465375146b7dceac969308ac0c2ac89e7863eb894"
+            "3015b24314cafb9c7c0e85fe543d56658c213632599efabfc1ec49dd8c88547b"
+            "b2cc40c9d38cbd3099b4547840560531d0188cd1e9c23a0ebee0a03d5577d66b"
+            "1d2bcb4baaf21cc7fef1e03806ca96299df0dfbc56e1b2b43e4fc20c37f834c4"
+            "af62127e7dae86c3c25a2f696ac8b589dec71d595bfbe94b5ed4bc07d800b330"
+            "796fda89edb77be0294136139354eb8cd37591578f9c600dd9be8ec6219fdd50"
+            "7adf3397ed4d68707b8d13b24ce4cd8fb22851bfe9d632407f31ed6f7cb1600d"
+            "e56f17576740ce2a32fc5145030145cfb97e63e0e41d354274a079d3e6fb2e15"),
+        ss: hex!("d2df0522128f09dd8e2c92b1e905c793d8f57a54c3da25861f10bf4ca613e384"),
+    },
+    Vector {
+        seed: hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+        eseed: hex!("202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f"
+            "404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f"),
+        pk: &hex!("6f54098a0a0e641146614b6960ba60d8603d62f447f9ab499b47bd6906cc40b0"
+            "61d8634a3e88906f284958e7441ca6c725cbb97095b7671a462b6681c9e6580b"
+            "bc8d60b149fa60261043afbba52f205a6028384851596adf371abea98d334738"
+            "3d2bb673438f6783612bf87014f7b91a89740265345df679340473d1c4c17688"
+            "6e5e29b8f058bb7c735316686cff5c3beb8c261cb00970a69c1afcc54b94cb86"
+            "e1ce63ba636e395ca45101e21c7bd04c313ea19af24141efd2ad44416a25ba4f"
+            "65910ef7d8809c3093f04aaf00e3cd96e35c4aa3c802c18ad6f39da4b4b8d98c"
+            "8bd7902d83a07ba45396674a60243cab93e80fd9b1c8777376a9cc0d6fa115e2"
+            "639380b9c6be7848bd13588c64703a0535d19a0f81633a976a0a105b66ee285d"
+            "0fd255e82c0331925f4383b6efc761ef6099235a0b98726358aa9d01b8b89651"
+            "9f921474bb7c14bb22252b5c2f10d41246c9b23e7644849367f541a15f63bc92"
+            "8a39bb7bc73f07b665c496bb6558c8f45489a72ec4bacd34e9c594c33871b723"
+            "f03495e88b4391ab26e43043deb6117b3919e45c4c1b16ab28e47ddd72366385"
+            "4766192fc1806ca70abb786cbdb30932e68c8a370bcfb07983a012c3266b93ef"
+            "a62657f4b838374cb0bb95e0ec06541b0765d99cf153bc6b96135ca780a55b36"
+            "47789e31915e46283cf9c7bb6e8453fb6682105141f1dc0d00d85eed703b6c6c"
+            "961f79c845276b4248949c06782e513eb2991b95d96042e38cbeda352449b2b5"
+            "084ebda5226a6206400789130a3096449848b629feea4a2c2a743c4a0ddc9cb3"
+            "f3d676fc563731b26c4a1a66dc8459170056d57697f1443b81a9a34412bb7bf0"
+            "5f3327575a5911dd301d6053867f3c3080711f1bf11587b0bb2984276b2685e7"
+            "756210e4b3f8955384231e558c6f510c91e0fc56b5d1885ff2949e95a46bc1be"
+            "e1fa71f5027e10c443b0e91d0fd7440f467a27221212e88f5c6ba64296cae0d2"
+            "07bfc60f88c7cfb5c45aa1839d18cb37c45843e5426a4a90c802b6428f953c35"
+            "9c4ac0603452fac0b7361e2fd35dcc885a92145d4fca0158f1b7d70b4bcd118e"
+            "4a2a4154438df310c44a9a1b99ea415907267a88b0624241579c1722f46ed61c"
+            "2e3eca545c9970517175399b800db25da39593d06490d7142c00e88d2db047e9"
+            "898bdb7acb7ed907f6e30416cc0de54a242c0a2126302f5d54c85bc66ac2f83c"
+            "797945b5067caa42bd2e0c19ca97506e507ab0a5c9f5633708499c19f24aec51"
+            "3bd3903a5d73b6ec4991f7c72eb991c1c37889805cb1ea38a0cc02176b27c58d"
+            "638ce5a32668457cf9b9be027ca0214057971725d54102e8996716eb2ad82345"
+            "3b605b855370b1b21b3932cded4160aa9973c7ebae5ac4764d94cf7cc9506f07"
+            "7bad73012dbb4ac8140a38746412eb33c9514596205f707635862217d9b60918"
+            "c6268d9344915b847a2476c1a270f154a5c84234165acfc869398702cea9e9a0"
+            "7e7b0e99ea9bdcb7841fe9c0fa25c8338092561a3edddc7001f478ad65781a60"
+            "24aad165d9b6979adac448a4462f564685527f762434fe9a425a84437b457392"
+            "eca80c913506151e3a13239f342fca7655b6eaae845a221ceb3e67f5639c6193"
+            "f6fdeef57e399b808b7f3aa2b5740aaded90163dc5d775c9faf7f1fbd075dab3"
+            "44e9d7d146647281fbba7b3c56cafd5833b7a930ec4206e7c3a6d7764fe81d7a"),
+        ct: &hex!("2300731f60f7ffcc2a3724204e3046f37eab7bbf4358aaa42ecdbafce51648df"
+            "c699ca882d7f876b1bd55d777e84c3c38d55c0f2892817211970a19df6071bfd"
+            "daeaefe64e540c3a14d426b248fbadc5fa0eb2f6a322d9de0a7bdc9a1bd5f035"
+            "4656fb7b33c30a0adcd42624714575a1c844af709270ff986ed32c7cfc0c2f73"
+            "e67fbca7bd176b6a89b6b3a8115358d5bf17ae72f546a0f652e50bcd3031b002"
+            "0c9cd0621def6823eedfbaa3913e2da930b68076b1f8c545cc85afb4b7451adb"
+            "2768f86e2f8ce27a72011938c99d5b0f2a7c79b1b65c3a66e22bf49194b6bcbf"
+            "20f683b6cd3d07a149c081b7ae2510967d484e5cd912d4a5fe04d7d214c9683e"
+            "7d8290a747671222402de4e363fca77ebcbb15aba5d1fd6e54c4f169f3d05b75"
+            "8a4b300a289a7aa44662c7498da9357d9dbf3e1637a2074dd6bcd33edcbbbceb"
+            "b7828d73149e0adc2fd1c7de0f55c1e65cde1dd8a670d6d44e4d14ca84010d7f"
+            "2117ae49af1021c5b58a22d7367b7ef17adf5b2b61d31f8b860c8d9e40c9e337"
+            "26fec7391280a65561b9fe5abfff35c69a1d821099ff0ceef76db86bccd81d43"
+            "8c38b40c075bbb1abbaed7883bab41832e6e2a0f24060336cc987c20696f6571"
+            "4e37a6f6f2120d2a2e57d993f12c5ab2fc5aaa63ae028133c77d7d7bb67d7b9b"
+            "7aae8f954588947364a4d49d74288cb45b6d6a5286cbdea8aeff45d118cf2ba8"
+            "f184717faf82dc3f35492d4b3f089b272d9a95c747f9d285c780799c84d0ba89"
+            "6b442bf92d45ea445d16f7fbe55f100b43162e7e5f49758ab40ddf0a0ba015b1"
+            "ba308085d147917ee89a64799a81c5dc71579886d5eea8332936c8e4c18525de"
+            "54d5b1d0daf95537e799dc35502e5ddc034ee3ec106cc1b9191d27e3c3e5413c"
+            "3ee72a34ac991f7a114a9b88184ba20a29475f1c2bd560419e066358829cb5ae"
+            "0354ec643d4771847452c89b2b149b1a07998ce95f1b27d3e153c0c059af0ad3"
+            "adea77dfe89fe8fc3c15907f7013920d9ae102caa0a4a7eff15ddabf11dd878e"
+            "09e92faa84899275dc901b950a4c78d14638a17e3c2324483f339aada1b33a11"
+            "e64d836466fee6b8e2c043d469f98a73c2760d58b3593de80edb29ef73b65df6"
+            "e4cff8ce2ecb9623d7da837e6650605d4a4bbe74ec81a689144c3c51423cbe52"
+            "d671582a68eee2e9f0706304eb5f55b3f9dcd3aa6337b3f536236b9dafb5d6c3"
+            "2a90575732ede66eb67caf32b7d2cb1772f52903d0bf73ee2be046c4824a8d55"
+            "14475b281ee9775dc6f380b5ba96d86ea735639492121c6cabe973f794f4fe4b"
+            "804a31c70a0aa4ea9635aefe6930ca74ebc27739a9b0b4d1e286807bd0771823"
+            "d02f23fa216df067d0537c24072886bc214955b73c7937921c16b1895ae8825b"
+            "c69b6da9387663055c3b10e7d6e81b70564365cc7e94e519e073de4d6fcbd9f8"
+            "f3c3641b5c0de9e29b991b73433238877a8658af3b55cb683f9e662895b0c37c"
+            "b50b46014a9ceb9aca96a601be901e5b3d8d1ede052b871020cb4f455ab96917"
+            "79a631eede1bf9c98f12032cdeadd0e7a079398fc786b88cc846ec89af85a51a"),
+        ss: hex!("9ef8c4373f751b482022f88f3e8cceeb4815a3c1afbc784324ac9eeb50932023"),
+    },
+    Vector {
+        seed: hex!("4242424242424242424242424242424242424242424242424242424242424242"),
+        eseed: hex!("1111111111111111111111111111111111111111111111111111111111111111"
+            "2222222222222222222222222222222222222222222222222222222222222222"),
+        pk: &hex!("60bace5f448d7598320e15a4875a82ac788c61886595d20438a1818d52149de5"
+            "842e396aa3664d803268b3d0b04aaa1fc6974882fc9a3a64afcf60413ceb6692"
+            "a03f85fb58e7941813e8c08490405f22ba4f176415a0b69ca56cc846b5d380b0"
+            "f8189060230f28c041f18c0ff77286773391a46cbceeaab194a44f901a620784"
+            "cf7bb157774b148ea462a42c9036fc2fd1aa072e4b54b8281695071a22cbaa9b"
+            "641a8d2cbdf9fb0f01dbc9530382f5509b88b14200dbba80f18433f170e48971"
+            "790aaa2aa591dae23f235c500229b855ba65a49230db0a86ad706220d99939db"
+            "406453c0fc8160fd618b5822a57fbcbeced9953a9934930439c8fb98bec1460b"
+            "a934c66938ca107fd5140c4eac180c7ac62e15848f9072a91197c5276ffa8c58"
+            "64ca76e807bec0c8bddecb372780bc13776087a909eb62a398810cea11b2e0fb"
+            "0af7e7487cf1299930385fb69f08138bd02103a429053aa6091c25573e059a43"
+            "2840d673a2e70055a838a7efa340a6335c8c76b33cf9c217381c6eb86842c46b"
+            "c6c3ac10985fdc87926bbc93c978c2b27091770c5551519d5523ba1d7a0e2386"
+            "89ade4aa27b0008909b835e081717b96ca36079a2773712c5eb198165905174e"
+            "0aaf8233cd188c649afba18bf00b4f299a9d8c4d0179312c5612d7016d6d2835"
+            "b527289e5542906b15275c07e3c22b35504050f4837dda64529b0368b66547e5"
+            "18e23a45a94b7ba1d308465a8f24c186f5881910ca6644c9bd440c40933781fe"
+            "e13addbc3a58c77a9b487ece783b616c07e507047aca305ed29ef66b9c637c2e"
+            "42da3f0cc49b8c6b4440f73a24f7aef0d72850ebbaf6c23195145836f6adfbba"
+            "197844cfa8109c8ba50eeaeab391314533b64f3f7cc70090b986940af33b24af"
+            "241ce8126591c7cd03b063b3d47a1fb7b5bcc900c755297613ab6ef1636550b2"
+            "21319eb1e62f78995c47d82c59f0952015859d45833a6583e59a002f8722cbd1"
+            "54298499853613e71c243cc0ce7ae28892e06f21d2313c0491e2541c51c4aabc"
+            "3bc86a65c4291154b3032080901890a63fc9f08a455781579a954937cb50a088"
+            "3837566782919b070d417767d8334ed6d118a1b891f4fc0b078482a26a71a0ca"
+            "98e30097b2261ae0d191e06976253b03cc6c39e277824aca67fdba6974279aee"
+            "e5597dc97ac5d485dd17629d447d39ea70e2890a78a9a4f4a20bfb71195822c4"
+            "9fa77b199235b2ebcc794759e553a8c86193d57a5a3ea6aa6168631001a57434"
+            "8411d624e0f42c80d3ca8ec886b8e53792e39473d1424a9176d3721856b68b05"
+            "e151e045a224967373436d119a0ad8895d0a4582f1301778853222667910582c"
+            "d6252c3a4898494904e6118e3bfc7e7a3837a7629f0466c08a88c93b93c15e56"
+            "960b0192c30a47a26073b165bee6db66a01a7ae329a7ef93b77eb617ba3082ba"
+            "c2665bf701b4035821684dbf09344f46cd4d0563a64c29a6f5c037884403bb58"
+            "7610124fa998af7b30fc827d0a3251b8f02016f3b5c4288c1ea85128008158d0"
+            "a484e7c9375bcd95b59b31fa2f494a1d95833085371d67d19b1bf77219b07d7e"
+            "30400e587e61c109032306a0fb3b4bf4a39f6267e9857a0935505ebb897803b3"
+            "cef5f84faec6bcfbe1af3aa8ddd57b056fb466d0e6327b56b33c315a1ab51401"
+            "393af45f850ebe5b319e3001e3b65ada92bebe28ebfc5980cce5a3c5a77e6946"),
+        ct: &hex!("68aabaa14c8d9c32d2c4f9882bbee583f2dc416198fc7e8a98527764eaf14725"
+            "00a7fc71052e908e46231fbe02114eb2017920568a9ea6ebe4041ab69ffd1100"
+            "c67a77f37b0c7bf2acffe587e37049525699a83d745ffb6f3e03a5bf97d3abf3"
+            "4c6db3a1b19c6b358421809a20918f9e01b6e5b97c0d4885906980687de53f71"
+            "9b810dc07d37ca14475a9954e7f9c0e21a55190e711ef736160eb7a4e6aa7a91"
+            "544259dffedc8d950d84b277f9564e3dfc1a753e77e9e7661d4fd710ce2f358c"
+            "f4f1f61a2d64d6d0a4af561158f9c5a76b978ea376e72da6d8847eabd12f750c"
+            "6b5d7eb734f0a06fb67997832a4d0afc1b7c3860c280fd2d160c016a4673d14d"
+            "a75256de2673c409b4fd515cd88354c7f8cebe6ee36677620c09c702de551bd3"
+            "d46dc06dc03bd25e4db3c3eaeefc2509ddc13f6e880c1fb880352e6d91f59e30"
+            "a68ac2e88ba3bf04f1a1e5dbc9bbc2331460dc8f0c2b262cf0264a94016abd7a"
+            "6d7afd2ac2e022686fb2d654b63ce2f0f83878c6c646d3d996a88fa122e4da50"
+            "ae856a37cc4ec4f8e919fb179fc70290df32a7c7a45e1b4480586f1b65f6467d"
+            "8e200eaf34a28cd26f7e17a9a136d39ac452d712742f13f79d9653c7a4bd2f41"
+            "6a3caac148a469b1f782c8df982369d3616a797726d62e6c5ed849bd6a0a16c1"
+            "793d84bc5d66ff54c91158cc415063f8895bbfc9acb27f0d7703bd148b03250e"
+            "31b404d782c54c9069c195878e43164df3008223fda6ae7f4d0a1e6384a4d068"
+            "c9b2a1e2eadfc087f42dd33529c0ad116903dc2996de0734444d76cdb3d796b7"
+            "443fa7b12e320daf8374bcf0eaafa026801cd710c62ffd203c5f47b04071cbe6"
+            "c32c5ff25e3b703bc09cd44c4ec87a1f3d2cfa2b03bfc6dd69376249187aaf54"
+            "68c6740e61a616c4b724c8b13faa094620b6eb6bd86802a4808c91e0c4ce6313"
+            "d289033b79b4b087961e5aeec62e40d9d2248a5ffd1c6b7ac58f2b1e69d921ae"
+            "56b269ef75ec74c775299ea34eac97392c1a25fe5ca54f83ae9378a7ba74c0b2"
+            "d4b135107a32ef96124f3130eefc759c4f5a344132f07167568f6b34fa1a3552"
+            "0d04fd1a93a7502c62de9e4a9edad365a24b7cc641b9a4c4e1ac9eae4a46e480"
+            "3dff6260a40d1a35c9f4cef574e04c4191f70495215ead017f6063adad874564"
+            "57cefc33b51642cdf82cb730caa914ca03b00af4c4728d8878f676453c71957d"
+            "82ce41a185cf2cf13e5d1bf4990e98e099c6ca5859ac476281d4f692e709c3ff"
+            "91060e6c6b6149320dc161caf3c4e69cdb40e053f63105174ed2afa25329d022"
+            "ed06ed2a62d83483c55579e9c82075822bd2906437e4b66edde6157734df889a"
+            "a6e6c97513cd9a4b903bf6cad29ae7fcc017806f182be6fd548c904c81a8cf33"
+            "3cc431982668c00343ba57e89efc69d37f103c61cf1a59bf148bc51a1c495027"
+            "dc45064e267e506d55e7223c8bd7f1ce010f4b33440d3e63b748808793107619"
+            "ec1eff8dde229295f05b8954315f136dc58a70202d50c80815a9e221b7815bf0"
+            "0faa684ed28867b97f4a6a2dee5df8ce974e76b7018e3f22a1c4cf2678570f20"),
+        ss: hex!("332a360b4092230280d4bdd9ce99ac2352dbb69d276a566d188539e61ccee597"),
+    },
+    Vector {
+        seed: hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+        eseed: hex!("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"),
+        pk: &hex!("bd548ef012c0764b8b668568c97257aca7b6627424ca07556f85a17e443cd794"
+            "1e276c350ba81fcc6678cf3683b5b231a9152e9ee0a5ab567510b80c51f05f8e"
+            "717fe056479998ba30a043b3ca218b59600ef798d5535d10380ecf59440c335e"
+            "66e51c35f22ba45950c27a3c4d7c12319a49b8b916e9a726f635ac16c478c1c3"
+            "596e16387967ce78c9248a762ef8fa50d37a04f7b29dc7a310c6f6057fab6253"
+            "a96954eb17f94888481a51a0f192a1a4593694c6ff1b03327b39e52665d77a45"
+            "035b8b33199cc7080231255e613c8c72b87f1680255626b8e01511b54705ade3"
+            "128be15b6f2a4b1489a097907aa74207855b69a4280e7237222e3cb5c88023ae"
+            "9738e18a2ec6661d7b7284c87064b9b9588cca29d46b3c58409fc48b54f1715a"
+            "43c7bf20f31435ea7044954499e6b18e0773c887073ae239c95153e3d57ac92a"
+            "78413859886a78e3b30c9cf2498ed29cd627420d830090860d28d13f9a95c466"
+            "f8a79df9c669b67f89b2066ae6175cb55d10575eefc310182b93aa06cefaf40d"
+            "20ba7945155b92e14e5f8b762bf352f0e1b76fe39a70cb4a53b8c7cb508174d2"
+            "5471e2b1d7f10d0ae74a5d13b94bf645abf9a57884992062593e59bbe4f9a3d5"
+            "d5ab26514969190d0529a50b945de1969dd84aa21db910bafaa708d6812f2bb8"
+            "d7aac565604d7d19124470514f6733c8f86b17c7326d168780107384d453db40"
+            "22e5ecc4ef9330efd0addbb1c2928a5b27014a4ec68e86834cb95305182a64f8"
+            "9a2a9d9ab60d17096429078ed7b3572721a7ecc71a793b728bc9bfec5f52921b"
+            "8924be8713c710ec8c1ea88663c4775c180b51ab4e24991cc8f179bbf45b16bb"
+            "b45a514109777c085c90090591e8d35d76aa0f169c1045bca261818013432282"
+            "952c6fb3407513a622100eab3074c46bb5c9f59766c193156349e77bce04ec8a"
+            "eaacb4f6631c1ba2427d92c48c886bc2415422964fd856cac4a89801887ea2b8"
+            "ae0b7b5cfc81709777bf0af0514bb094ea959a2456bb83eac27d112bcf407676"
+            "d9413e020817f96ae9604d3600a828966e2ccc63daec07c4b7064cd43c51d52f"
+            "e8c4a85cf753ad2a3bba4966e0548d38c3709140194ab724d1c808f7094d11f6"
+            "cfd984a76bb46b7284992c62c2c6210bea196be0d169750c5c3fd31728d03285"
+            "47213a72560efca6201ccd140661b4a512ce021180e447e2b02c23427507c492"
+            "ad07c8c9aca18ca33da0ea228d1a734bc88d4ac9128306131fa83f6abb725018"
+            "2011d8c229a0ceee894a92675b4e3340d26b1180d0268de15206411e77cb390f"
+            "8029bdaaa2e90647c0eb8d0c5352b8129daac15f399441bd34b5a55ba2f13ab5"
+            "f88c04d91cc8607a1df750b10b3a05aa2184639297e71b1f29043c8209204fa2"
+            "66790a76136a400050a80a0651bf107cd9b1833d359b524ba6a6a53450a46e1d"
+            "d30123e84eae0c2635b558f94510c08b11ad438f8bd3821e0191db678ebf3522"
+            "7a195a330761c73a0e47e8a6b1135a0ba2814456701d7bc4fcbcb295d82fc62c"
+            "10419589d407a433ab8ace90661265069f051b2626a92ad07798332939f3456c"
+            "f1473ad6b6049a7c37a4c2d0b2b14f4121df8058da380cbb610489575587d64c"
+            "7387808ade7f91d17aac1929fefd0f9e32df057d523c718b3e786cce48759dbb"
+            "bc3e9f13e40b0359896722b11e7cec85cd83c5b264bd1487738857bb76ffa836"),
+        ct: &hex!("70936e54afbd98fd8cab8f4b74b5c930bff6ecca1e511184b3e7f458415945b6"
+            "759836cf1f61f2286382d0fb275a8d77f0caa9f3b33da1c55bfd85c4125924b2"
+            "5745c8c0c0a6391a2a26e3a2125bc70b6cc6c53e62d4fa18912533eeaa339533"
+            "37b62b21720810f7ee7bd34162212f927fc5aa537d558c31283b580b5f2a8c96"
+            "00075fdba5bf00f53b313456da2942f080726aea882433dd76e35e71cc2fe6d7"
+            "049677eb224468433e340609497a6eed680c650d32a9f0fd227d2af383b9dd98"
+            "1f422bc1c3e98d6c2468a1333d4fa432b70b4b94f7d4707ea13fcb6d8b4dfcd8"
+            "40863594468972ba40718eee36af4f1c1ea481eddecd5c70a01ae80f99fca41f"
+            "15dd49dc2fa64be02b3c1a520169040826f4ccf66bb171492eca93545e1bf0f7"
+            "4aca02efd4946c61c79fbf547efc3c3441224f63c0651c32b52b76019dbda80b"
+            "7a1ded3131ec29c56439def38c96fcec56f868e45dd1538e9098287a6dca461c"
+            "f3f9df046a6a1744a6e9134b4a8deb0e11a0fdc199d4d328d3f587b978c5dd1c"
+            "d7fd607318b5d27957895b7589f4c6fc081ffd95309fe817ae04db27de6f89d9"
+            "b8c87937842332f4ac5199b45dee66446b9a53a729c2467939711bb4588d80aa"
+            "eb4890f0711534228028784b942e89dfc6eebcfd84b650af9c05d31f121380f2"
+            "ef013e48d59b66a420bb27c80ced4ce8bba7c2fd83ed0fffbccbf59590362b45"
+            "7137ee08a91d872c747f2a18d2bb4ee6f464cf4fc1d0bc497c78968c1fed2f0a"
+            "3bb4fd9f3f3ed736c88524d090d40cf0b284af9a2db2344f5a69d81ead922891"
+            "ce9bd22ca1c4246952fac3331b1d417df503621a7a70f68937f53f71520fb7ff"
+            "49b97c08498370e0ac8bee15933015bb7000bd71d5be7b8f3308ae8ebe1b5247"
+            "fbceb74b38017b3dfc8f5c8e09b9d7671fb11cc1455c8d63636df933ef7f837f"
+            "d549592791eaf459d3e09fbda0e7941fe8c3da2b675f26101388fc5a48d51d63"
+            "01d3894099e7dec743a51120b51e5fa305ddfcbc39e717396f8e26930faa88cd"
+            "e0af775a74c95b4455cf96b234c5eb6db50a8775fc40e5cbfc6eb0d8ca853206"
+            "016716859286da1a9408a42a574259aef5dcbd4cd0a144882ac01be109937584"
+            "c8d6d0b4aa80472fce358069ad0a1a3af536e4d1d49b1c97f501126339bbb235"
+            "c45253c48c3870019e9c5f7a497009554b5208b7110f7da2aa50b7533777f7c2"
+            "34a422bed3c8ba4b5122378a5765a0fd873cb6e1286b0ebc839c8478518895a1"
+            "5150dc5be1b7a540fdfa7b1100d992dec6186ae4f121464b035f9dec672de12a"
+            "6a7f910d53553aef3eed0d795376a59741d4656818e412a75fb9b33be17db632"
+            "840eb290cb9e40c7fda9cddd0f49439a5e6f7a6e26ce80a30ce1ac14077095a5"
+            "27002df5c804c457a9be6607727b4f009e3b60dfb7990a2aa4599bef937d9567"
+            "33c468b72c1eaa83b99db61c799bda9a9fb08840a42cf0e070af5f03ae4b33fa"
+            "e001e7b9131b9f85fb6f4707c0fb4d3e69d0ae22e15771d409cc9e0d432f5f8e"
+            "847c0d2c375234f365e660955187a3735a0f7613d1609d3a6a4d8c53aeaa5a22"),
+        ss: hex!("4c9b4e8b2edbc6c16710165379caba7093eaa90b8948bc4d88b50dbde9bc7ad9"),
+    },
+];